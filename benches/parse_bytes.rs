@@ -0,0 +1,31 @@
+//! Parses a million timestamps with `Zemen::parse_bytes`, run with
+//! `cargo bench --bench parse_bytes`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use zemen::{format::FormatDescription, Werh, Zemen};
+
+const DAYS: usize = 1_000_000;
+
+fn main() {
+    let start = Zemen::from_eth_cal(1900, Werh::Meskerem, 1).expect("a valid date");
+    let lines: Vec<String> = start
+        .iter_days_until(&(start + DAYS as i32))
+        .map(|qen| qen.to_string())
+        .collect();
+
+    let description = FormatDescription::parse("YYYY-M-D").expect("a parsable pattern");
+
+    let now = Instant::now();
+    for line in &lines {
+        black_box(Zemen::parse_bytes(black_box(line.as_bytes()), &description).ok());
+    }
+    let elapsed = now.elapsed();
+
+    println!(
+        "parse_bytes: {DAYS} dates in {elapsed:?}, {:.0} ns/date, {:.1} M dates/s",
+        elapsed.as_nanos() as f64 / DAYS as f64,
+        DAYS as f64 / elapsed.as_secs_f64() / 1e6,
+    );
+}
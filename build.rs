@@ -0,0 +1,21 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let mut unstable: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .filter(|feature| feature.starts_with("UNSTABLE"))
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+
+    if !unstable.is_empty() {
+        unstable.sort();
+        println!(
+            "cargo:warning=zemen is built with unstable features ({}), their APIs may change in any release",
+            unstable.join(", ")
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Plain text agendas, e.g. for chat bots.
+
+use crate::{Locale, Zemen};
+
+/// Write out the week starting on `start` as plain text, one line per day
+/// with its weekday, date, and events.
+///
+/// Events are `(date, description)` pairs, e.g. meetings or holidays, and
+/// events outside of the week are ignored. The weekday and date columns are
+/// padded to the same width on every line, so the events line up in a
+/// monospaced font, and days without events get a `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{agenda, error, Locale, Zemen, Werh};
+/// let start = Zemen::from_eth_cal(2015, Werh::Tir, 8)?;
+/// let events = [
+///     (Zemen::from_eth_cal(2015, Werh::Tir, 11)?, "Timket"),
+///     (Zemen::from_eth_cal(2015, Werh::Tir, 9)?, "Standup"),
+///     (Zemen::from_eth_cal(2015, Werh::Tir, 11)?, "Family lunch"),
+/// ];
+///
+/// let text = agenda::weekly_text(&start, &events, Locale::English);
+/// assert_eq!(
+///     text,
+///     "\
+/// Monday     Tir 8   -
+/// Tuesday    Tir 9   Standup
+/// Wednesday  Tir 10  -
+/// Thursday   Tir 11  Timket, Family lunch
+/// Friday     Tir 12  -
+/// Saturday   Tir 13  -
+/// Sunday     Tir 14  -
+/// "
+/// );
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn weekly_text<S: AsRef<str>>(start: &Zemen, events: &[(Zemen, S)], locale: Locale) -> String {
+    let days: Vec<_> = start
+        .iter_days_until(&(*start + 7))
+        .map(|qen| {
+            let weekday = locale.weekday_name(qen.weekday());
+            let date = format!("{} {}", locale.month_name(qen.month()), qen.day());
+            let todo: Vec<_> = events
+                .iter()
+                .filter(|(day, _)| *day == qen)
+                .map(|(_, event)| event.as_ref())
+                .collect();
+
+            (weekday, date, todo)
+        })
+        .collect();
+
+    let weekday_width = days
+        .iter()
+        .map(|(w, _, _)| w.chars().count())
+        .max()
+        .unwrap_or(0);
+    let date_width = days
+        .iter()
+        .map(|(_, d, _)| d.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut text = String::new();
+    for (weekday, date, todo) in days {
+        let todo = if todo.is_empty() {
+            "-".to_string()
+        } else {
+            todo.join(", ")
+        };
+
+        text.push_str(&format!(
+            "{}  {}  {}\n",
+            pad(&weekday, weekday_width),
+            pad(&date, date_width),
+            todo
+        ));
+    }
+
+    text
+}
+
+/// Pad `s` with spaces up to `width` characters.
+fn pad(s: &str, width: usize) -> String {
+    let mut padded = s.to_string();
+    padded.extend(std::iter::repeat(' ').take(width.saturating_sub(s.chars().count())));
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_amharic_columns_line_up() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Puagme, 3)?;
+        let events = [(Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?, "እንቁጣጣሽ")];
+
+        let text = weekly_text(&start, &events, Locale::Amharic);
+        let lines: Vec<_> = text.lines().collect();
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "ዓርብ   ጳጉሜ 3    -");
+        assert_eq!(lines[4], "ማክሰኞ  መስከረም 1  እንቁጣጣሽ");
+        for line in lines {
+            let events: String = line.chars().skip(15).collect();
+            assert!(events == "-" || events == "እንቁጣጣሽ", "{line}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+//! Keyed date shifting for sharing datasets without their real dates.
+//!
+//! Every date shifted with the same key moves by the same number of days, so
+//! the intervals between the dates of, e.g., one patient are kept. Shifts are
+//! whole weeks, which keeps every date on its weekday and, with a window of a
+//! few weeks, close to its month and season.
+//!
+//! The shift is derived from the key with a fast, non cryptographic hash. The
+//! key should include a secret that isn't shared along with the data,
+//! otherwise the shifts can be recomputed:
+//!
+//! ```rust
+//! # use zemen::{anonymize, error, Zemen, Werh};
+//! let secret = "kept out of the export";
+//! let key = format!("{}:{}", secret, "patient-0042");
+//!
+//! let visit = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//! let follow_up = Zemen::from_eth_cal(2015, Werh::Yekatit, 2)?;
+//!
+//! let shifted_visit = anonymize::anonymize(&visit, key.as_bytes(), 28)?;
+//! let shifted_follow_up = anonymize::anonymize(&follow_up, key.as_bytes(), 28)?;
+//!
+//! assert_eq!(shifted_visit.weekday(), visit.weekday());
+//! assert_eq!(
+//!     shifted_follow_up.to_jdn() - shifted_visit.to_jdn(),
+//!     follow_up.to_jdn() - visit.to_jdn()
+//! );
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use crate::{error, Zemen};
+
+/// Get the number of days dates are shifted by for `key`: a non-zero
+/// multiple of 7 between `-window` and `window`.
+///
+/// Fails if `window` is shorter than a week.
+pub fn shift(key: &[u8], window: u16) -> Result<i32, error::Error> {
+    error::is_in_range(window as i32, 7, u16::MAX as i32, "window")?;
+
+    let weeks = (window / 7) as u64;
+    // pick one of the 2 * weeks non-zero shifts
+    let pick = (hash(key) % (2 * weeks)) as i32;
+    let weeks = weeks as i32;
+
+    let shift = if pick < weeks {
+        pick - weeks
+    } else {
+        pick - weeks + 1
+    };
+
+    Ok(shift * 7)
+}
+
+/// Shift `qen` by the number of days given by [`shift`].
+pub fn anonymize(qen: &Zemen, key: &[u8], window: u16) -> Result<Zemen, error::Error> {
+    Zemen::from_jdn(qen.to_jdn() + shift(key, window)?)
+}
+
+/// FNV-1a followed by the SplitMix64 finalizer, so similar keys still get
+/// unrelated shifts. Fixed here, rather than using `std`'s hashers, so shifts
+/// are stable across Rust and crate versions.
+fn hash(key: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in key {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_stays_in_window() {
+        for id in 0..1000 {
+            let key = format!("secret:{}", id);
+            let days = shift(key.as_bytes(), 30).unwrap();
+
+            assert_ne!(days, 0);
+            assert_eq!(days % 7, 0);
+            assert!((-30..=30).contains(&days));
+        }
+
+        assert!(shift(b"secret:1", 6).is_err());
+    }
+
+    #[test]
+    fn test_shift_is_stable() {
+        assert_eq!(
+            shift(b"secret:patient-1", 70).unwrap(),
+            shift(b"secret:patient-1", 70).unwrap()
+        );
+
+        let shifts: std::collections::HashSet<_> = (0..100)
+            .map(|id| shift(format!("secret:{}", id).as_bytes(), 70).unwrap())
+            .collect();
+        assert_eq!(shifts.len(), 20);
+    }
+}
@@ -0,0 +1,124 @@
+//! Arrow dates for analytics pipelines, with
+//! [arrow-array](https://docs.rs/arrow-array).
+//!
+//! An Arrow `Date32` is the days since the Unix epoch, the same as
+//! [`Zemen::to_epoch_day`], and a `Date64` is the milliseconds since the Unix
+//! epoch. A `Zemen` is written as the `Date64` of its midnight UTC, and any
+//! `Date64` is read back as the UTC day it falls on.
+//!
+//! Whole arrays are converted with [`to_zemen`] and [`to_date32_array`],
+//! keeping their nulls.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use arrow_array::Date32Array;
+//! # use zemen::{arrow, error, Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//! assert_eq!(arrow::to_date32(&qen), 19_375);
+//! assert_eq!(arrow::to_date64(&qen), 1_674_000_000_000);
+//!
+//! let array = Date32Array::from(vec![Some(19_375), None, Some(19_376)]);
+//! let dates = arrow::to_zemen(&array)?;
+//! assert_eq!(dates, [Some(qen), None, Some(qen + 1)]);
+//!
+//! let back = arrow::to_date32_array(dates);
+//! assert_eq!(back, array);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use arrow_array::Date32Array;
+
+use crate::{error, Zemen};
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Get the `Date32` of `qen`, the days since the Unix epoch.
+pub fn to_date32(qen: &Zemen) -> i32 {
+    qen.to_epoch_day()
+}
+
+/// Create a `Zemen` from a `Date32`, failing if it's beyond the range of
+/// `Zemen`.
+pub fn from_date32(days: i32) -> Result<Zemen, error::Error> {
+    Zemen::from_epoch_day(days)
+}
+
+/// Get the `Date64` of `qen`, the milliseconds since the Unix epoch at
+/// midnight UTC.
+pub fn to_date64(qen: &Zemen) -> i64 {
+    qen.to_epoch_day() as i64 * MILLIS_PER_DAY
+}
+
+/// Create a `Zemen` from the UTC day of a `Date64`, failing if it's beyond
+/// the range of `Zemen`.
+pub fn from_date64(millis: i64) -> Result<Zemen, error::Error> {
+    let days = i32::try_from(millis.div_euclid(MILLIS_PER_DAY))
+        .map_err(|_| error::Error::InvalidVariant("Date64", millis.to_string()))?;
+
+    Zemen::from_epoch_day(days)
+}
+
+/// Convert every value of `array`, keeping its nulls, failing on the first
+/// value beyond the range of `Zemen`.
+pub fn to_zemen(array: &Date32Array) -> Result<Vec<Option<Zemen>>, error::Error> {
+    array
+        .iter()
+        .map(|days| days.map(from_date32).transpose())
+        .collect()
+}
+
+/// Create a `Date32Array` of `dates`, `None`s being nulls.
+pub fn to_date32_array<I>(dates: I) -> Date32Array
+where
+    I: IntoIterator<Item = Option<Zemen>>,
+{
+    dates
+        .into_iter()
+        .map(|qen| qen.as_ref().map(to_date32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_values_round_trip() -> Result<(), error::Error> {
+        let epoch = Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?;
+        assert_eq!((to_date32(&epoch), to_date64(&epoch)), (0, 0));
+        assert_eq!(from_date64(-1)?, epoch - 1);
+        assert_eq!(from_date64(MILLIS_PER_DAY - 1)?, epoch);
+
+        for qen in [Zemen::MIN, Zemen::MAX, epoch - 1] {
+            assert_eq!(from_date32(to_date32(&qen))?, qen);
+            assert_eq!(from_date64(to_date64(&qen))?, qen);
+        }
+        assert!(from_date32(i32::MAX).is_err());
+        assert!(from_date64(i64::MIN).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_arrays() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Puagme, 1)?;
+        let dates: Vec<_> = start
+            .iter_days_until(&(start + 10))
+            .map(|qen| (qen.day() % 3 != 0).then_some(qen))
+            .collect();
+
+        let array = to_date32_array(dates.clone());
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.null_count(), 3);
+        assert_eq!(to_zemen(&array)?, dates);
+
+        let broken = Date32Array::from(vec![Some(0), Some(i32::MAX)]);
+        assert!(to_zemen(&broken).is_err());
+
+        Ok(())
+    }
+}
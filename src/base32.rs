@@ -0,0 +1,117 @@
+//! Short base32 codes for dates, e.g. `2B2NMM`, to print on tickets and in
+//! SMS messages.
+//!
+//! A code is the Julian day number of the date written with five Crockford
+//! base32 digits, followed by one check digit. The Crockford alphabet leaves
+//! out `I`, `L`, `O`, and `U`, so codes survive being read out or copied by
+//! hand: parsing ignores case, dashes, and spaces, and reads `O` as `0` and
+//! `I` or `L` as `1`.
+
+use crate::{error, Zemen};
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const DIGITS: usize = 5;
+/// Odd, so changing any single digit changes the check digit.
+const WEIGHTS: [u32; DIGITS] = [1, 3, 5, 7, 9];
+
+pub(crate) fn encode(qen: &Zemen) -> Result<String, error::Error> {
+    let jdn = qen.to_jdn();
+    error::is_in_range(jdn, 0, (1 << (5 * DIGITS)) - 1, "jdn")?;
+
+    let mut digits = [0u32; DIGITS];
+    for (i, digit) in digits.iter_mut().rev().enumerate() {
+        *digit = (jdn as u32 >> (5 * i)) & 31;
+    }
+
+    Ok(digits
+        .iter()
+        .chain([checksum(&digits)].iter())
+        .map(|digit| ALPHABET[*digit as usize] as char)
+        .collect())
+}
+
+pub(crate) fn decode(code: &str) -> Result<Zemen, error::Error> {
+    let invalid = || error::Error::InvalidVariant("base32 code", code.to_string());
+
+    let digits = code
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| value_of(c).ok_or_else(invalid))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let Some((check, digits)) = digits.split_last() else {
+        return Err(invalid());
+    };
+    if digits.len() != DIGITS || checksum(digits) != *check {
+        return Err(invalid());
+    }
+
+    let jdn = digits.iter().fold(0, |jdn, digit| jdn << 5 | digit);
+    Zemen::from_jdn(jdn as i32)
+}
+
+fn checksum(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip(WEIGHTS)
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>()
+        % 32
+}
+
+fn value_of(c: char) -> Option<u32> {
+    let c = match c.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        c => c,
+    };
+
+    ALPHABET
+        .iter()
+        .position(|digit| *digit as char == c)
+        .map(|value| value as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_codes_round_trip() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(1990, Werh::Meskerem, 1)?;
+
+        for days in (0..20_000).step_by(13) {
+            let qen = start + days;
+            let code = encode(&qen)?;
+
+            assert_eq!(code.len(), 6);
+            assert_eq!(decode(&code)?, qen);
+            assert_eq!(decode(&code.to_lowercase())?, qen);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_typos_are_caught() -> Result<(), error::Error> {
+        let code = encode(&Zemen::from_eth_cal(2016, Werh::Tir, 21)?)?;
+
+        for i in 0..code.len() {
+            for digit in ALPHABET.iter().map(|d| *d as char) {
+                let mut typo: Vec<char> = code.chars().collect();
+                if typo[i] == digit {
+                    continue;
+                }
+
+                typo[i] = digit;
+                assert!(decode(&typo.iter().collect::<String>()).is_err());
+            }
+        }
+
+        assert!(decode("").is_err());
+        assert!(decode(&code[1..]).is_err());
+
+        Ok(())
+    }
+}
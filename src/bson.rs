@@ -0,0 +1,242 @@
+//! Storing `Zemen` in MongoDB documents with [bson](https://docs.rs/bson).
+//!
+//! There are two forms, both with a module for `#[serde(with = "...")]`:
+//!
+//! - [`date_time`]: a BSON `DateTime` at **midnight UTC** of the date, for
+//!   collections that are also read as Gregorian dates or queried with
+//!   date operators. A `DateTime` is read back as the UTC day it falls on.
+//! - [`tagged`]: the canonical `{ "$zemen": "YYYY-MM-DD" }` document of the
+//!   `Display` form, which keeps the Ethiopian date as it was written. The
+//!   strings sort like the dates for the years 0 to 9999, but a dotted path
+//!   can't reach a field starting with `$`, so a range query has to read it
+//!   with `$getField` in an `$expr`, e.g. `{ "$lt": [{ "$getField": { "field":
+//!   { "$literal": "$zemen" }, "input": "$field" } }, "2016-01-01"] }`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use bson::doc;
+//! # use serde::{Deserialize, Serialize};
+//! # use zemen::{error, Werh, Zemen};
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(with = "zemen::bson::tagged")]
+//!     issued: Zemen,
+//!     #[serde(with = "zemen::bson::date_time")]
+//!     due: Zemen,
+//! }
+//!
+//! let invoice = Invoice {
+//!     issued: Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+//!     due: Zemen::from_eth_cal(2015, Werh::Yekatit, 10)?,
+//! };
+//! let document = bson::to_document(&invoice).unwrap();
+//!
+//! assert_eq!(
+//!     document,
+//!     doc! {
+//!         "issued": { "$zemen": "2015-05-10" },
+//!         "due": bson::DateTime::from_millis(1_676_592_000_000),
+//!     }
+//! );
+//! assert_eq!(bson::from_document::<Invoice>(document).unwrap(), invoice);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use ::bson::{Bson, DateTime, Document};
+
+use crate::{error, Zemen};
+
+/// The key of the canonical document, see [`tagged`].
+pub const KEY: &str = "$zemen";
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+
+impl From<Zemen> for DateTime {
+    /// Get the `DateTime` of midnight UTC of the date.
+    fn from(qen: Zemen) -> Self {
+        DateTime::from_millis((qen.to_jdn() as i64 - UNIX_EPOCH_JDN) * MILLIS_PER_DAY)
+    }
+}
+
+impl TryFrom<DateTime> for Zemen {
+    type Error = error::Error;
+
+    /// Get the UTC day `date_time` falls on, failing if it's beyond the
+    /// range of `Zemen`.
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let millis = date_time.timestamp_millis();
+        let jdn = millis.div_euclid(MILLIS_PER_DAY) + UNIX_EPOCH_JDN;
+        let jdn = i32::try_from(jdn)
+            .map_err(|_| error::Error::InvalidVariant("DateTime", millis.to_string()))?;
+
+        Zemen::from_jdn(jdn)
+    }
+}
+
+impl From<Zemen> for Bson {
+    /// Get the canonical `{ "$zemen": "YYYY-MM-DD" }` document.
+    fn from(qen: Zemen) -> Self {
+        Bson::Document(to_document(&qen))
+    }
+}
+
+impl TryFrom<Bson> for Zemen {
+    type Error = error::Error;
+
+    /// Read a canonical document, or a `DateTime` as its UTC day.
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::Document(document) => from_document(&document),
+            Bson::DateTime(date_time) => Zemen::try_from(date_time),
+            value => Err(error::Error::InvalidVariant("BSON date", value.to_string())),
+        }
+    }
+}
+
+/// Get the canonical `{ "$zemen": "YYYY-MM-DD" }` document of `qen`.
+pub fn to_document(qen: &Zemen) -> Document {
+    let mut document = Document::new();
+    document.insert(KEY, qen.to_string());
+    document
+}
+
+/// Read a canonical `{ "$zemen": "YYYY-MM-DD" }` document, failing if it
+/// has any other key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bson::doc;
+/// # use zemen::{error, Werh, Zemen};
+/// let qen = zemen::bson::from_document(&doc! { "$zemen": "2015-13-06" })?;
+/// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+///
+/// assert!(zemen::bson::from_document(&doc! { "$zemen": "2015-13-06", "note": 1 }).is_err());
+/// assert!(zemen::bson::from_document(&doc! { "$zemen": 2015 }).is_err());
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn from_document(document: &Document) -> Result<Zemen, error::Error> {
+    let invalid = || error::Error::InvalidVariant("$zemen document", document.to_string());
+
+    if document.len() != 1 {
+        return Err(invalid());
+    }
+
+    document.get_str(KEY).map_err(|_| invalid())?.parse()
+}
+
+/// Serialize and deserialize a `Zemen` as a BSON `DateTime` at midnight
+/// UTC, see the [module documentation](self).
+pub mod date_time {
+    use ::bson::DateTime;
+    use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Zemen;
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        DateTime::from(*qen).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        let date_time = DateTime::deserialize(deserializer)?;
+        Zemen::try_from(date_time).map_err(de::Error::custom)
+    }
+}
+
+/// Serialize and deserialize a `Zemen` as the canonical
+/// `{ "$zemen": "YYYY-MM-DD" }` document, see the
+/// [module documentation](self).
+pub mod tagged {
+    use ::serde::ser::SerializeMap;
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::Zemen;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Tagged {
+        #[serde(rename = "$zemen")]
+        zemen: String,
+    }
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(super::KEY, &qen.to_string())?;
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        let tagged = Tagged::deserialize(deserializer)?;
+        tagged.zemen.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::bson::doc;
+
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_date_times_are_utc_midnight() -> Result<(), error::Error> {
+        let epoch = Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?;
+        assert_eq!(DateTime::from(epoch), DateTime::from_millis(0));
+        assert_eq!(Zemen::try_from(DateTime::from_millis(-1))?, epoch - 1);
+        assert_eq!(
+            Zemen::try_from(DateTime::from_millis(MILLIS_PER_DAY - 1))?,
+            epoch
+        );
+
+        for qen in [Zemen::MIN, Zemen::MAX] {
+            assert_eq!(Zemen::try_from(DateTime::from(qen))?, qen);
+        }
+        assert!(Zemen::try_from(DateTime::MAX).is_err());
+        assert!(Zemen::try_from(DateTime::MIN).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bson_values() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?;
+
+        assert_eq!(
+            Bson::from(qen),
+            Bson::Document(doc! { "$zemen": "2016-01-01" })
+        );
+        assert_eq!(Zemen::try_from(Bson::from(qen))?, qen);
+        assert_eq!(Zemen::try_from(Bson::DateTime(qen.into()))?, qen);
+        assert!(Zemen::try_from(Bson::String("2016-01-01".to_string())).is_err());
+        assert!(Zemen::try_from(Bson::Document(doc! {})).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_helpers_reject_other_forms() {
+        #[derive(::serde::Deserialize, Debug)]
+        struct Letter {
+            #[allow(dead_code)]
+            #[serde(with = "tagged")]
+            written: Zemen,
+        }
+
+        for document in [
+            doc! { "written": "2016-01-01" },
+            doc! { "written": { "$zemen": "2016-01-01", "by": "hand" } },
+            doc! { "written": { "$zemen": "2016-13-07" } },
+        ] {
+            assert!(::bson::from_document::<Letter>(document).is_err());
+        }
+
+        let document = doc! { "written": { "$zemen": "2015-13-06" } };
+        let letter: Letter = ::bson::from_document(document).unwrap();
+        assert_eq!(
+            letter.written,
+            Zemen::from_eth_cal(2015, Werh::Puagme, 6).unwrap()
+        );
+    }
+}
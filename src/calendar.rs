@@ -0,0 +1,309 @@
+//! Layout helpers for wall calendar style month views.
+
+use crate::{conversion, error, Period, Samint, Werh, Zemen};
+
+/// Get the weeks of `werh` in `year` as rows of 7 days, the first column
+/// being `week_start`.
+///
+/// Days before the first and after the last day of the month are `None`, so
+/// the rows can be drawn as they are by GUI or TUI calendar widgets. See
+/// [`month_grid_spillover`] to fill them with the neighbouring months' days
+/// instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{calendar, error, Samint, Zemen, Werh};
+/// let grid = calendar::month_grid(2015, Werh::Puagme, Samint::Senyo);
+///
+/// let days: Vec<Vec<_>> = grid
+///     .iter()
+///     .map(|week| week.iter().map(|qen| qen.as_ref().map(Zemen::day)).collect())
+///     .collect();
+///
+/// assert_eq!(
+///     days,
+///     [
+///         [None, None, Some(1), Some(2), Some(3), Some(4), Some(5)],
+///         [Some(6), None, None, None, None, None, None],
+///     ]
+/// );
+/// # Ok::<(), error::Error>(())
+/// ```
+///
+/// # Panics
+///
+/// Panics if `year` is beyond the years of [`Zemen::MIN`] and [`Zemen::MAX`].
+pub fn month_grid(year: i32, werh: Werh, week_start: Samint) -> Vec<[Option<Zemen>; 7]> {
+    let first = first_day(year, werh);
+    let last = first.to_jdn() + werh.num_days(year) as i32 - 1;
+
+    month_grid_spillover(year, werh, week_start)
+        .into_iter()
+        .map(|week| {
+            week.map(|qen| {
+                let jdn = qen.to_jdn();
+                (first.to_jdn()..=last).contains(&jdn).then_some(qen)
+            })
+        })
+        .collect()
+}
+
+/// Like [`month_grid`], but the days before and after the month are the
+/// last days of the previous month and the first days of the next one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{calendar, error, Samint, Zemen, Werh};
+/// let grid = calendar::month_grid_spillover(2015, Werh::Puagme, Samint::Senyo);
+///
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid[0][0], Zemen::from_eth_cal(2015, Werh::Nehase, 29)?);
+/// assert_eq!(grid[1][6], Zemen::from_eth_cal(2016, Werh::Meskerem, 6)?);
+/// # Ok::<(), error::Error>(())
+/// ```
+///
+/// # Panics
+///
+/// Panics if `year` is beyond the years of [`Zemen::MIN`] and [`Zemen::MAX`],
+/// or the grid spills past either of them.
+pub fn month_grid_spillover(year: i32, werh: Werh, week_start: Samint) -> Vec<[Zemen; 7]> {
+    let first = first_day(year, werh);
+    let leading = (first.weekday() as i32 - week_start as i32).rem_euclid(7);
+    let weeks = (leading + werh.num_days(year) as i32 + 6) / 7;
+
+    let start = first + -leading;
+    (0..weeks)
+        .map(|week| std::array::from_fn(|day| start + (week * 7 + day as i32)))
+        .collect()
+}
+
+/// A week of a month, cut off at the month's first and last day.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WerhWeek {
+    number: u8,
+    period: Period,
+}
+
+impl WerhWeek {
+    /// Get the number of the week in the month, starting at 1.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Get the days of the week that are in the month.
+    pub fn period(&self) -> &Period {
+        &self.period
+    }
+
+    /// Get the number of days of the week that are in the month.
+    pub fn len_days(&self) -> i32 {
+        // a week has at most 7 days
+        self.period.len_days() as i32
+    }
+
+    /// Returns `true` if part of the week is in the previous or next month.
+    pub fn is_partial(&self) -> bool {
+        self.len_days() < 7
+    }
+}
+
+/// Split `werh` of `year` into weeks starting on `week_start`, keeping only
+/// the days in the month, so partial weeks at either end can be weighted by
+/// their length, e.g. when prorating timesheets.
+///
+/// Fails if the month is beyond the range of `Zemen`, including the Puagme
+/// of [`Zemen::MAX`], whose last week ends on a day `Zemen` can't hold.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{calendar, error, Samint, Werh};
+/// let weeks = calendar::werh_weeks(2015, Werh::Tir, Samint::Ihud)?;
+/// let lengths: Vec<_> = weeks.iter().map(|week| week.len_days()).collect();
+///
+/// assert_eq!(lengths, [6, 7, 7, 7, 3]);
+/// assert!(weeks[0].is_partial() && weeks[4].is_partial());
+/// assert!(!weeks[1].is_partial());
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn werh_weeks(
+    year: i32,
+    werh: Werh,
+    week_start: Samint,
+) -> Result<Vec<WerhWeek>, error::Error> {
+    let first = Zemen::from_eth_cal(year, werh, 1)?;
+    let end = first.to_jdn() + werh.num_days(year) as i32;
+    let leading = (first.weekday() as i32 - week_start as i32).rem_euclid(7);
+
+    let grid_start = first.to_jdn() - leading;
+    let weeks = (leading + werh.num_days(year) as i32 + 6) / 7;
+
+    (0..weeks)
+        .map(|week| {
+            let start = (grid_start + 7 * week).max(first.to_jdn());
+            let week_end = (grid_start + 7 * (week + 1)).min(end);
+
+            Ok(WerhWeek {
+                number: week as u8 + 1,
+                period: Period::from_jdn(start, week_end)?,
+            })
+        })
+        .collect()
+}
+
+/// How the weeks of a year are numbered, see [`Zemen::week_of_year`].
+///
+/// Offices don't agree on this, so pick the one the report or form at hand
+/// uses, and pass the same one to [`week_numbers`], to
+/// [`Zemen::try_format_weeks`], and to the grids, through
+/// [`WeekNumbering::week_start`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WeekNumbering {
+    /// Weeks start on the given day, and week 1 is the one Meskerem 1 falls
+    /// in, even if the week starts in the previous year.
+    Ethiopian(Samint),
+    /// The ISO 8601 week of the Gregorian date. Weeks start on ሰኞ, and week 1
+    /// of a Gregorian year is the one holding its first Thursday.
+    Iso,
+}
+
+impl WeekNumbering {
+    /// Get the day weeks start on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{calendar::WeekNumbering, Samint};
+    /// assert_eq!(WeekNumbering::Iso.week_start(), Samint::Senyo);
+    /// assert_eq!(WeekNumbering::Ethiopian(Samint::Ihud).week_start(), Samint::Ihud);
+    /// ```
+    pub fn week_start(&self) -> Samint {
+        match self {
+            WeekNumbering::Ethiopian(week_start) => *week_start,
+            WeekNumbering::Iso => Samint::Senyo,
+        }
+    }
+}
+
+/// Get the week of every row of [`month_grid`], drawn with the weeks of
+/// `numbering`, as the year the week belongs to and its number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{calendar::{self, WeekNumbering}, Samint, Werh};
+/// let numbering = WeekNumbering::Iso;
+/// let grid = calendar::month_grid(2015, Werh::Tir, numbering.week_start());
+/// let weeks = calendar::week_numbers(2015, Werh::Tir, numbering);
+///
+/// assert_eq!(weeks.len(), grid.len());
+/// assert_eq!(weeks[0], (2023, 2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `year` is beyond the years of [`Zemen::MIN`] and [`Zemen::MAX`].
+pub fn week_numbers(year: i32, werh: Werh, numbering: WeekNumbering) -> Vec<(i32, u8)> {
+    month_grid(year, werh, numbering.week_start())
+        .iter()
+        .filter_map(|week| week.iter().flatten().next())
+        .map(|qen| week_of_year(qen, numbering))
+        .collect()
+}
+
+pub(crate) fn week_of_year(qen: &Zemen, numbering: WeekNumbering) -> (i32, u8) {
+    match numbering {
+        WeekNumbering::Ethiopian(week_start) => {
+            let first = qen.start_of_year();
+            let leading = (first.weekday() as i32 - week_start as i32).rem_euclid(7);
+
+            (
+                qen.year(),
+                ((qen.ordinal() as i32 - 1 + leading) / 7 + 1) as u8,
+            )
+        }
+        WeekNumbering::Iso => {
+            // the ISO week and its year are the ones of the week's Thursday
+            let jdn = qen.to_jdn();
+            let thursday = jdn - jdn.rem_euclid(7) + 3;
+            let (year, _, _) = conversion::jdn_to_gre(thursday);
+
+            (
+                year,
+                ((thursday - conversion::gre_to_jdn(year, 1, 1)) / 7 + 1) as u8,
+            )
+        }
+    }
+}
+
+#[allow(clippy::expect_used)]
+fn first_day(year: i32, werh: Werh) -> Zemen {
+    Zemen::from_eth_cal(year, werh, 1).expect("`year` is beyond the years of `Zemen`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_week_numbers() -> Result<(), crate::error::Error> {
+        let start = Zemen::from_eth_cal(2010, Werh::Meskerem, 1)?;
+        let mut previous = None;
+
+        for qen in start.iter_days_until(&(start + 8 * 366)) {
+            let iso = week_of_year(&qen, WeekNumbering::Iso);
+            let monday = qen + -(((qen.weekday() as i32) + 6) % 7);
+            assert_eq!(week_of_year(&monday, WeekNumbering::Iso), iso);
+            assert!((1..=53).contains(&iso.1));
+
+            let ethiopian = week_of_year(&qen, WeekNumbering::Ethiopian(Samint::Ihud));
+            if qen.ordinal() == 1 {
+                assert_eq!(ethiopian, (qen.year(), 1));
+            } else if qen.weekday() == Samint::Ihud {
+                assert_eq!(
+                    previous.map(|(year, week)| (year, week + 1)),
+                    Some(ethiopian)
+                );
+            } else {
+                assert_eq!(previous, Some(ethiopian));
+            }
+            previous = Some(ethiopian);
+        }
+
+        // 2022-01-01 is a Saturday in week 52 of 2021, 2026-12-31 in week 53
+        let new_year = Zemen::from_jdn(conversion::gre_to_jdn(2022, 1, 1))?;
+        assert_eq!(week_of_year(&new_year, WeekNumbering::Iso), (2021, 52));
+        let last = Zemen::from_jdn(conversion::gre_to_jdn(2026, 12, 31))?;
+        assert_eq!(week_of_year(&last, WeekNumbering::Iso), (2026, 53));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grids_hold_every_day_once() {
+        for year in [2015, 2016] {
+            for werh in Werh::iter() {
+                for week_start in Samint::iter() {
+                    let grid = month_grid(year, werh, week_start);
+                    let days: Vec<_> = grid.iter().flatten().flatten().cloned().collect();
+
+                    assert_eq!(days, werh.days_iter(year).collect::<Vec<_>>());
+                    assert!(grid.iter().all(|week| week.iter().any(Option::is_some)));
+
+                    for week in month_grid_spillover(year, werh, week_start) {
+                        assert_eq!(week[0].weekday(), week_start);
+                    }
+
+                    let weeks = werh_weeks(year, werh, week_start).unwrap();
+                    assert_eq!(weeks.len(), grid.len());
+                    for (week, row) in weeks.iter().zip(&grid) {
+                        assert_eq!(week.len_days() as usize, row.iter().flatten().count());
+                        assert_eq!(Some(week.period().start()), row.iter().flatten().next());
+                    }
+                }
+            }
+        }
+    }
+}
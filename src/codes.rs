@@ -0,0 +1,231 @@
+//! Period codes of budgeting and reporting exports, e.g. `2016/Q2/Tir`.
+//!
+//! A code is the year, the quarter, and the latin name of the month,
+//! separated by `/`. How months are grouped into years and quarters depends
+//! on the [`CodeScheme`]:
+//!
+//! ```txt
+//!          Calendar                    Fiscal
+//! Q1       Meskerem .. Hedar           Hamle .. Meskerem
+//! Q2       Tahasass .. Yekatit         Tikimit .. Tahasass
+//! Q3       Megabit  .. Ginbot          Tir .. Megabit
+//! Q4       Sene .. Nehase, Puagme      Miyazia .. Sene
+//! ```
+//!
+//! Puagme belongs to the quarter of Nehase. Fiscal years are named after the
+//! year they end in, so Hamle 2015 is in the fiscal year 2016.
+
+use crate::{error, Period, Werh, Zemen};
+
+/// How months are grouped into years and quarters, see the
+/// [module documentation](self).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CodeScheme {
+    /// The calendar year, from Meskerem 1.
+    #[default]
+    Calendar,
+    /// The government fiscal year, from Hamle 1 to Sene 30.
+    Fiscal,
+}
+
+impl CodeScheme {
+    /// Get the year of `qen` in this scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{codes::CodeScheme, error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Hamle, 1)?;
+    ///
+    /// assert_eq!(CodeScheme::Calendar.year(&qen), 2015);
+    /// assert_eq!(CodeScheme::Fiscal.year(&qen), 2016);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn year(self, qen: &Zemen) -> i32 {
+        match (self, qen.month()) {
+            (CodeScheme::Fiscal, Werh::Hamle | Werh::Nehase | Werh::Puagme) => qen.year() + 1,
+            _ => qen.year(),
+        }
+    }
+
+    /// Get the quarter, 1 to 4, of `month` in this scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{codes::CodeScheme, Werh};
+    /// assert_eq!(CodeScheme::Calendar.quarter(Werh::Tir), 2);
+    /// assert_eq!(CodeScheme::Fiscal.quarter(Werh::Tir), 3);
+    /// assert_eq!(CodeScheme::Fiscal.quarter(Werh::Puagme), 1);
+    /// ```
+    pub fn quarter(self, month: Werh) -> u8 {
+        // months counted from 0, from the first month of the year
+        let index = match (self, month) {
+            (_, Werh::Puagme) => return self.quarter(Werh::Nehase),
+            (CodeScheme::Calendar, month) => month as u8 - 1,
+            (CodeScheme::Fiscal, Werh::Hamle) => 0,
+            (CodeScheme::Fiscal, Werh::Nehase) => 1,
+            (CodeScheme::Fiscal, month) => month as u8 + 1,
+        };
+
+        index / 3 + 1
+    }
+}
+
+/// Get the period code of the month `qen` is in, e.g. `2016/Q2/Tir`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{codes::{self, CodeScheme}, error, Zemen, Werh};
+/// let qen = Zemen::from_eth_cal(2016, Werh::Tir, 10)?;
+/// assert_eq!(codes::period_code(&qen, CodeScheme::Calendar), "2016/Q2/Tir");
+/// assert_eq!(codes::period_code(&qen, CodeScheme::Fiscal), "2016/Q3/Tir");
+///
+/// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 3)?;
+/// assert_eq!(codes::period_code(&qen, CodeScheme::Fiscal), "2016/Q1/Puagme");
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn period_code(qen: &Zemen, scheme: CodeScheme) -> String {
+    let month = qen.month();
+
+    format!(
+        "{}/Q{}/{}",
+        scheme.year(qen),
+        scheme.quarter(month),
+        month.latin_name()
+    )
+}
+
+/// Parse a period code written by [`period_code`] into the days of its
+/// month.
+///
+/// The month name is read like [`Werh`]'s `FromStr`, so it ignores case and
+/// can be in Amharic. Fails if the code doesn't have three parts, or the
+/// quarter isn't the one of the month in `scheme`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{codes::{self, CodeScheme}, error, Zemen, Werh};
+/// let hamle = codes::parse_period_code("2016/Q1/Hamle", CodeScheme::Fiscal)?;
+/// assert_eq!(hamle.start(), &Zemen::from_eth_cal(2015, Werh::Hamle, 1)?);
+/// assert_eq!(hamle.len_days(), 30);
+///
+/// assert!(codes::parse_period_code("2016/Q1/Hamle", CodeScheme::Calendar).is_err());
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn parse_period_code(code: &str, scheme: CodeScheme) -> Result<Period, error::Error> {
+    let invalid = || error::Error::InvalidVariant("period code", code.to_string());
+
+    let mut parts = code.trim().split('/');
+    let (Some(year), Some(quarter), Some(month), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let quarter: u8 = quarter
+        .strip_prefix(['Q', 'q'])
+        .and_then(|quarter| quarter.parse().ok())
+        .ok_or_else(invalid)?;
+    let month: Werh = month.parse()?;
+
+    if quarter != scheme.quarter(month) {
+        return Err(invalid());
+    }
+
+    let year = match (scheme, month) {
+        (CodeScheme::Fiscal, Werh::Hamle | Werh::Nehase | Werh::Puagme) => {
+            year.checked_sub(1).ok_or_else(invalid)?
+        }
+        _ => year,
+    };
+    let first = Zemen::from_eth_cal(year, month, 1)?.to_jdn();
+
+    Period::from_jdn(first, first + month.num_days(year) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() -> Result<(), error::Error> {
+        for scheme in [CodeScheme::Calendar, CodeScheme::Fiscal] {
+            for year in [2015, 2016] {
+                for month in Werh::iter() {
+                    let qen = Zemen::from_eth_cal(year, month, 1)?;
+                    let code = period_code(&qen, scheme);
+                    let period = parse_period_code(&code, scheme)?;
+
+                    assert_eq!(period.start(), &qen, "{code}");
+                    assert_eq!(period.len_days(), month.num_days(year) as i64, "{code}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quarters() {
+        let quarters = |scheme: CodeScheme| {
+            Werh::iter()
+                .map(|month| scheme.quarter(month))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            quarters(CodeScheme::Calendar),
+            [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 4]
+        );
+        assert_eq!(
+            quarters(CodeScheme::Fiscal),
+            [1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_codes_at_the_end_of_zemen() -> Result<(), error::Error> {
+        let year = Zemen::MAX.year();
+
+        // Puagme of the last year ends on a day `Zemen` can't hold
+        let nehase = parse_period_code(&format!("{year}/Q4/Nehase"), CodeScheme::Calendar)?;
+        assert_eq!(nehase.end(), &Zemen::from_eth_cal(year, Werh::Puagme, 1)?);
+        assert!(parse_period_code(&format!("{year}/Q4/Puagme"), CodeScheme::Calendar).is_err());
+
+        let fiscal = year + 1;
+        assert!(parse_period_code(&format!("{fiscal}/Q1/Nehase"), CodeScheme::Fiscal).is_ok());
+        assert!(parse_period_code(&format!("{fiscal}/Q1/Puagme"), CodeScheme::Fiscal).is_err());
+        assert!(
+            parse_period_code(&format!("{}/Q1/Meskerem", fiscal + 1), CodeScheme::Fiscal).is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_period_code() -> Result<(), error::Error> {
+        let tir = parse_period_code(" 2016/q2/ጥር ", CodeScheme::Calendar)?;
+        assert_eq!(tir.start(), &Zemen::from_eth_cal(2016, Werh::Tir, 1)?);
+
+        for broken in [
+            "2016/Q2",
+            "2016/Q2/Tir/1",
+            "2016/2/Tir",
+            "2016/Q3/Tir",
+            "2016/Q2/Tirr",
+            "MMXVI/Q2/Tir",
+            "",
+        ] {
+            assert!(
+                parse_period_code(broken, CodeScheme::Calendar).is_err(),
+                "{broken}"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -8,36 +8,48 @@ use crate::validator::gre;
 
 const JDN_EPOCH_OFFSET_ETH: i32 = 1_723_856;
 
-fn modl(i: i32, j: i32) -> i32 {
-    i - (j * (i / j))
-}
-
 /// Returns the Julian day number (`jdn`) given `year`, `month`, and `day`
 /// in ethiopic date format.
 ///
 /// Doesn't not check the validity of the provided date.
-pub fn eth_to_jdn(year: i32, month: i32, day: i32) -> i32 {
-    (JDN_EPOCH_OFFSET_ETH + 365) + 365 * (year - 1) + (year / 4) + 30 * month + day - 31
+pub const fn eth_to_jdn(year: i32, month: i32, day: i32) -> i32 {
+    (JDN_EPOCH_OFFSET_ETH + 365) + 365 * (year - 1) + year.div_euclid(4) + 30 * month + day - 31
 }
 
 /// Returns the ethiopic date, given jdn, as (year, month, day)
 ///
 /// Doesn't check for the validity of the provided Julian day number.
 pub fn jdn_to_eth(jdn: i32) -> (i32, u8, u8) {
-    let r = modl(jdn - JDN_EPOCH_OFFSET_ETH, 1461);
-    let n = modl(r, 365) + 365 * (r / 1460);
+    // in `i64`, so that the Julian day numbers near `i32::MIN` don't overflow
+    let days = jdn as i64 - JDN_EPOCH_OFFSET_ETH as i64;
+    let r = days.rem_euclid(1461);
+    let n = r % 365 + 365 * (r / 1460);
 
-    let year = 4 * ((jdn - JDN_EPOCH_OFFSET_ETH) / 1461) + (r / 365) - (r / 1460);
+    let year = 4 * days.div_euclid(1461) + (r / 365) - (r / 1460);
     let month = (n / 30) + 1;
-    let day = modl(n, 30) + 1;
+    let day = n % 30 + 1;
 
-    (year, month as u8, day as u8)
+    (year as i32, month as u8, day as u8)
+}
+
+/// The Gregorian years `time::Date` holds, whichever of its features are
+/// enabled.
+#[cfg(feature = "time")]
+const GRE_INTEROP_YEARS: (i32, i32) = (-9999, 9999);
+
+/// Fails with the range of [`GRE_INTEROP_YEARS`] if the Gregorian `year`
+/// isn't one of them.
+#[cfg(feature = "time")]
+fn is_gre_interop_year(year: i32) -> Result<(), error::Error> {
+    let (min, max) = GRE_INTEROP_YEARS;
+    error::is_in_range(year, min, max, "Gregorian year")
 }
 
 /// Tries to create a Gregorian date from Ethiopian date.
 #[cfg(feature = "time")]
 pub fn eth_to_gre(year: i32, month: u8, day: u8) -> Result<time::Date, error::Error> {
     let jdn = eth_to_jdn(year, month as i32, day as i32);
+    is_gre_interop_year(jdn_to_gre(jdn).0)?;
     let date = time::Date::from_julian_day(jdn)?;
 
     Ok(date)
@@ -47,6 +59,7 @@ pub fn eth_to_gre(year: i32, month: u8, day: u8) -> Result<time::Date, error::Er
 ///
 #[cfg(feature = "time")]
 pub fn gre_to_eth(year: i32, month: u8, day: u8) -> Result<Zemen, error::Error> {
+    is_gre_interop_year(year)?;
     let month = time::Month::try_from(month)?;
     let date = time::Date::from_calendar_date(year, month, day)?;
     let (year, month, day) = jdn_to_eth(date.to_julian_day());
@@ -54,6 +67,78 @@ pub fn gre_to_eth(year: i32, month: u8, day: u8) -> Result<Zemen, error::Error>
     Zemen::new(year, month, day)
 }
 
+/// Days in the 400 years after which the Gregorian calendar repeats.
+const GRE_CYCLE_DAYS: i64 = 146_097;
+/// Days in the 4 years after which the Julian calendar repeats.
+const JULIAN_CYCLE_DAYS: i64 = 1_461;
+
+/// Returns the Julian day number (`jdn`) of a proleptic Gregorian date.
+///
+/// Doesn't check the validity of the provided date, and the result wraps for
+/// years beyond the Gregorian years `Zemen` holds, see
+/// [`Zemen::checked_from_gre_cal`].
+pub fn gre_to_jdn(year: i32, month: u8, day: u8) -> i32 {
+    // the formula divides towards zero, so shift the year by whole 400 year
+    // cycles to keep it positive
+    let cycles = (year as i64).div_euclid(400);
+    let (year, month, day) = (year as i64 - 400 * cycles, month as i64, day as i64);
+    let a = (14 - month) / 12;
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+
+    (day + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32_045
+        + GRE_CYCLE_DAYS * cycles) as i32
+}
+
+/// Returns the proleptic Gregorian date, given jdn, as (year, month, day)
+///
+/// Doesn't check for the validity of the provided Julian day number.
+pub fn jdn_to_gre(jdn: i32) -> (i32, u8, u8) {
+    let cycles = (jdn as i64).div_euclid(GRE_CYCLE_DAYS);
+    let l = jdn as i64 - GRE_CYCLE_DAYS * cycles + 68_569;
+    let n = 4 * l / 146_097;
+    let l = l - (146_097 * n + 3) / 4;
+    let i = 4_000 * (l + 1) / 1_461_001;
+    let l = l - 1_461 * i / 4 + 31;
+    let j = 80 * l / 2_447;
+    let day = l - 2_447 * j / 80;
+    let l = j / 11;
+    let month = j + 2 - 12 * l;
+    let year = 100 * (n - 49) + i + l + 400 * cycles;
+
+    (year as i32, month as u8, day as u8)
+}
+
+/// Returns the Julian day number (`jdn`) of a proleptic Julian calendar date.
+///
+/// Doesn't check the validity of the provided date.
+pub fn julian_to_jdn(year: i32, month: u8, day: u8) -> i32 {
+    let cycles = (year as i64).div_euclid(4);
+    let (year, month, day) = (year as i64 - 4 * cycles, month as i64, day as i64);
+    let a = (14 - month) / 12;
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+
+    (day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32_083 + JULIAN_CYCLE_DAYS * cycles) as i32
+}
+
+/// Returns the proleptic Julian calendar date, given jdn, as (year, month, day)
+///
+/// Doesn't check for the validity of the provided Julian day number.
+pub fn jdn_to_julian(jdn: i32) -> (i32, u8, u8) {
+    let cycles = (jdn as i64).div_euclid(JULIAN_CYCLE_DAYS);
+    let c = jdn as i64 - JULIAN_CYCLE_DAYS * cycles + 32_082;
+    let d = (4 * c + 3) / 1_461;
+    let e = c - 1_461 * d / 4;
+    let m = (5 * e + 2) / 153;
+
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = d - 4_800 + m / 10 + 4 * cycles;
+
+    (year as i32, month as u8, day as u8)
+}
+
 pub fn to_ordinal(month: i32, day: i32) -> i32 {
     (month - 1) * 30 + day
 }
@@ -81,8 +166,8 @@ pub fn timestamp_to_ordinal(timestamp: u64) -> (u64, u16) {
     let mut days_since_epoch = timestamp / SECONDS_IN_A_DAY;
 
     let mut year = 1970;
-    while days_since_epoch >= gre::days_in_year(year).into() {
-        days_since_epoch -= gre::days_in_year(year) as u64;
+    while days_since_epoch >= gre::days_in_year(year as i32).into() {
+        days_since_epoch -= gre::days_in_year(year as i32) as u64;
         year += 1;
     }
 
@@ -167,6 +252,56 @@ mod basic_conversion {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_gre_interop_years() {
+        fn out_of_range<T>(result: Result<T, error::Error>) -> bool {
+            matches!(
+                result,
+                Err(error::Error::InvalidRange {
+                    name: "Gregorian year",
+                    min: -9999,
+                    max: 9999,
+                    ..
+                })
+            )
+        }
+
+        assert!(eth_to_gre(9992, 2, 21).is_ok());
+        assert!(out_of_range(eth_to_gre(9992, 2, 22)));
+        assert!(eth_to_gre(-10_007, 7, 23).is_ok());
+        assert!(out_of_range(eth_to_gre(-10_007, 7, 22)));
+
+        assert!(gre_to_eth(9999, 12, 31).is_ok());
+        assert!(out_of_range(gre_to_eth(10_000, 1, 1)));
+        assert!(out_of_range(gre_to_eth(-10_000, 12, 31)));
+    }
+
+    #[test]
+    fn test_gre_jdn_round_trip() {
+        assert_eq!(gre_to_jdn(2000, 1, 1), 2_451_545);
+        assert_eq!(jdn_to_gre(2_451_545), (2000, 1, 1));
+        assert_eq!(jdn_to_gre(gre_to_jdn(1923, 5, 15)), (1923, 5, 15));
+        assert_eq!(jdn_to_gre(gre_to_jdn(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_negative_jdn_round_trip() {
+        assert_eq!(gre_to_jdn(-4713, 11, 24), 0);
+        assert_eq!(jdn_to_gre(-1), (-4713, 11, 23));
+        assert_eq!(julian_to_jdn(-4712, 1, 1), 0);
+        assert_eq!(jdn_to_julian(-1), (-4713, 12, 31));
+
+        for jdn in (-2_000_000_000..-1_000_000_000).step_by(99_991) {
+            let (year, month, day) = jdn_to_gre(jdn);
+            assert_eq!(gre_to_jdn(year, month, day), jdn);
+            assert!(jdn_to_gre(jdn + 1) > (year, month, day));
+
+            let (year, month, day) = jdn_to_julian(jdn);
+            assert_eq!(julian_to_jdn(year, month, day), jdn);
+        }
+    }
+
     #[test]
     #[cfg(not(feature = "time"))]
     fn test_date_from_timestamp() {
@@ -174,3 +309,71 @@ mod basic_conversion {
         println!("a: {:?}", a);
     }
 }
+
+/// Converts every day from 1600 to 2400 in the Gregorian calendar, both ways,
+/// and checks the results against `time`.
+///
+/// Run it with `cargo test --features exhaustive-tests exhaustive`.
+/// It writes a summary to `$ZEMEN_CONVERSION_REPORT`, or to
+/// `zemen-conversion-report.txt` in the temporary directory.
+#[cfg(all(test, feature = "exhaustive-tests"))]
+mod exhaustive {
+    use std::fmt::Write;
+
+    use super::*;
+
+    const FIRST_YEAR: i32 = 1600;
+    const LAST_YEAR: i32 = 2400;
+    const MAX_REPORTED: usize = 20;
+
+    #[test]
+    fn test_every_day_against_time() -> Result<(), error::Error> {
+        let first = time::Date::from_calendar_date(FIRST_YEAR, time::Month::January, 1)?;
+        let last = time::Date::from_calendar_date(LAST_YEAR, time::Month::December, 31)?;
+
+        let mut mismatches = Vec::new();
+        let mut previous: Option<Zemen> = None;
+
+        for jdn in first.to_julian_day()..=last.to_julian_day() {
+            let date = time::Date::from_julian_day(jdn)?;
+            let (year, month, day) = (date.year(), date.month() as u8, date.day());
+            let qen = Zemen::from_jdn(jdn)?;
+
+            let checks = [
+                ("Zemen::to_jdn", qen.to_jdn() == jdn),
+                ("Zemen::to_gre", qen.to_gre() == date),
+                ("gre_to_eth", gre_to_eth(year, month, day)? == qen),
+                (
+                    "eth_to_gre",
+                    eth_to_gre(qen.year(), qen.month() as u8, qen.day())? == date,
+                ),
+                ("gre_to_jdn", gre_to_jdn(year, month, day) == jdn),
+                ("jdn_to_gre", jdn_to_gre(jdn) == (year, month, day)),
+                ("consecutive", previous.map_or(true, |p| p.next() == qen)),
+            ];
+
+            for (check, _) in checks.iter().filter(|(_, ok)| !ok) {
+                mismatches.push(format!("{check} failed on jdn {jdn} ({date}, {qen})"));
+            }
+            previous = Some(qen);
+        }
+
+        let days = last.to_julian_day() - first.to_julian_day() + 1;
+        let mut report = String::new();
+        let _ = writeln!(report, "window: {first} to {last} ({days} days)");
+        let _ = writeln!(report, "mismatches: {}", mismatches.len());
+        for mismatch in mismatches.iter().take(MAX_REPORTED) {
+            let _ = writeln!(report, "  {mismatch}");
+        }
+
+        let path = std::env::var_os("ZEMEN_CONVERSION_REPORT")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("zemen-conversion-report.txt"));
+        std::fs::write(&path, &report).expect("the report should be writable");
+        println!("{report}report written to {}", path.display());
+
+        assert!(mismatches.is_empty(), "{report}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Digit systems numbers in dates can be written in.
+
+/// The digits numbers are written with, for [`Zemen::render_digits`] and
+/// [`Zemen::format_digits`].
+///
+/// Ethiopic numerals aren't positional and have no zero, so a number is
+/// written out by its value, e.g. `2015` is `፳፻፲፭` and the `05` of a month
+/// is `፭`. Zero, which has no Ethiopic numeral, is left as `0`.
+///
+/// [`Zemen::render_digits`]: crate::Zemen::render_digits
+/// [`Zemen::format_digits`]: crate::Zemen::format_digits
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::DigitStyle;
+/// assert_eq!(DigitStyle::Ethiopic.apply("2015-05-10"), "፳፻፲፭-፭-፲");
+/// assert_eq!(DigitStyle::ArabicIndic.apply("2015-05-10"), "٢٠١٥-٠٥-١٠");
+/// assert_eq!(DigitStyle::Ascii.apply("2015-05-10"), "2015-05-10");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DigitStyle {
+    /// `0` to `9`, as written by `Display` and [`Zemen::format`](crate::Zemen::format).
+    #[default]
+    Ascii,
+    /// Ge'ez numerals, e.g. `፲፱`.
+    Ethiopic,
+    /// Arabic-Indic digits, `٠` to `٩`.
+    ArabicIndic,
+}
+
+impl DigitStyle {
+    /// Every digit style.
+    pub const ALL: [DigitStyle; 3] = [
+        DigitStyle::Ascii,
+        DigitStyle::Ethiopic,
+        DigitStyle::ArabicIndic,
+    ];
+
+    /// Rewrite every run of ASCII digits in `text` with the style, leaving
+    /// everything else as it is.
+    pub fn apply(self, text: &str) -> String {
+        if self == DigitStyle::Ascii {
+            return text.to_string();
+        }
+
+        let mut out = String::with_capacity(text.len() * 3);
+        let mut rest = text;
+
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            self.write(&rest[..len], &mut out);
+            rest = &rest[len..];
+        }
+        out.push_str(rest);
+
+        out
+    }
+
+    /// Write the ASCII digits `digits` out to `out` with the style.
+    fn write(self, digits: &str, out: &mut String) {
+        match self {
+            DigitStyle::Ascii => out.push_str(digits),
+            DigitStyle::ArabicIndic => out.extend(
+                digits
+                    .bytes()
+                    .map(|digit| ARABIC_INDIC[(digit - b'0') as usize]),
+            ),
+            DigitStyle::Ethiopic => ethiopic(digits.trim_start_matches('0'), out),
+        }
+    }
+}
+
+const ARABIC_INDIC: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+const ONES: [char; 10] = ['\0', '፩', '፪', '፫', '፬', '፭', '፮', '፯', '፰', '፱'];
+const TENS: [char; 10] = ['\0', '፲', '፳', '፴', '፵', '፶', '፷', '፸', '፹', '፺'];
+
+/// Write a number without leading zeros out in Ge'ez numerals.
+///
+/// The digits are read in pairs from the right, each pair being written as
+/// tens and ones, followed by ፻ (100) after every odd pair and ፼ (10000)
+/// after every even one. A lone ፩ before ፻, or before a leading ፼, is left
+/// out, so 100 is ፻ rather than ፩፻.
+fn ethiopic(digits: &str, out: &mut String) {
+    if digits.is_empty() {
+        out.push('0');
+        return;
+    }
+
+    let digits = digits.as_bytes();
+    let pairs = digits.len().div_ceil(2);
+    let pair = |i: usize| {
+        // the `i`th pair from the right
+        let end = digits.len() - 2 * i;
+        let tens = if end >= 2 { digits[end - 2] - b'0' } else { 0 };
+        (tens, digits[end - 1] - b'0')
+    };
+
+    for i in (0..pairs).rev() {
+        let (tens, ones) = pair(i);
+        let zero = tens == 0 && ones == 0;
+        let leading = i == pairs - 1;
+
+        let omit_one = tens == 0 && ones == 1 && (i % 2 == 1 || (i > 0 && leading));
+        if !zero && !omit_one {
+            if tens != 0 {
+                out.push(TENS[tens as usize]);
+            }
+            if ones != 0 {
+                out.push(ONES[ones as usize]);
+            }
+        }
+
+        if i % 2 == 1 && !zero {
+            out.push('፻');
+        } else if i > 0 && i % 2 == 0 {
+            let (above_tens, above_ones) = if leading { (0, 0) } else { pair(i + 1) };
+            if !zero || above_tens != 0 || above_ones != 0 {
+                out.push('፼');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ethiopic_numerals() {
+        for (number, expected) in [
+            ("0", "0"),
+            ("00", "0"),
+            ("1", "፩"),
+            ("05", "፭"),
+            ("10", "፲"),
+            ("13", "፲፫"),
+            ("30", "፴"),
+            ("99", "፺፱"),
+            ("100", "፻"),
+            ("101", "፻፩"),
+            ("130", "፻፴"),
+            ("200", "፪፻"),
+            ("366", "፫፻፷፮"),
+            ("1100", "፲፩፻"),
+            ("1992", "፲፱፻፺፪"),
+            ("2000", "፳፻"),
+            ("2015", "፳፻፲፭"),
+            ("10000", "፼"),
+            ("10100", "፼፻"),
+            ("20000", "፪፼"),
+            ("123456", "፲፪፼፴፬፻፶፮"),
+            ("1000000", "፻፼"),
+        ] {
+            let mut out = String::new();
+            ethiopic(number.trim_start_matches('0'), &mut out);
+            assert_eq!(out, expected, "{number}");
+        }
+    }
+
+    #[test]
+    fn test_only_digits_are_rewritten() {
+        assert_eq!(
+            DigitStyle::Ethiopic.apply("ጥር 10 ቀን -2015 ዓ.ም"),
+            "ጥር ፲ ቀን -፳፻፲፭ ዓ.ም"
+        );
+        assert_eq!(DigitStyle::ArabicIndic.apply("Q1/13"), "Q١/١٣");
+        assert_eq!(DigitStyle::Ethiopic.apply(""), "");
+        assert_eq!(DigitStyle::ArabicIndic.apply("ጳጉሜ"), "ጳጉሜ");
+    }
+}
@@ -0,0 +1,75 @@
+//! Calendar aware amounts of time that can be added to a `Zemen`.
+
+use std::ops::Neg;
+
+/// An amount of years, months, and days.
+///
+/// Unlike a fixed number of days, adding a month moves to the same day of
+/// the next month whatever its length. When that day doesn't exist (only
+/// possible for Puagme) the last day of the month is used instead, see
+/// [`MonthOverflow::Clamp`](crate::MonthOverflow::Clamp).
+///
+/// Years are applied first, then months, then days.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, Zemen, ZemenDuration, Werh};
+/// let qen = Zemen::from_eth_cal(2015, Werh::Nehase, 30)?;
+///
+/// assert_eq!(qen + ZemenDuration::months(1), Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+/// assert_eq!(qen - ZemenDuration::weeks(1), Zemen::from_eth_cal(2015, Werh::Nehase, 23)?);
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ZemenDuration {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl ZemenDuration {
+    /// A duration of `days` days.
+    pub const fn days(days: i32) -> Self {
+        Self {
+            years: 0,
+            months: 0,
+            days,
+        }
+    }
+
+    /// A duration of `weeks` seven day weeks.
+    pub const fn weeks(weeks: i32) -> Self {
+        Self::days(weeks * 7)
+    }
+
+    /// A duration of `months` months.
+    pub const fn months(months: i32) -> Self {
+        Self {
+            years: 0,
+            months,
+            days: 0,
+        }
+    }
+
+    /// A duration of `years` years.
+    pub const fn years(years: i32) -> Self {
+        Self {
+            years,
+            months: 0,
+            days: 0,
+        }
+    }
+}
+
+impl Neg for ZemenDuration {
+    type Output = ZemenDuration;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+        }
+    }
+}
@@ -5,7 +5,7 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(
-        "With the provided date: {name} must be in the range {min}..{max}, but given {given}."
+        "With the provided date: {name} must be in the range {min}..={max}, but given {given}."
     )]
     InvalidRange {
         name: &'static str,
@@ -17,9 +17,83 @@ pub enum Error {
     #[error("can not parse {0}, invalid token `{1}`")]
     InvalidVariant(&'static str, String),
 
+    /// A format pattern has letters that aren't a specifier, see
+    /// [`Zemen::try_format`](crate::Zemen::try_format). `position` counts
+    /// characters, not bytes, from the start of the pattern.
+    #[error("unknown format specifier `{token}` at position {position}")]
+    UnknownSpecifier { token: String, position: usize },
+
+    /// The input of [`Zemen::parse_bytes`](crate::Zemen::parse_bytes)
+    /// doesn't match its format description. `position` counts bytes from
+    /// the start of the input, which is `len` bytes long and isn't copied.
+    #[error("can not parse date, the input doesn't match at byte {position} of {len}")]
+    InvalidBytes { position: usize, len: usize },
+
     #[error("conversion failed")]
     #[cfg(feature = "time")]
     DateConversion(#[from] time::error::ComponentRange),
+
+    /// An error with a message describing what was being done when it
+    /// happened, see [`ResultExt::context`].
+    #[error("{msg}")]
+    Context {
+        msg: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Wrap the error with a message describing what was being done when it
+    /// happened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error as _;
+    /// # use zemen::Werh;
+    /// let err = Werh::try_from(14).unwrap_err().with_context("reading the month column");
+    ///
+    /// assert_eq!(err.to_string(), "reading the month column");
+    /// assert!(err.source().is_some());
+    /// ```
+    pub fn with_context(self, msg: impl Into<String>) -> Error {
+        Error::Context {
+            msg: msg.into(),
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Adds context to the errors of the crate's `Result`s.
+pub trait ResultExt<T> {
+    /// Wrap the error, if any, with a message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error::ResultExt, Zemen, Werh};
+    /// let err = Zemen::from_eth_cal(2015, Werh::Tir, 31)
+    ///     .context("parsing payroll row 42")
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "parsing payroll row 42");
+    /// ```
+    fn context(self, msg: impl Into<String>) -> Result<T, Error>;
+
+    /// Wrap the error, if any, with a message built only when there is an
+    /// error.
+    fn with_context<M: Into<String>, F: FnOnce() -> M>(self, f: F) -> Result<T, Error>;
+}
+
+impl<T> ResultExt<T> for Result<T, Error> {
+    fn context(self, msg: impl Into<String>) -> Result<T, Error> {
+        self.map_err(|err| err.with_context(msg))
+    }
+
+    fn with_context<M: Into<String>, F: FnOnce() -> M>(self, f: F) -> Result<T, Error> {
+        self.map_err(|err| err.with_context(f()))
+    }
 }
 
 pub fn is_in_range(value: i32, min: i32, max: i32, name: &'static str) -> Result<(), Error> {
@@ -34,3 +108,29 @@ pub fn is_in_range(value: i32, min: i32, max: i32, name: &'static str) -> Result
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_context_keeps_the_source() {
+        let result: Result<(), Error> = is_in_range(14, 1, 13, "month");
+        let err = result
+            .with_context(|| format!("row {}", 42))
+            .context("importing payroll")
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "importing payroll");
+
+        let source = err.source().expect("has a source");
+        assert_eq!(source.to_string(), "row 42");
+
+        let root = source.source().expect("has a source");
+        assert_eq!(
+            root.to_string(),
+            is_in_range(14, 1, 13, "month").unwrap_err().to_string()
+        );
+    }
+}
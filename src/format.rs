@@ -0,0 +1,470 @@
+//! The specifiers understood by [`Zemen::format`].
+//!
+//! [`specifiers`] is the list [`Zemen::format`] itself works from, so it can
+//! be used to generate documentation or validate patterns without going out
+//! of date. Specifiers are only ever added: the token, name, and output of
+//! an existing specifier will not change between releases.
+
+use std::fmt;
+
+use crate::{
+    error,
+    formatting::{self, Piece},
+    Werh, Zemen,
+};
+
+/// A pattern token understood by [`Zemen::format`], e.g. `YYYY`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::format;
+/// let year = format::specifiers()
+///     .iter()
+///     .find(|spec| spec.token() == "YYYY")
+///     .unwrap();
+///
+/// assert_eq!(year.name(), "year");
+/// assert_eq!(year.example(), "2015");
+/// ```
+#[derive(Clone, Copy)]
+pub struct SpecifierInfo {
+    token: &'static str,
+    name: &'static str,
+    description: &'static str,
+    example: &'static str,
+    parse: bool,
+    render: fn(&Zemen) -> String,
+}
+
+impl SpecifierInfo {
+    /// Get the token written in patterns, e.g. `YYYY`.
+    pub fn token(&self) -> &'static str {
+        self.token
+    }
+
+    /// Get the name of the specifier, e.g. `year`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get a one line description of what the specifier writes.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Get what the specifier writes for ጥር 10 2015, a Wednesday.
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+
+    /// Check whether the specifier can be read back by
+    /// [`Zemen::parse_bytes`], i.e. used in a [`FormatDescription`].
+    pub fn parse(&self) -> bool {
+        self.parse
+    }
+
+    pub(crate) fn render(&self, qen: &Zemen) -> String {
+        (self.render)(qen)
+    }
+}
+
+impl fmt::Debug for SpecifierInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpecifierInfo")
+            .field("token", &self.token)
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("example", &self.example)
+            .field("parse", &self.parse)
+            .finish()
+    }
+}
+
+const SPECIFIERS: [SpecifierInfo; 10] = [
+    SpecifierInfo {
+        token: "YY",
+        name: "year_short",
+        description: "The last two digits of the year (00..99)",
+        example: "15",
+        parse: false,
+        render: |qen| format!("{:02}", qen.year() % 100),
+    },
+    SpecifierInfo {
+        token: "YYYY",
+        name: "year",
+        description: "Full year",
+        example: "2015",
+        parse: true,
+        render: |qen| qen.year().to_string(),
+    },
+    SpecifierInfo {
+        token: "M",
+        name: "month_number",
+        description: "Month (01..13)",
+        example: "05",
+        parse: true,
+        render: |qen| format!("{:02}", qen.month() as u8),
+    },
+    SpecifierInfo {
+        token: "MM",
+        name: "month_short",
+        description: "Abbreviated month name (e.g., መስከ)",
+        example: "ጥር",
+        parse: false,
+        render: |qen| qen.month().short_name(),
+    },
+    SpecifierInfo {
+        token: "MMM",
+        name: "month",
+        description: "Full month name (e.g., መስከረም)",
+        example: "ጥር",
+        parse: true,
+        render: |qen| qen.month().to_string(),
+    },
+    SpecifierInfo {
+        token: "D",
+        name: "day",
+        description: "Day of month (01..30)",
+        example: "10",
+        parse: true,
+        render: |qen| format!("{:02}", qen.day()),
+    },
+    SpecifierInfo {
+        token: "DD",
+        name: "weekday_short",
+        description: "Abbreviated weekday name (e.g., ማክሰ)",
+        example: "ረቡዕ",
+        parse: false,
+        render: |qen| qen.weekday().short_name(),
+    },
+    SpecifierInfo {
+        token: "DDD",
+        name: "weekday",
+        description: "Full weekday name (e.g., ማክሰኞ)",
+        example: "ረቡዕ",
+        parse: false,
+        render: |qen| qen.weekday().to_string(),
+    },
+    SpecifierInfo {
+        token: "JJ",
+        name: "day_of_year",
+        description: "Day of the year (001..366)",
+        example: "130",
+        parse: true,
+        render: |qen| format!("{:03}", qen.ordinal()),
+    },
+    SpecifierInfo {
+        token: "QQ",
+        name: "quarter",
+        description: "Quarter of the year (01..04)",
+        example: "01",
+        parse: false,
+        render: |qen| format!("{:02}", (qen.ordinal() / 4 / 360) + 1),
+    },
+];
+
+/// Get every specifier understood by [`Zemen::format`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, format, Zemen, Werh};
+/// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+///
+/// for spec in format::specifiers() {
+///     assert_eq!(qen.format(spec.token()), spec.example());
+/// }
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn specifiers() -> &'static [SpecifierInfo] {
+    &SPECIFIERS
+}
+
+/// A pattern prepared for parsing dates with [`Zemen::parse_bytes`].
+///
+/// Only the specifiers for which [`SpecifierInfo::parse`] is `true` can be
+/// parsed, and the pattern must give the year, and either the month and day
+/// or the day of the year. `YYYY` reads up to 7 digits, or exactly 4 when
+/// followed right away by another number, and may start with a `-`. `M` and
+/// `D` read 2 digits, `JJ` reads 3, and `MMM` reads a month name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, format::FormatDescription};
+/// assert!(FormatDescription::parse("YYYY-M-D").is_ok());
+/// assert!(FormatDescription::parse("[day] JJ [of] YYYY").is_ok());
+///
+/// // no day
+/// assert!(FormatDescription::parse("YYYY-M").is_err());
+/// // weekdays can't be parsed
+/// assert!(FormatDescription::parse("DDD YYYY-M-D").is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FormatDescription {
+    items: Vec<Item>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Item {
+    Literal(Vec<u8>),
+    Year,
+    Month,
+    MonthName,
+    Day,
+    DayOfYear,
+}
+
+impl Item {
+    fn is_number(&self) -> bool {
+        matches!(self, Item::Year | Item::Month | Item::Day | Item::DayOfYear)
+    }
+}
+
+impl FormatDescription {
+    /// Prepare `pattern` for parsing, failing if it has unknown or
+    /// unparsable specifiers, or doesn't give a whole date.
+    pub fn parse(pattern: &str) -> Result<Self, error::Error> {
+        let mut items: Vec<Item> = Vec::new();
+        let mut unparsable = None;
+
+        formatting::walk(pattern, true, false, |piece| match piece {
+            Piece::Text(text) => match items.last_mut() {
+                Some(Item::Literal(literal)) => literal.extend_from_slice(text.as_bytes()),
+                _ => items.push(Item::Literal(text.as_bytes().to_vec())),
+            },
+            Piece::Specifier(spec) => items.push(match spec.token() {
+                "YYYY" => Item::Year,
+                "M" => Item::Month,
+                "MMM" => Item::MonthName,
+                "D" => Item::Day,
+                "JJ" => Item::DayOfYear,
+                token => {
+                    unparsable.get_or_insert(token);
+                    return;
+                }
+            }),
+            Piece::Week => {}
+        })?;
+
+        if let Some(token) = unparsable {
+            return Err(error::Error::InvalidVariant(
+                "parse specifier",
+                token.to_string(),
+            ));
+        }
+
+        let has = |item: Item| items.contains(&item);
+        let month = has(Item::Month) || has(Item::MonthName);
+        if !has(Item::Year) || !(month && has(Item::Day) || has(Item::DayOfYear)) {
+            return Err(error::Error::InvalidVariant(
+                "format description",
+                pattern.to_string(),
+            ));
+        }
+
+        Ok(FormatDescription { items })
+    }
+}
+
+pub(crate) fn parse_bytes(
+    bytes: &[u8],
+    description: &FormatDescription,
+) -> Result<Zemen, error::Error> {
+    // the input isn't copied into errors, so parsing never allocates
+    let invalid = |rest: &[u8]| error::Error::InvalidBytes {
+        position: bytes.len() - rest.len(),
+        len: bytes.len(),
+    };
+
+    let mut rest = bytes;
+    let (mut year, mut month, mut day, mut ordinal) = (None, None, None, None);
+
+    for (i, item) in description.items.iter().enumerate() {
+        let next_is_number = description.items.get(i + 1).is_some_and(Item::is_number);
+
+        match item {
+            Item::Literal(literal) => {
+                rest = rest
+                    .strip_prefix(literal.as_slice())
+                    .ok_or_else(|| invalid(rest))?;
+            }
+            Item::Year => {
+                let (sign, digits) = match rest.strip_prefix(b"-") {
+                    Some(digits) => (-1, digits),
+                    None => (1, rest),
+                };
+                let (min, max) = if next_is_number { (4, 4) } else { (1, 7) };
+                let (value, left) = number(digits, min, max).ok_or_else(|| invalid(rest))?;
+
+                year = Some(sign * value as i32);
+                rest = left;
+            }
+            Item::Month | Item::Day | Item::DayOfYear => {
+                let len = if *item == Item::DayOfYear { 3 } else { 2 };
+                let (value, left) = number(rest, len, len).ok_or_else(|| invalid(rest))?;
+
+                match item {
+                    Item::Month => month = Some(value as u8),
+                    Item::Day => day = Some(value as u8),
+                    _ => ordinal = Some(value as u16),
+                }
+                rest = left;
+            }
+            Item::MonthName => {
+                let werh = Werh::iter()
+                    .filter(|werh| rest.starts_with(werh.amharic_name().as_bytes()))
+                    .max_by_key(|werh| werh.amharic_name().len())
+                    .ok_or_else(|| invalid(rest))?;
+
+                month = Some(werh as u8);
+                rest = &rest[werh.amharic_name().len()..];
+            }
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(invalid(rest));
+    }
+
+    let year = year.ok_or_else(|| invalid(rest))?;
+    match (month, day, ordinal) {
+        (Some(month), Some(day), _) => Zemen::from_eth_cal(year, Werh::try_from(month)?, day),
+        (_, _, Some(ordinal)) => Zemen::from_ordinal_date(year, ordinal),
+        _ => Err(invalid(rest)),
+    }
+}
+
+/// Read a number of `min` to `max` ASCII digits off the start of `bytes`.
+fn number(bytes: &[u8], min: usize, max: usize) -> Option<(u32, &[u8])> {
+    let len = bytes
+        .iter()
+        .take(max)
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len < min {
+        return None;
+    }
+
+    let value = bytes[..len]
+        .iter()
+        .fold(0, |value, digit| value * 10 + (digit - b'0') as u32);
+    Some((value, &bytes[len..]))
+}
+
+/// Get the longest specifier `pattern` starts with.
+pub(crate) fn find(pattern: &str) -> Option<&'static SpecifierInfo> {
+    SPECIFIERS
+        .iter()
+        .filter(|spec| pattern.starts_with(spec.token))
+        .max_by_key(|spec| spec.token.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_examples_match_the_output() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+        for spec in specifiers() {
+            assert_eq!(qen.format(spec.token()), spec.example(), "{spec:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_what_was_formatted() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2014, Werh::Meskerem, 1)?;
+
+        for pattern in [
+            "YYYY-M-D",
+            "YYYYMD",
+            "D/M/YYYY",
+            "MMM D ቀን YYYY ዓ.ም",
+            "[Day] JJ [of] YYYY",
+            "YYYYJJ",
+            "DMMMYYYY",
+        ] {
+            let description = FormatDescription::parse(pattern)?;
+
+            for qen in start.iter_days_until(&(start + 800)) {
+                let formatted = qen.try_format(pattern)?;
+                assert_eq!(
+                    parse_bytes(formatted.as_bytes(), &description)?,
+                    qen,
+                    "{pattern}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_input() -> Result<(), error::Error> {
+        let description = FormatDescription::parse("YYYY-M-D")?;
+
+        for input in [
+            "",
+            "2015-05",
+            "2015-5-10",
+            "2015-05-10 ",
+            "2015-14-01",
+            "2015-13-06x",
+            "2016-13-06",
+            "2015-00-10",
+            "2015-05-00",
+            "2015-05-31",
+            "abcd-05-10",
+        ] {
+            assert!(
+                parse_bytes(input.as_bytes(), &description).is_err(),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            parse_bytes(b"-5-01-01", &description)?,
+            Zemen::from_eth_cal(-5, Werh::Meskerem, 1)?
+        );
+        assert_eq!(
+            parse_bytes(b"123456-01-01", &description)?,
+            Zemen::from_eth_cal(123_456, Werh::Meskerem, 1)?
+        );
+        assert!(parse_bytes(&[0xff, b'-', b'0', b'1'], &description).is_err());
+
+        for (input, at) in [
+            ("", 0),
+            ("2015-05", 7),
+            ("2015-5-10", 5),
+            ("2015-05-10 ", 10),
+            ("abcd-05-10", 0),
+        ] {
+            assert!(
+                matches!(
+                    parse_bytes(input.as_bytes(), &description),
+                    Err(error::Error::InvalidBytes { position, len })
+                        if position == at && len == input.len()
+                ),
+                "{input}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokens_and_names_are_unique() {
+        for (i, spec) in specifiers().iter().enumerate() {
+            for other in &specifiers()[i + 1..] {
+                assert_ne!(spec.token(), other.token());
+                assert_ne!(spec.name(), other.name());
+            }
+        }
+    }
+}
@@ -9,20 +9,202 @@
 // JJ       Day of Year (001..366)
 // QQ       Quarter of Year (1..4)
 
-use crate::Zemen;
+use crate::{calendar::WeekNumbering, error, format::SpecifierInfo, DigitStyle, Zemen};
+
+/// A named, frozen pattern for common ways of writing dates.
+///
+/// The output of every preset is covered by golden tests and will not change
+/// between releases, so generated documents can be diffed safely.
+///
+/// ```txt
+///
+/// GOV_LETTER       ጥር 10 ቀን 2015 ዓ.ም
+/// BANK_STATEMENT   10-ጥር-2015
+/// SHORT_SLASH      10/05/15
+/// ISO              2015-05-10
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, DisplayPreset, Zemen, Werh};
+/// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+///
+/// assert_eq!(DisplayPreset::GOV_LETTER.format(&qen), "ጥር 10 ቀን 2015 ዓ.ም");
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DisplayPreset {
+    name: &'static str,
+    pattern: &'static str,
+}
+
+impl DisplayPreset {
+    /// The date line of official letters, e.g. `ጥር 10 ቀን 2015 ዓ.ም`.
+    pub const GOV_LETTER: DisplayPreset = DisplayPreset {
+        name: "GOV_LETTER",
+        pattern: "MMM D ቀን YYYY ዓ.ም",
+    };
+
+    /// Day, abbreviated month, and year, e.g. `10-ጥር-2015`.
+    pub const BANK_STATEMENT: DisplayPreset = DisplayPreset {
+        name: "BANK_STATEMENT",
+        pattern: "D-MM-YYYY",
+    };
+
+    /// Numeric day, month, and two digit year, e.g. `10/05/15`.
+    pub const SHORT_SLASH: DisplayPreset = DisplayPreset {
+        name: "SHORT_SLASH",
+        pattern: "D/M/YY",
+    };
+
+    /// Year, month, and day, e.g. `2015-05-10`. Same as `Display` for the
+    /// years 1000 to 9999: the year isn't zero padded, so year 999 is
+    /// `999-01-01` rather than `0999-01-01`.
+    pub const ISO: DisplayPreset = DisplayPreset {
+        name: "ISO",
+        pattern: "YYYY-M-D",
+    };
+
+    /// Every preset. More may be added, so don't rely on the number of them.
+    pub const ALL: &'static [DisplayPreset] = &[
+        Self::GOV_LETTER,
+        Self::BANK_STATEMENT,
+        Self::SHORT_SLASH,
+        Self::ISO,
+    ];
+
+    /// Get the name of the preset, e.g. `GOV_LETTER`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the format pattern of the preset. See [`Zemen::format`].
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// Format `qen` with the preset.
+    pub fn format(&self, qen: &Zemen) -> String {
+        format(qen, self.pattern)
+    }
+}
 
 pub(crate) fn format(qen: &Zemen, pattern: &str) -> String {
-    pattern
-        .replace("YYYY", &qen.year().to_string())
-        .replace("YY", &format!("{:02}", (qen.year() % 100)))
-        .replace("MMM", &qen.month().to_string())
-        .replace("MM", &qen.month().short_name())
-        .replace('M', &format!("{:02}", (qen.month() as u8)))
-        .replace("DDD", &qen.weekday().to_string())
-        .replace("DD", &qen.weekday().short_name())
-        .replace('D', &format!("{:02}", qen.day()))
-        .replace("JJ", &format!("{:03}", qen.ordinal()))
-        .replace("QQ", &format!("{:02}", (qen.ordinal() / 4 / 360) + 1))
+    format_digits(qen, pattern, DigitStyle::Ascii)
+}
+
+#[allow(clippy::expect_used)]
+pub(crate) fn format_digits(qen: &Zemen, pattern: &str, digits: DigitStyle) -> String {
+    render(qen, pattern, false, digits, None).expect("only strict formatting fails")
+}
+
+pub(crate) fn try_format(qen: &Zemen, pattern: &str) -> Result<String, error::Error> {
+    render(qen, pattern, true, DigitStyle::Ascii, None)
+}
+
+pub(crate) fn try_format_weeks(
+    qen: &Zemen,
+    pattern: &str,
+    numbering: WeekNumbering,
+) -> Result<String, error::Error> {
+    render(qen, pattern, true, DigitStyle::Ascii, Some(numbering))
+}
+
+/// Write `pattern` out for `qen`, with the numbers of specifiers in
+/// `digits`. See [`walk`] for `strict`, `WW` is only understood if `weeks`
+/// is given.
+fn render(
+    qen: &Zemen,
+    pattern: &str,
+    strict: bool,
+    digits: DigitStyle,
+    weeks: Option<WeekNumbering>,
+) -> Result<String, error::Error> {
+    let mut formatted = String::new();
+
+    walk(pattern, strict, weeks.is_some(), |piece| match piece {
+        Piece::Text(text) => formatted.push_str(text),
+        Piece::Specifier(spec) => formatted.push_str(&digits.apply(&spec.render(qen))),
+        Piece::Week => {
+            if let Some(numbering) = weeks {
+                let week = format!("{:02}", qen.week_of_year(numbering).1);
+                formatted.push_str(&digits.apply(&week));
+            }
+        }
+    })?;
+
+    Ok(formatted)
+}
+
+/// A part of a pattern.
+pub(crate) enum Piece<'a> {
+    /// Text to copy as it is.
+    Text(&'a str),
+    Specifier(&'static SpecifierInfo),
+    /// `WW`, the week of the year.
+    Week,
+}
+
+/// Split `pattern` up into text and specifiers, in order. Letters that aren't
+/// part of a specifier are text, unless `strict` is set. `WW` is only a
+/// specifier if `weeks` is set.
+///
+/// Text between `[` and `]` is copied as it is, specifiers included, so `[[]`
+/// writes a `[`. An unclosed `[` copies the rest of the pattern, unless
+/// `strict` is set, in which case it's reported as an unknown specifier.
+pub(crate) fn walk<'a>(
+    pattern: &'a str,
+    strict: bool,
+    weeks: bool,
+    mut f: impl FnMut(Piece<'a>),
+) -> Result<(), error::Error> {
+    let mut rest = pattern;
+    let mut position = 0;
+
+    while let Some(c) = rest.chars().next() {
+        let len = match crate::format::find(rest) {
+            None if c == '[' => match rest[1..].find(']') {
+                Some(end) => {
+                    f(Piece::Text(&rest[1..end + 1]));
+                    end + 2
+                }
+                None if strict => {
+                    return Err(error::Error::UnknownSpecifier {
+                        token: "[".to_string(),
+                        position,
+                    });
+                }
+                None => {
+                    f(Piece::Text(&rest[1..]));
+                    rest.len()
+                }
+            },
+            Some(spec) => {
+                f(Piece::Specifier(spec));
+                spec.token().len()
+            }
+            None if weeks && rest.starts_with("WW") => {
+                f(Piece::Week);
+                2
+            }
+            None if strict && c.is_ascii_alphabetic() => {
+                return Err(error::Error::UnknownSpecifier {
+                    token: rest.chars().take_while(char::is_ascii_alphabetic).collect(),
+                    position,
+                });
+            }
+            None => {
+                f(Piece::Text(&rest[..c.len_utf8()]));
+                c.len_utf8()
+            }
+        };
+
+        position += rest[..len].chars().count();
+        rest = &rest[len..];
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -32,6 +214,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::format_in_format_args)]
     fn test_format_specifiers_with_ascii() {
         for i in 1..=13 {
             let qen = Zemen::from_eth_cal(2001, Werh::try_from(i).unwrap(), 1).unwrap();
@@ -56,6 +239,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_strict_formatting() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+        assert_eq!(
+            try_format(&qen, "ዛሬ ቀን DDD, MMM D YYYY ነው")?,
+            "ዛሬ ቀን ረቡዕ, ጥር 10 2015 ነው"
+        );
+        assert_eq!(try_format(&qen, "MMMM DDDD")?, "ጥር05 ረቡዕ10");
+
+        for (pattern, bad, at) in [
+            ("YYYYY", "Y", 4),
+            ("ቀን D, YYY", "Y", 8),
+            ("yyyy-M-D", "yyyy", 0),
+            ("ዓ.ም YYYY Hr", "Hr", 9),
+            ("D-MX-YYYY", "X", 3),
+        ] {
+            match try_format(&qen, pattern) {
+                Err(error::Error::UnknownSpecifier { token, position }) => {
+                    assert_eq!((token.as_str(), position), (bad, at), "{pattern}");
+                }
+                other => panic!("{pattern}: {other:?}"),
+            }
+            assert!(!format(&qen, pattern).is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_escaped_text() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+        for (pattern, expected) in [
+            ("[Monthly] MM [report] D", "Monthly ጥር report 10"),
+            ("[YYYY]-YYYY", "YYYY-2015"),
+            ("[[]D]", "[10]"),
+            ("[]D", "10"),
+            ("D [ቀን] MMM [Day] ዓ.ም", "10 ቀን ጥር Day ዓ.ም"),
+            ("ቀን [D", "ቀን D"),
+        ] {
+            assert_eq!(format(&qen, pattern), expected, "{pattern}");
+        }
+
+        assert!(matches!(
+            try_format(&qen, "ቀን [D"),
+            Err(error::Error::UnknownSpecifier { token, position: 3 }) if token == "["
+        ));
+
+        for qen in qen.iter_days_until(&(qen + 800)) {
+            let pattern = "[Day] D [of] MMM [(Month] M[)], [Year] YYYY ዓ.ም [QQ:] QQ";
+            let expected = format!(
+                "Day {:02} of {} (Month {:02}), Year {} ዓ.ም QQ: {:02}",
+                qen.day(),
+                qen.month(),
+                qen.month() as u8,
+                qen.year(),
+                (qen.ordinal() / 4 / 360) + 1,
+            );
+
+            assert_eq!(try_format(&qen, pattern)?, expected);
+            assert_eq!(format(&qen, pattern), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weeks_follow_the_numbering() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 20)?;
+
+        for qen in start.iter_days_until(&(start + 400)) {
+            for numbering in [
+                WeekNumbering::Ethiopian(crate::Samint::Ihud),
+                WeekNumbering::Ethiopian(crate::Samint::Senyo),
+                WeekNumbering::Iso,
+            ] {
+                assert_eq!(
+                    try_format_weeks(&qen, "WW [WW] D", numbering)?,
+                    format!("{:02} WW {:02}", qen.week_of_year(numbering).1, qen.day())
+                );
+            }
+
+            // the output of lenient patterns stays as it was
+            assert_eq!(format(&qen, "WW VV"), "WW VV");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_digit_styles_leave_text_alone() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let pattern = "[Q1] D MMM YYYY ዓ.ም";
+
+        assert_eq!(
+            format_digits(&qen, pattern, DigitStyle::Ethiopic),
+            "Q1 ፲ ጥር ፳፻፲፭ ዓ.ም"
+        );
+        assert_eq!(
+            format_digits(&qen, pattern, DigitStyle::ArabicIndic),
+            "Q1 ١٠ ጥር ٢٠١٥ ዓ.ም"
+        );
+        assert_eq!(
+            format_digits(&qen, pattern, DigitStyle::Ascii),
+            format(&qen, pattern)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_presets_golden() {
+        let golden = [
+            (
+                (2015, Werh::Tir, 10),
+                ["ጥር 10 ቀን 2015 ዓ.ም", "10-ጥር-2015", "10/05/15", "2015-05-10"],
+            ),
+            (
+                (2015, Werh::Puagme, 6),
+                [
+                    "ጳጉሜ 06 ቀን 2015 ዓ.ም",
+                    "06-ጳጉሜ-2015",
+                    "06/13/15",
+                    "2015-13-06",
+                ],
+            ),
+            (
+                (2000, Werh::Meskerem, 1),
+                [
+                    "መስከረም 01 ቀን 2000 ዓ.ም",
+                    "01-መስከ-2000",
+                    "01/01/00",
+                    "2000-01-01",
+                ],
+            ),
+        ];
+
+        for ((year, month, day), expected) in golden {
+            let qen = Zemen::from_eth_cal(year, month, day).unwrap();
+
+            for (preset, expected) in DisplayPreset::ALL.iter().zip(expected) {
+                assert_eq!(preset.format(&qen), expected, "{}", preset.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso_preset_matches_display_from_year_1000() {
+        for year in [999, 1000, 2015, 9999] {
+            let qen = Zemen::from_eth_cal(year, Werh::Tir, 10).unwrap();
+            let iso = DisplayPreset::ISO.format(&qen);
+
+            assert_eq!(iso == qen.to_string(), year >= 1000, "{iso}");
+        }
+    }
+
     #[test]
     fn test_format_specifiers_with_unicode() {
         // with unicode
@@ -0,0 +1,102 @@
+//! Greetings for public holidays, e.g. for messages sent out on the day.
+//!
+//! This module is behind the `unstable-holidays` feature, see
+//! [`unstable`](crate::unstable).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::{greetings, holiday::{EthiopianHolidays, HolidayProvider}, Locale, Zemen, Werh};
+//! # use zemen::error;
+//! let qen = Zemen::from_eth_cal(2016, Werh::Miyazia, 27)?;
+//!
+//! for holiday in EthiopianHolidays.holidays_on(&qen) {
+//!     if let Some(greeting) = greetings::for_holiday(holiday, Locale::Amharic) {
+//!         println!("{greeting}");
+//!     }
+//! }
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use crate::{holiday::Holiday, Locale};
+
+/// Get the greeting for `holiday`, e.g. `እንኳን ለብርሃነ ትንሳኤው አደረሳችሁ` for
+/// Fasika.
+///
+/// ስቅለት, Good Friday, is a day of fasting and mourning rather than a feast,
+/// so it has no greeting and gets `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{greetings, holiday::Holiday, Locale};
+/// assert_eq!(
+///     greetings::for_holiday(Holiday::Enkutatash, Locale::Amharic),
+///     Some("እንኳን ለአዲሱ ዓመት አደረሳችሁ")
+/// );
+/// assert_eq!(
+///     greetings::for_holiday(Holiday::Timket, Locale::English),
+///     Some("Happy Timket!")
+/// );
+/// assert_eq!(greetings::for_holiday(Holiday::Siklet, Locale::Amharic), None);
+/// ```
+pub fn for_holiday(holiday: Holiday, locale: Locale) -> Option<&'static str> {
+    let greeting = match locale {
+        Locale::Amharic => match holiday {
+            Holiday::Enkutatash => "እንኳን ለአዲሱ ዓመት አደረሳችሁ",
+            Holiday::Meskel => "እንኳን ለብርሃነ መስቀሉ አደረሳችሁ",
+            Holiday::Genna => "እንኳን ለብርሃነ ልደቱ አደረሳችሁ",
+            Holiday::Timket => "እንኳን ለብርሃነ ጥምቀቱ አደረሳችሁ",
+            Holiday::Adwa => "እንኳን ለዓድዋ ድል በዓል አደረሳችሁ",
+            Holiday::Siklet => return None,
+            Holiday::Fasika => "እንኳን ለብርሃነ ትንሳኤው አደረሳችሁ",
+            Holiday::LabourDay => "እንኳን ለዓለም አቀፍ የሠራተኞች ቀን አደረሳችሁ",
+            Holiday::PatriotsVictory => "እንኳን ለአርበኞች የድል ቀን አደረሳችሁ",
+            Holiday::DergDownfall => "እንኳን ለግንቦት 20 በዓል አደረሳችሁ",
+            Holiday::EidAlFitr => "እንኳን ለኢድ አል ፈጥር በዓል አደረሳችሁ",
+            Holiday::EidAlAdha => "እንኳን ለኢድ አል አድሐ በዓል አደረሳችሁ",
+            Holiday::Mawlid => "እንኳን ለመውሊድ በዓል አደረሳችሁ",
+        },
+        Locale::English => match holiday {
+            Holiday::Enkutatash => "Happy Ethiopian New Year!",
+            Holiday::Meskel => "Happy Meskel!",
+            Holiday::Genna => "Merry Christmas!",
+            Holiday::Timket => "Happy Timket!",
+            Holiday::Adwa => "Happy Adwa Victory Day!",
+            Holiday::Siklet => return None,
+            Holiday::Fasika => "Happy Easter!",
+            Holiday::LabourDay => "Happy Labour Day!",
+            Holiday::PatriotsVictory => "Happy Patriots' Victory Day!",
+            Holiday::DergDownfall => "Happy Ginbot 20!",
+            Holiday::EidAlFitr => "Eid Mubarak!",
+            Holiday::EidAlAdha => "Eid Mubarak!",
+            Holiday::Mawlid => "Happy Mawlid!",
+        },
+    };
+
+    Some(greeting)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_feast_has_a_greeting() {
+        for holiday in Holiday::ALL {
+            for locale in Locale::ALL {
+                let greeting = for_holiday(holiday, locale);
+                assert_eq!(
+                    greeting.is_none(),
+                    holiday == Holiday::Siklet,
+                    "{holiday:?}"
+                );
+
+                if let (Some(greeting), Locale::Amharic) = (greeting, locale) {
+                    assert!(greeting.starts_with("እንኳን ለ"), "{greeting}");
+                    assert!(greeting.ends_with(" አደረሳችሁ"), "{greeting}");
+                }
+            }
+        }
+    }
+}
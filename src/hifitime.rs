@@ -0,0 +1,120 @@
+//! Converting `Zemen` to and from [hifitime](https://docs.rs/hifitime)
+//! `Epoch`s.
+//!
+//! A `Zemen` becomes the `Epoch` of **midnight UTC** of the date, and an
+//! `Epoch` of any time scale is read back as the UTC day it falls on. An
+//! `Epoch` only reaches about 3.27 million years either side of 1900, so
+//! converting dates beyond that, like [`Zemen::MIN`] and [`Zemen::MAX`],
+//! fails.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use hifitime::{Epoch, TimeScale, Unit};
+//! # use zemen::{error, Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//! let epoch = Epoch::try_from(qen)?;
+//! assert_eq!(epoch, Epoch::from_gregorian_utc_at_midnight(2023, 1, 18));
+//!
+//! // the time of day and the time scale don't matter
+//! let later = (epoch + 23 * Unit::Hour).to_time_scale(TimeScale::GPST);
+//! assert_eq!(Zemen::try_from(later)?, qen);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use ::hifitime::Epoch;
+
+use crate::{error, Zemen};
+
+impl TryFrom<Zemen> for Epoch {
+    type Error = error::Error;
+
+    /// Get the `Epoch` of midnight UTC of the date, failing if it's beyond
+    /// the range of `Epoch`.
+    fn try_from(qen: Zemen) -> Result<Self, Self::Error> {
+        let (year, month, day) = qen.try_into_gregorian_parts()?;
+        let epoch = Epoch::maybe_from_gregorian_utc(year, month, day, 0, 0, 0, 0)
+            .map_err(|err| error::Error::InvalidVariant("Epoch", err.to_string()))?;
+
+        // `Epoch` saturates at the ends of its range instead of failing
+        if epoch.to_gregorian_utc() != (year, month, day, 0, 0, 0, 0) {
+            return Err(error::Error::InvalidVariant("Epoch", qen.to_string()));
+        }
+
+        Ok(epoch)
+    }
+}
+
+impl TryFrom<Epoch> for Zemen {
+    type Error = error::Error;
+
+    /// Get the UTC day `epoch` falls on.
+    fn try_from(epoch: Epoch) -> Result<Self, Self::Error> {
+        let (year, month, day, ..) = epoch.to_gregorian_utc();
+
+        Zemen::checked_from_gre_cal(year, month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::hifitime::{TimeScale, Unit};
+
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_round_trip() {
+        let start = Zemen::from_eth_cal(1960, Werh::Meskerem, 1).unwrap();
+
+        for offset in (0..40_000).step_by(7) {
+            let qen = start + offset;
+            let epoch = Epoch::try_from(qen).unwrap();
+
+            assert_eq!(epoch.time_scale, TimeScale::UTC);
+            assert_eq!(Zemen::try_from(epoch).unwrap(), qen);
+        }
+    }
+
+    #[test]
+    fn test_epoch_is_read_as_its_utc_day() {
+        let qen = Zemen::from_eth_cal(2016, Werh::Puagme, 5).unwrap();
+        let midnight = Epoch::try_from(qen).unwrap();
+        let last_second = midnight + 86_399 * Unit::Second;
+
+        assert_eq!(Zemen::try_from(last_second).unwrap(), qen);
+        assert_eq!(
+            Zemen::try_from(last_second + Unit::Second).unwrap(),
+            qen.next()
+        );
+        assert_eq!(
+            Zemen::try_from(midnight - Unit::Nanosecond).unwrap(),
+            qen.previous()
+        );
+
+        for ts in [
+            TimeScale::TAI,
+            TimeScale::TT,
+            TimeScale::GPST,
+            TimeScale::ET,
+        ] {
+            assert_eq!(
+                Zemen::try_from(last_second.to_time_scale(ts)).unwrap(),
+                qen,
+                "{ts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_epoch_range() {
+        for year in [-3_000_000, -10_000, 1, 10_000, 3_000_000] {
+            let qen = Zemen::from_eth_cal(year, Werh::Meskerem, 1).unwrap();
+            let epoch = Epoch::try_from(qen).unwrap();
+            assert_eq!(Zemen::try_from(epoch).unwrap(), qen);
+        }
+
+        assert!(Epoch::try_from(Zemen::MIN).is_err());
+        assert!(Epoch::try_from(Zemen::MAX).is_err());
+    }
+}
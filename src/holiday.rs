@@ -0,0 +1,755 @@
+//! Ethiopian public holidays.
+//!
+//! A [`HolidayProvider`] lists the holidays of an Ethiopian year.
+//! [`EthiopianHolidays`] computes the ones that follow the calendar, i.e.
+//! every holiday except the Islamic ones, whose dates depend on the sighting
+//! of the moon and are only known once announced. [`RefreshableProvider`]
+//! adds announced dates on top of another provider, and can be refreshed
+//! while a service keeps running.
+//!
+//! This module is behind the `unstable-holidays` feature, see
+//! [`unstable`](crate::unstable).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::{error, holiday::{EthiopianHolidays, Holiday, HolidayProvider}, Zemen, Werh};
+//! let holidays = EthiopianHolidays.holidays(2016);
+//!
+//! assert_eq!(holidays[0], (Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?, Holiday::Enkutatash));
+//! assert!(holidays.contains(&(Zemen::from_eth_cal(2016, Werh::Miyazia, 27)?, Holiday::Fasika)));
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::RwLock;
+
+#[cfg(any(feature = "toml", feature = "json"))]
+use crate::error;
+use crate::{conversion, Locale, Zemen};
+
+/// The public holidays of Ethiopia.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Holiday {
+    /// New year, Meskerem 1.
+    Enkutatash,
+    /// The finding of the True Cross, Meskerem 17.
+    Meskel,
+    /// Christmas, on the 25th of December of the Julian calendar.
+    Genna,
+    /// Epiphany, Tir 11.
+    Timket,
+    /// The victory at Adwa, Yekatit 23.
+    Adwa,
+    /// Good Friday, two days before Fasika.
+    Siklet,
+    /// Easter, on the Orthodox (Julian) Easter.
+    Fasika,
+    /// International workers' day, May 1.
+    LabourDay,
+    /// Patriots' victory day, Miyazia 27.
+    PatriotsVictory,
+    /// The downfall of the Derg, Ginbot 20.
+    DergDownfall,
+    /// The end of Ramadan, observed.
+    EidAlFitr,
+    /// The feast of sacrifice, observed.
+    EidAlAdha,
+    /// The birthday of the Prophet, observed.
+    Mawlid,
+}
+
+impl Holiday {
+    /// Every holiday.
+    pub const ALL: [Holiday; 13] = [
+        Self::Enkutatash,
+        Self::Meskel,
+        Self::Genna,
+        Self::Timket,
+        Self::Adwa,
+        Self::Siklet,
+        Self::Fasika,
+        Self::LabourDay,
+        Self::PatriotsVictory,
+        Self::DergDownfall,
+        Self::EidAlFitr,
+        Self::EidAlAdha,
+        Self::Mawlid,
+    ];
+
+    /// Returns `true` if the date of the holiday depends on the sighting of
+    /// the moon, so [`EthiopianHolidays`] doesn't list it.
+    pub fn is_observed(self) -> bool {
+        matches!(self, Self::EidAlFitr | Self::EidAlAdha | Self::Mawlid)
+    }
+
+    /// Get the name of the holiday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{holiday::Holiday, Locale};
+    /// assert_eq!(Holiday::Meskel.name(Locale::Amharic), "መስቀል");
+    /// assert_eq!(Holiday::Adwa.name(Locale::English), "Adwa Victory Day");
+    /// ```
+    pub fn name(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Amharic => match self {
+                Self::Enkutatash => "እንቁጣጣሽ",
+                Self::Meskel => "መስቀል",
+                Self::Genna => "ገና",
+                Self::Timket => "ጥምቀት",
+                Self::Adwa => "የዓድዋ ድል በዓል",
+                Self::Siklet => "ስቅለት",
+                Self::Fasika => "ፋሲካ",
+                Self::LabourDay => "የሠራተኞች ቀን",
+                Self::PatriotsVictory => "የአርበኞች ቀን",
+                Self::DergDownfall => "ደርግ የወደቀበት ቀን",
+                Self::EidAlFitr => "ኢድ አል ፈጥር",
+                Self::EidAlAdha => "ኢድ አል አድሐ",
+                Self::Mawlid => "መውሊድ",
+            },
+            Locale::English => match self {
+                Self::Enkutatash => "Enkutatash",
+                Self::Meskel => "Meskel",
+                Self::Genna => "Genna",
+                Self::Timket => "Timket",
+                Self::Adwa => "Adwa Victory Day",
+                Self::Siklet => "Siklet",
+                Self::Fasika => "Fasika",
+                Self::LabourDay => "Labour Day",
+                Self::PatriotsVictory => "Patriots' Victory Day",
+                Self::DergDownfall => "Derg Downfall Day",
+                Self::EidAlFitr => "Eid al-Fitr",
+                Self::EidAlAdha => "Eid al-Adha",
+                Self::Mawlid => "Mawlid",
+            },
+        }
+    }
+}
+
+/// A source of the holidays of a year.
+pub trait HolidayProvider {
+    /// Get the holidays of the Ethiopian `year`, sorted by date.
+    fn holidays(&self, year: i32) -> Vec<(Zemen, Holiday)>;
+
+    /// Get the holidays falling on `qen`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, holiday::{EthiopianHolidays, Holiday, HolidayProvider}, Zemen, Werh};
+    /// let meskel = Zemen::from_eth_cal(2016, Werh::Meskerem, 17)?;
+    ///
+    /// assert_eq!(EthiopianHolidays.holidays_on(&meskel), [Holiday::Meskel]);
+    /// assert!(EthiopianHolidays.holidays_on(&meskel.next()).is_empty());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn holidays_on(&self, qen: &Zemen) -> Vec<Holiday> {
+        self.holidays(qen.year())
+            .into_iter()
+            .filter(|(date, _)| date == qen)
+            .map(|(_, holiday)| holiday)
+            .collect()
+    }
+}
+
+/// The holidays that follow the calendar, i.e. all but the
+/// [observed](Holiday::is_observed) ones.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct EthiopianHolidays;
+
+impl HolidayProvider for EthiopianHolidays {
+    fn holidays(&self, year: i32) -> Vec<(Zemen, Holiday)> {
+        // the Gregorian and Julian year the Ethiopian year's Tir falls in
+        let january = year.saturating_add(8);
+        let fasika = julian_easter(january);
+
+        let mut holidays: Vec<_> = [
+            (conversion::eth_to_jdn(year, 1, 1), Holiday::Enkutatash),
+            (conversion::eth_to_jdn(year, 1, 17), Holiday::Meskel),
+            (
+                conversion::julian_to_jdn(january - 1, 12, 25),
+                Holiday::Genna,
+            ),
+            (conversion::eth_to_jdn(year, 5, 11), Holiday::Timket),
+            (conversion::eth_to_jdn(year, 6, 23), Holiday::Adwa),
+            (fasika - 2, Holiday::Siklet),
+            (fasika, Holiday::Fasika),
+            (conversion::gre_to_jdn(january, 5, 1), Holiday::LabourDay),
+            (
+                conversion::eth_to_jdn(year, 8, 27),
+                Holiday::PatriotsVictory,
+            ),
+            (conversion::eth_to_jdn(year, 9, 20), Holiday::DergDownfall),
+        ]
+        .into_iter()
+        .filter_map(|(jdn, holiday)| Some((Zemen::from_jdn(jdn).ok()?, holiday)))
+        .filter(|(qen, _)| qen.year() == year)
+        .collect();
+
+        holidays.sort_by_key(|(qen, _)| *qen);
+        holidays
+    }
+}
+
+/// Get the Julian day number of the Orthodox Easter of `year`, with Meeus'
+/// Julian algorithm.
+fn julian_easter(year: i32) -> i32 {
+    let (a, b, c) = (year.rem_euclid(4), year.rem_euclid(7), year.rem_euclid(19));
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+
+    conversion::julian_to_jdn(year, month as u8, day as u8)
+}
+
+/// The announced date of a holiday in one year, e.g. of Eid al-Fitr once
+/// the moon was sighted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observance {
+    pub holiday: Holiday,
+    pub date: Zemen,
+}
+
+/// A provider with announced dates on top of another one.
+///
+/// An [`Observance`] replaces the date the inner provider has for its
+/// holiday in the same year, or adds the holiday if the inner provider
+/// doesn't have it. The observances can be replaced at any time, e.g. with
+/// [`RefreshableProvider::refresh`] from a background task, while other
+/// threads keep reading holidays.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, holiday::*, Zemen, Werh};
+/// let provider = RefreshableProvider::new(EthiopianHolidays);
+/// let eid = Zemen::from_eth_cal(2016, Werh::Miyazia, 1)?;
+/// assert!(provider.holidays_on(&eid).is_empty());
+///
+/// provider.set_observances(vec![Observance { holiday: Holiday::EidAlFitr, date: eid }]);
+/// assert_eq!(provider.holidays_on(&eid), [Holiday::EidAlFitr]);
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct RefreshableProvider<P> {
+    inner: P,
+    observances: RwLock<Vec<Observance>>,
+}
+
+impl<P: HolidayProvider> RefreshableProvider<P> {
+    /// Create a provider with no observances yet on top of `inner`.
+    pub fn new(inner: P) -> Self {
+        RefreshableProvider {
+            inner,
+            observances: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Get the provider the observances are on top of.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Get the current observances.
+    pub fn observances(&self) -> Vec<Observance> {
+        self.observances
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Replace every observance with `observances`.
+    pub fn set_observances(&self, observances: Vec<Observance>) {
+        *self
+            .observances
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = observances;
+    }
+
+    /// Replace every observance with the ones `source` resolves to, e.g. a
+    /// request to a service publishing announcements.
+    ///
+    /// The observances are kept as they are if `source` fails, and nothing
+    /// is locked while waiting for it, so it doesn't hold up readers. Any
+    /// executor can run it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, holiday::*, Zemen, Werh};
+    /// async fn fetch_announcements() -> Result<Vec<Observance>, error::Error> {
+    ///     Ok(vec![Observance {
+    ///         holiday: Holiday::EidAlAdha,
+    ///         date: Zemen::from_eth_cal(2016, Werh::Sene, 9)?,
+    ///     }])
+    /// }
+    ///
+    /// async fn refresh(provider: &RefreshableProvider<EthiopianHolidays>) -> Result<(), error::Error> {
+    ///     provider.refresh(fetch_announcements()).await
+    /// }
+    /// ```
+    pub async fn refresh<S, E>(&self, source: S) -> Result<(), E>
+    where
+        S: Future<Output = Result<Vec<Observance>, E>>,
+    {
+        let observances = source.await?;
+        self.set_observances(observances);
+        Ok(())
+    }
+}
+
+impl<P: HolidayProvider> HolidayProvider for RefreshableProvider<P> {
+    fn holidays(&self, year: i32) -> Vec<(Zemen, Holiday)> {
+        let observances: Vec<_> = self
+            .observances
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .filter(|observance| observance.date.year() == year)
+            .copied()
+            .collect();
+
+        let mut holidays = self.inner.holidays(year);
+        holidays.retain(|(_, holiday)| !observances.iter().any(|o| o.holiday == *holiday));
+        holidays.extend(observances.iter().map(|o| (o.date, o.holiday)));
+
+        holidays.sort_by_key(|(qen, _)| *qen);
+        holidays
+    }
+}
+
+/// Holidays added, removed, or moved in some years, e.g. one-off holidays
+/// declared by the government, merged over a provider with
+/// [`WithOverrides`].
+///
+/// The overrides of a year are applied in order: removals, then moves, which
+/// replace the date the provider has for the holiday (or add it), then
+/// additions, which are added next to any date the holiday already has, e.g.
+/// a second day of Eid.
+///
+/// # Format
+///
+/// With the `toml` or `json` feature, overrides can be loaded from a file
+/// shipped with an application. Every year is a table with the optional
+/// `removed`, `moved`, and `added` lists:
+///
+/// ```toml
+/// [2016]
+/// removed = ["LabourDay"]
+/// moved = [{ holiday = "Adwa", date = "2016-06-24" }]
+/// added = [{ holiday = "EidAlFitr", date = "2016-08-02" }]
+/// ```
+///
+/// or, in JSON:
+///
+/// ```json
+/// {"2016": {"removed": ["LabourDay"], "added": [{"holiday": "EidAlFitr", "date": "2016-08-02"}]}}
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, holiday::*, Zemen, Werh};
+/// let eid = Zemen::from_eth_cal(2016, Werh::Miyazia, 2)?;
+/// let overrides = HolidayOverrides::new()
+///     .with_removed(2016, Holiday::LabourDay)
+///     .with_added(Observance { holiday: Holiday::EidAlFitr, date: eid });
+///
+/// let provider = WithOverrides::new(EthiopianHolidays, overrides);
+/// assert_eq!(provider.holidays_on(&eid), [Holiday::EidAlFitr]);
+/// assert!(provider.holidays(2016).iter().all(|(_, holiday)| *holiday != Holiday::LabourDay));
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct HolidayOverrides {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_years"))]
+    years: BTreeMap<i32, YearOverrides>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+struct YearOverrides {
+    removed: Vec<Holiday>,
+    moved: Vec<Observance>,
+    added: Vec<Observance>,
+}
+
+impl HolidayOverrides {
+    /// Create overrides that don't change anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `observance`, next to any date its holiday already has.
+    pub fn with_added(mut self, observance: Observance) -> Self {
+        self.year(observance.date.year()).added.push(observance);
+        self
+    }
+
+    /// Remove `holiday` from `year`.
+    pub fn with_removed(mut self, year: i32, holiday: Holiday) -> Self {
+        self.year(year).removed.push(holiday);
+        self
+    }
+
+    /// Move the holiday of `observance` to its date, in the year of the
+    /// date.
+    pub fn with_moved(mut self, observance: Observance) -> Self {
+        self.year(observance.date.year()).moved.push(observance);
+        self
+    }
+
+    /// Load overrides in the TOML [format](HolidayOverrides#format).
+    ///
+    /// Fails if the TOML doesn't have the format, or a date isn't in the
+    /// year it's listed under.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, holiday::*};
+    /// let overrides = HolidayOverrides::from_toml(r#"
+    ///     [2016]
+    ///     moved = [{ holiday = "Adwa", date = "2016-06-24" }]
+    /// "#)?;
+    ///
+    /// assert!(HolidayOverrides::from_toml(r#"2016 = { moved = [{ holiday = "Adwa", date = "2017-06-24" }] }"#).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, error::Error> {
+        let overrides: Self = toml::from_str(s)
+            .map_err(|err| error::Error::InvalidVariant("holiday overrides", err.to_string()))?;
+        overrides.validate()
+    }
+
+    /// Load overrides in the JSON [format](HolidayOverrides#format).
+    ///
+    /// Fails if the JSON doesn't have the format, or a date isn't in the
+    /// year it's listed under.
+    #[cfg(feature = "json")]
+    pub fn from_json(s: &str) -> Result<Self, error::Error> {
+        let overrides: Self = serde_json::from_str(s)
+            .map_err(|err| error::Error::InvalidVariant("holiday overrides", err.to_string()))?;
+        overrides.validate()
+    }
+
+    #[cfg(any(feature = "toml", feature = "json"))]
+    fn validate(self) -> Result<Self, error::Error> {
+        for (year, overrides) in &self.years {
+            let misplaced = overrides
+                .moved
+                .iter()
+                .chain(&overrides.added)
+                .find(|observance| observance.date.year() != *year);
+
+            if let Some(observance) = misplaced {
+                return Err(error::Error::InvalidVariant(
+                    "holiday overrides",
+                    format!("{} is listed under {year}", observance.date),
+                ));
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn year(&mut self, year: i32) -> &mut YearOverrides {
+        self.years.entry(year).or_default()
+    }
+
+    fn apply(&self, year: i32, holidays: &mut Vec<(Zemen, Holiday)>) {
+        let Some(overrides) = self.years.get(&year) else {
+            return;
+        };
+
+        holidays.retain(|(_, holiday)| {
+            !overrides.removed.contains(holiday)
+                && !overrides.moved.iter().any(|o| o.holiday == *holiday)
+        });
+        holidays.extend(overrides.moved.iter().map(|o| (o.date, o.holiday)));
+        holidays.extend(overrides.added.iter().map(|o| (o.date, o.holiday)));
+
+        holidays.sort_by_key(|(qen, _)| *qen);
+    }
+}
+
+// TOML keys are always strings, so years are parsed from them.
+#[cfg(feature = "serde")]
+fn deserialize_years<'de, D>(deserializer: D) -> Result<BTreeMap<i32, YearOverrides>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{Deserialize, Error, Unexpected};
+
+    BTreeMap::<String, YearOverrides>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(year, overrides)| match year.trim().parse() {
+            Ok(parsed) => Ok((parsed, overrides)),
+            Err(_) => Err(D::Error::invalid_value(Unexpected::Str(&year), &"a year")),
+        })
+        .collect()
+}
+
+/// A provider with [`HolidayOverrides`] merged over it.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct WithOverrides<P> {
+    inner: P,
+    overrides: HolidayOverrides,
+}
+
+impl<P: HolidayProvider> WithOverrides<P> {
+    /// Merge `overrides` over `inner`.
+    pub fn new(inner: P, overrides: HolidayOverrides) -> Self {
+        WithOverrides { inner, overrides }
+    }
+
+    /// Get the provider the overrides are merged over.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Get the overrides.
+    pub fn overrides(&self) -> &HolidayOverrides {
+        &self.overrides
+    }
+}
+
+impl<P: HolidayProvider> HolidayProvider for WithOverrides<P> {
+    fn holidays(&self, year: i32) -> Vec<(Zemen, Holiday)> {
+        let mut holidays = self.inner.holidays(year);
+        self.overrides.apply(year, &mut holidays);
+        holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use super::*;
+    use crate::{error, Werh};
+
+    fn day(year: i32, werh: Werh, day: u8) -> Zemen {
+        Zemen::from_eth_cal(year, werh, day).expect("a valid date")
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Noop;
+        impl Wake for Noop {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let waker = Waker::from(Arc::new(Noop));
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_ethiopian_holidays_2016() {
+        // from Gregorian September 2023 to September 2024
+        assert_eq!(
+            EthiopianHolidays.holidays(2016),
+            [
+                (day(2016, Werh::Meskerem, 1), Holiday::Enkutatash),
+                (day(2016, Werh::Meskerem, 17), Holiday::Meskel),
+                (day(2016, Werh::Tahasass, 28), Holiday::Genna),
+                (day(2016, Werh::Tir, 11), Holiday::Timket),
+                (day(2016, Werh::Yekatit, 23), Holiday::Adwa),
+                (day(2016, Werh::Miyazia, 23), Holiday::LabourDay),
+                (day(2016, Werh::Miyazia, 25), Holiday::Siklet),
+                (day(2016, Werh::Miyazia, 27), Holiday::Fasika),
+                (day(2016, Werh::Miyazia, 27), Holiday::PatriotsVictory),
+                (day(2016, Werh::Ginbot, 20), Holiday::DergDownfall),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_genna_and_fasika_follow_the_julian_calendar() {
+        for (year, genna_day, fasika) in [
+            (2015, 29, (2023, 4, 16)),
+            (2016, 28, (2024, 5, 5)),
+            (2017, 29, (2025, 4, 20)),
+            (2018, 29, (2026, 4, 12)),
+        ] {
+            let holidays = EthiopianHolidays.holidays(year);
+            let find = |holiday| {
+                holidays
+                    .iter()
+                    .find(|(_, h)| *h == holiday)
+                    .map(|(qen, _)| *qen)
+            };
+
+            assert_eq!(
+                find(Holiday::Genna),
+                Some(day(year, Werh::Tahasass, genna_day))
+            );
+            assert_eq!(
+                find(Holiday::Fasika).map(|qen| conversion::jdn_to_gre(qen.to_jdn())),
+                Some(fasika)
+            );
+            assert!(Holiday::ALL
+                .iter()
+                .filter(|holiday| !holiday.is_observed())
+                .all(|holiday| find(*holiday).is_some()));
+        }
+
+        assert!(EthiopianHolidays.holidays(crate::zemen::MAX_YEAR).len() < 10);
+    }
+
+    #[test]
+    fn test_refresh_replaces_and_adds() -> Result<(), error::Error> {
+        let provider = RefreshableProvider::new(EthiopianHolidays);
+        let eid = Observance {
+            holiday: Holiday::EidAlFitr,
+            date: day(2016, Werh::Miyazia, 2),
+        };
+        let moved = Observance {
+            holiday: Holiday::Adwa,
+            date: day(2016, Werh::Yekatit, 24),
+        };
+
+        block_on(provider.refresh(async { Ok::<_, error::Error>(vec![eid, moved]) }))?;
+
+        let holidays = provider.holidays(2016);
+        assert_eq!(holidays.len(), 11);
+        assert!(holidays.contains(&(eid.date, Holiday::EidAlFitr)));
+        assert_eq!(provider.holidays_on(&moved.date), [Holiday::Adwa]);
+        assert!(provider
+            .holidays_on(&day(2016, Werh::Yekatit, 23))
+            .is_empty());
+        assert_eq!(provider.holidays(2017), EthiopianHolidays.holidays(2017));
+
+        let failed = block_on(provider.refresh(async { Err("offline") }));
+        assert_eq!(failed, Err("offline"));
+        assert_eq!(provider.observances(), [eid, moved]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overrides_apply_in_order() {
+        let eid = Observance {
+            holiday: Holiday::EidAlFitr,
+            date: day(2016, Werh::Miyazia, 2),
+        };
+        let second_day = Observance {
+            date: eid.date.next(),
+            ..eid
+        };
+        let adwa = Observance {
+            holiday: Holiday::Adwa,
+            date: day(2016, Werh::Yekatit, 24),
+        };
+
+        let overrides = HolidayOverrides::new()
+            .with_removed(2016, Holiday::LabourDay)
+            .with_moved(eid)
+            .with_moved(adwa)
+            .with_added(second_day);
+        let provider = WithOverrides::new(EthiopianHolidays, overrides);
+
+        let holidays = provider.holidays(2016);
+        assert_eq!(holidays.len(), 11);
+        assert!(holidays.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(provider.holidays_on(&eid.date), [Holiday::EidAlFitr]);
+        assert_eq!(provider.holidays_on(&second_day.date), [Holiday::EidAlFitr]);
+        assert_eq!(provider.holidays_on(&adwa.date), [Holiday::Adwa]);
+        assert!(provider
+            .holidays_on(&day(2016, Werh::Miyazia, 23))
+            .is_empty());
+        assert_eq!(provider.holidays(2015), EthiopianHolidays.holidays(2015));
+
+        let stacked = WithOverrides::new(
+            RefreshableProvider::new(EthiopianHolidays),
+            HolidayOverrides::new(),
+        );
+        assert_eq!(stacked.holidays(2016), EthiopianHolidays.holidays(2016));
+    }
+
+    #[cfg(all(feature = "toml", feature = "json"))]
+    #[test]
+    fn test_overrides_files() -> Result<(), error::Error> {
+        let toml = r#"
+            [2016]
+            removed = ["LabourDay"]
+            moved = [{ holiday = "Adwa", date = "2016-06-24" }]
+            added = [{ holiday = "EidAlFitr", date = "2016-08-02" }]
+
+            [2017]
+            removed = ["Meskel"]
+        "#;
+        let json = r#"{
+            "2016": {
+                "removed": ["LabourDay"],
+                "moved": [{"holiday": "Adwa", "date": "2016-06-24"}],
+                "added": [{"holiday": "EidAlFitr", "date": "2016-08-02"}]
+            },
+            "2017": {"removed": ["Meskel"]}
+        }"#;
+
+        let expected = HolidayOverrides::new()
+            .with_removed(2016, Holiday::LabourDay)
+            .with_moved(Observance {
+                holiday: Holiday::Adwa,
+                date: day(2016, Werh::Yekatit, 24),
+            })
+            .with_added(Observance {
+                holiday: Holiday::EidAlFitr,
+                date: day(2016, Werh::Miyazia, 2),
+            })
+            .with_removed(2017, Holiday::Meskel);
+
+        assert_eq!(HolidayOverrides::from_toml(toml)?, expected);
+        assert_eq!(HolidayOverrides::from_json(json)?, expected);
+        assert_eq!(HolidayOverrides::from_toml("")?, HolidayOverrides::new());
+
+        for broken in [
+            r#"[2016]
+               removed = ["Christmas"]"#,
+            r#"[2016]
+               renamed = ["Adwa"]"#,
+            r#"[2016]
+               added = [{ holiday = "Mawlid", date = "2016-13-06" }]"#,
+            r#"[2016]
+               added = [{ holiday = "Mawlid", date = "2017-01-06" }]"#,
+            r#"[twenty]
+               removed = ["Adwa"]"#,
+        ] {
+            assert!(HolidayOverrides::from_toml(broken).is_err(), "{broken}");
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_observances_from_json() {
+        let json = r#"[{"holiday": "EidAlAdha", "date": "2016-10-09"}]"#;
+        let observances: Vec<Observance> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            observances,
+            [Observance {
+                holiday: Holiday::EidAlAdha,
+                date: day(2016, Werh::Sene, 9)
+            }]
+        );
+    }
+}
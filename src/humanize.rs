@@ -0,0 +1,211 @@
+//! Human readable, Amharic descriptions of how far apart two dates are.
+
+use crate::Zemen;
+
+/// How a distance is rounded to a whole number of units.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Rounding {
+    /// Round down, e.g. 45 days is 1 month.
+    #[default]
+    Down,
+    /// Round to the nearest unit, halves up, e.g. 45 days is 2 months.
+    Nearest,
+    /// Round to the nearest half of a unit, e.g. 45 days is a month and a
+    /// half, `1 ወር ተኩል`.
+    Halves,
+}
+
+/// How [`relative_with`] picks the unit of a distance and rounds it.
+///
+/// A distance is described in the largest unit whose threshold it reaches.
+/// The units themselves are always a week of 7 days, a month of 30, and a
+/// year of 365; e.g. with `months_from` at 60, 45 days is `6 ሳምንት`, and
+/// with `weeks_from` and `months_from` above 45, it's `45 ቀን`.
+///
+/// Amounts below one unit are rounded up to one (or to a half with
+/// [`Rounding::Halves`]).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HumanizeOptions {
+    /// Days from which a distance is in weeks.
+    pub weeks_from: i32,
+    /// Days from which a distance is in months.
+    pub months_from: i32,
+    /// Days from which a distance is in years.
+    pub years_from: i32,
+    /// How the distance is rounded to the unit.
+    pub rounding: Rounding,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        HumanizeOptions {
+            weeks_from: 7,
+            months_from: 30,
+            years_from: 365,
+            rounding: Rounding::Down,
+        }
+    }
+}
+
+/// Describes `qen` relative to `now`, e.g. `ዛሬ`, `ነገ`, `ከ 3 ቀን በፊት`.
+///
+/// Distances are rounded down to the largest fitting unit: days below a
+/// week, weeks below a month (30 days), months below a year (365 days),
+/// and years otherwise. See [`relative_with`] to change that.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{humanize, Zemen, Werh, error};
+/// let now = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+///
+/// assert_eq!(humanize::relative(&now, &now), "ዛሬ");
+/// assert_eq!(humanize::relative(&(now + 1), &now), "ነገ");
+/// assert_eq!(humanize::relative(&(now + 3), &now), "ከ 3 ቀን በኋላ");
+/// assert_eq!(humanize::relative(&(now + -60), &now), "ከ 2 ወር በፊት");
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn relative(qen: &Zemen, now: &Zemen) -> String {
+    relative_with(qen, now, &HumanizeOptions::default())
+}
+
+/// Describes `qen` relative to `now` like [`relative`], with the thresholds
+/// and rounding of `options`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{humanize::{self, HumanizeOptions, Rounding}, Zemen, Werh, error};
+/// let now = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+/// let later = now + 45;
+///
+/// let halves = HumanizeOptions {
+///     rounding: Rounding::Halves,
+///     ..Default::default()
+/// };
+/// assert_eq!(humanize::relative_with(&later, &now, &halves), "ከ 1 ወር ተኩል በኋላ");
+///
+/// let in_days = HumanizeOptions {
+///     weeks_from: 60,
+///     months_from: 60,
+///     ..Default::default()
+/// };
+/// assert_eq!(humanize::relative_with(&later, &now, &in_days), "ከ 45 ቀን በኋላ");
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn relative_with(qen: &Zemen, now: &Zemen, options: &HumanizeOptions) -> String {
+    // far apart dates overflow an `i32`
+    let days = i64::from(qen.to_jdn()) - i64::from(now.to_jdn());
+
+    match days {
+        0 => "ዛሬ".to_string(),
+        1 => "ነገ".to_string(),
+        -1 => "ትናንት".to_string(),
+        _ => {
+            let amount = amount(days.abs(), options);
+
+            if days > 0 {
+                format!("ከ {} በኋላ", amount)
+            } else {
+                format!("ከ {} በፊት", amount)
+            }
+        }
+    }
+}
+
+fn amount(days: i64, options: &HumanizeOptions) -> String {
+    let (length, unit) = largest_unit(days, options);
+
+    match options.rounding {
+        Rounding::Down => format!("{} {}", (days / length).max(1), unit),
+        Rounding::Nearest => format!("{} {}", ((days + length / 2) / length).max(1), unit),
+        Rounding::Halves => {
+            let halves = ((2 * days + length / 2) / length).max(1);
+
+            match (halves / 2, halves % 2) {
+                (0, _) => format!("ግማሽ {}", unit),
+                (whole, 0) => format!("{} {}", whole, unit),
+                (whole, _) => format!("{} {} ተኩል", whole, unit),
+            }
+        }
+    }
+}
+
+fn largest_unit(days: i64, options: &HumanizeOptions) -> (i64, &'static str) {
+    if days >= i64::from(options.years_from) {
+        (365, "ዓመት")
+    } else if days >= i64::from(options.months_from) {
+        (30, "ወር")
+    } else if days >= i64::from(options.weeks_from) {
+        (7, "ሳምንት")
+    } else {
+        (1, "ቀን")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_relative_units() -> Result<(), error::Error> {
+        let now = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+        assert_eq!(relative(&(now + -1), &now), "ትናንት");
+        assert_eq!(relative(&(now + 6), &now), "ከ 6 ቀን በኋላ");
+        assert_eq!(relative(&(now + 14), &now), "ከ 2 ሳምንት በኋላ");
+        assert_eq!(relative(&(now + -400), &now), "ከ 1 ዓመት በፊት");
+
+        assert_eq!(relative(&Zemen::MAX, &Zemen::MIN), "ከ 8394353 ዓመት በኋላ");
+        assert_eq!(relative(&Zemen::MIN, &Zemen::MAX), "ከ 8394353 ዓመት በፊት");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_with_options() -> Result<(), error::Error> {
+        let now = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let with =
+            |days: i32, options: HumanizeOptions| relative_with(&(now + days), &now, &options);
+
+        let nearest = HumanizeOptions {
+            rounding: Rounding::Nearest,
+            ..Default::default()
+        };
+        assert_eq!(with(45, nearest), "ከ 2 ወር በኋላ");
+        assert_eq!(with(-10, nearest), "ከ 1 ሳምንት በፊት");
+        assert_eq!(with(11, nearest), "ከ 2 ሳምንት በኋላ");
+        assert_eq!(with(545, nearest), "ከ 1 ዓመት በኋላ");
+        assert_eq!(with(548, nearest), "ከ 2 ዓመት በኋላ");
+
+        let halves = HumanizeOptions {
+            rounding: Rounding::Halves,
+            ..Default::default()
+        };
+        assert_eq!(with(60, halves), "ከ 2 ወር በኋላ");
+        assert_eq!(with(-550, halves), "ከ 1 ዓመት ተኩል በፊት");
+        assert_eq!(with(3, halves), "ከ 3 ቀን በኋላ");
+
+        let early_months = HumanizeOptions {
+            months_from: 10,
+            rounding: Rounding::Halves,
+            ..Default::default()
+        };
+        assert_eq!(with(12, early_months), "ከ ግማሽ ወር በኋላ");
+
+        let early_weeks = HumanizeOptions {
+            weeks_from: 2,
+            ..Default::default()
+        };
+        assert_eq!(with(3, early_weeks), "ከ 1 ሳምንት በኋላ");
+        assert_eq!(with(1, early_weeks), "ነገ");
+
+        assert_eq!(
+            with(45, HumanizeOptions::default()),
+            relative(&(now + 45), &now)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+//! The ISO 8601 date representations, applied to Ethiopian dates.
+//!
+//! The year, month, and day are the Ethiopian ones, so the month goes up to
+//! 13: ጳጉሜ 5, 2015 is `2015-13-05`. The representations are:
+//!
+//! - calendar dates, `2015-05-10`, or `20150510` in the basic format.
+//! - ordinal dates, `2015-130`, or `2015130` in the basic format.
+//! - expanded years, for years outside of 0 to 9999 (or any other year when
+//!   parsing). They have a sign and at least 5 digits, `+10000-01-01` and
+//!   `-00005-13-06`, and are only read in the extended format, where the `-`
+//!   separators say where the year ends.
+
+use crate::{error, Zemen};
+
+pub(crate) fn format(qen: &Zemen) -> String {
+    let (_, month, day) = qen.to_calendar_date();
+    format!("{}-{:02}-{:02}", year(qen.year()), month as u8, day)
+}
+
+pub(crate) fn format_ordinal(qen: &Zemen) -> String {
+    format!("{}-{:03}", year(qen.year()), qen.ordinal())
+}
+
+fn year(year: i32) -> String {
+    match year {
+        0..=9999 => format!("{year:04}"),
+        _ => format!(
+            "{}{:05}",
+            if year < 0 { '-' } else { '+' },
+            year.unsigned_abs()
+        ),
+    }
+}
+
+pub(crate) fn parse(s: &str) -> Result<Zemen, error::Error> {
+    let invalid = || error::Error::InvalidVariant("ISO 8601 date", s.to_string());
+
+    let (sign, unsigned) = match s.as_bytes().first() {
+        Some(b'+') => (Some(1), &s[1..]),
+        Some(b'-') => (Some(-1), &s[1..]),
+        _ => (None, s),
+    };
+
+    let year_len = match sign {
+        // expanded years need the extended format to know where they end
+        Some(_) => unsigned
+            .find('-')
+            .filter(|len| *len >= 4)
+            .ok_or_else(invalid)?,
+        None => 4,
+    };
+    let digits = unsigned.as_bytes().get(..year_len).ok_or_else(invalid)?;
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return Err(invalid());
+    }
+
+    let year: i32 = unsigned[..year_len].parse().map_err(|_| invalid())?;
+    let year = year * sign.unwrap_or(1);
+    let rest = &unsigned[year_len..];
+
+    let (month, day, ordinal) = match rest.as_bytes() {
+        // YYYY-MM-DD
+        [b'-', m1, m2, b'-', d1, d2] => (Some([*m1, *m2]), Some([*d1, *d2]), None),
+        // YYYY-DDD
+        [b'-', o @ ..] if o.len() == 3 => (None, None, Some(o)),
+        // YYYYMMDD
+        [m1, m2, d1, d2] if sign.is_none() => (Some([*m1, *m2]), Some([*d1, *d2]), None),
+        // YYYYDDD
+        o if o.len() == 3 && sign.is_none() => (None, None, Some(o)),
+        _ => return Err(invalid()),
+    };
+
+    match (month, day, ordinal) {
+        (Some(month), Some(day), _) => {
+            let (month, day) = (
+                number(&month).ok_or_else(invalid)?,
+                number(&day).ok_or_else(invalid)?,
+            );
+            Zemen::new(year, month as u8, day as u8)
+        }
+        (_, _, Some(ordinal)) => {
+            Zemen::from_ordinal_date(year, number(ordinal).ok_or_else(invalid)?)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn number(digits: &[u8]) -> Option<u16> {
+    digits.iter().try_fold(0, |value: u16, digit| {
+        digit
+            .is_ascii_digit()
+            .then(|| value * 10 + (digit - b'0') as u16)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_conformance() -> Result<(), error::Error> {
+        let tir_10 = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let puagme_6 = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+
+        for (input, expected) in [
+            ("2015-05-10", tir_10),
+            ("20150510", tir_10),
+            ("2015-130", tir_10),
+            ("2015130", tir_10),
+            ("2015-13-06", puagme_6),
+            ("2015-366", puagme_6),
+            ("+2015-05-10", tir_10),
+            ("+02015-130", tir_10),
+            ("0000-01-01", Zemen::from_eth_cal(0, Werh::Meskerem, 1)?),
+            (
+                "+10000-01-01",
+                Zemen::from_eth_cal(10_000, Werh::Meskerem, 1)?,
+            ),
+            ("-00005-13-06", Zemen::from_eth_cal(-5, Werh::Puagme, 6)?),
+            ("-0005-001", Zemen::from_eth_cal(-5, Werh::Meskerem, 1)?),
+            ("+4194303-366", Zemen::MAX),
+            ("-4194304-01-01", Zemen::MIN),
+        ] {
+            assert_eq!(parse(input)?, expected, "{input}");
+        }
+
+        for input in [
+            "",
+            "2015",
+            "2015-5-10",
+            "2015-05-1",
+            "2015-0510",
+            "201505-10",
+            "2015-14-01",
+            "2015-13-07",
+            "2015-00-10",
+            "2015-05-00",
+            "2015-000",
+            "2016-366",
+            "2015-1300",
+            "015-05-10",
+            "02015-05-10",
+            "+20150510",
+            "+201-05-10",
+            "-2015130",
+            "++2015-05-10",
+            "2015-05-10T00:00",
+            "2015-05-10 ",
+            "2015-W01-1",
+            "2015-+5-10",
+            "+4194304-01-01",
+            "+99999999999-01-01",
+            "２０１５-05-10",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), error::Error> {
+        for (qen, calendar, ordinal) in [
+            (
+                Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+                "2015-05-10",
+                "2015-130",
+            ),
+            (
+                Zemen::from_eth_cal(5, Werh::Hedar, 1)?,
+                "0005-03-01",
+                "0005-061",
+            ),
+            (
+                Zemen::from_eth_cal(9999, Werh::Puagme, 5)?,
+                "9999-13-05",
+                "9999-365",
+            ),
+            (
+                Zemen::from_eth_cal(10_000, Werh::Meskerem, 1)?,
+                "+10000-01-01",
+                "+10000-001",
+            ),
+            (
+                Zemen::from_eth_cal(-1, Werh::Puagme, 6)?,
+                "-00001-13-06",
+                "-00001-366",
+            ),
+            (Zemen::MIN, "-4194304-01-01", "-4194304-001"),
+            (Zemen::MAX, "+4194303-13-06", "+4194303-366"),
+        ] {
+            assert_eq!(format(&qen), calendar);
+            assert_eq!(format_ordinal(&qen), ordinal);
+            assert_eq!(parse(calendar)?, qen);
+            assert_eq!(parse(ordinal)?, qen);
+        }
+
+        let start = Zemen::from_eth_cal(-20, Werh::Meskerem, 1)?;
+        for qen in start.iter_days_until(&(start + 10_000)) {
+            assert_eq!(parse(&format(&qen))?, qen);
+            assert_eq!(parse(&format_ordinal(&qen))?, qen);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+//! The leap year rule, and the cycle of the four Evangelists it follows.
+//!
+//! Every year is named after one of the Evangelists, in a four year cycle
+//! counted from the year of creation: the Amete Alem year is the year plus
+//! 5500, and the remainder of it divided by 4 gives the Evangelist:
+//!
+//! ```txt
+//! remainder  Evangelist
+//! 1          Matewos (Matthew)
+//! 2          Marqos (Mark)
+//! 3          Luqas (Luke)        Puagme has 6 days
+//! 0          Yohannes (John)
+//! ```
+//!
+//! 5500 is a multiple of 4, so the remainder of the year itself is the
+//! same: a year is a leap year when `year % 4 == 3`, the year of Luqas.
+//! Negative years are counted with a remainder that is never negative, so
+//! the cycle continues without a gap before the year 1.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::leap::{self, Evangelist};
+//! assert_eq!(Evangelist::of(2015), Evangelist::Luqas);
+//! assert!(leap::is_leap_year(2015));
+//! assert!(leap::is_yohannes_year(2016));
+//! assert_eq!(leap::leap_rule().evangelist, Evangelist::Luqas);
+//! ```
+
+use std::fmt;
+
+use crate::validator;
+
+/// The years to add to a year to get its Amete Alem year.
+pub const AMETE_ALEM_OFFSET: i32 = 5500;
+
+/// The Evangelist a year is named after, see the
+/// [module documentation](self).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Evangelist {
+    Matewos,
+    Marqos,
+    Luqas,
+    Yohannes,
+}
+
+impl Evangelist {
+    /// The Evangelists in the order of the cycle.
+    pub const ALL: [Evangelist; 4] = [
+        Evangelist::Matewos,
+        Evangelist::Marqos,
+        Evangelist::Luqas,
+        Evangelist::Yohannes,
+    ];
+
+    /// Get the Evangelist `year` is named after.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::leap::Evangelist;
+    /// assert_eq!(Evangelist::of(2016), Evangelist::Yohannes);
+    /// assert_eq!(Evangelist::of(2017), Evangelist::Matewos);
+    /// assert_eq!(Evangelist::of(-1), Evangelist::Luqas);
+    /// ```
+    pub const fn of(year: i32) -> Self {
+        match year.rem_euclid(4) {
+            1 => Evangelist::Matewos,
+            2 => Evangelist::Marqos,
+            3 => Evangelist::Luqas,
+            _ => Evangelist::Yohannes,
+        }
+    }
+
+    /// Get the remainder of the years of this Evangelist divided by 4.
+    pub const fn remainder(self) -> i32 {
+        match self {
+            Evangelist::Matewos => 1,
+            Evangelist::Marqos => 2,
+            Evangelist::Luqas => 3,
+            Evangelist::Yohannes => 0,
+        }
+    }
+
+    /// Get the name of the Evangelist written in latin script.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::leap::Evangelist;
+    /// assert_eq!(Evangelist::Yohannes.latin_name(), "Yohannes");
+    /// assert_eq!(Evangelist::Yohannes.to_string(), "ዮሐንስ");
+    /// ```
+    pub fn latin_name(&self) -> &'static str {
+        match self {
+            Evangelist::Matewos => "Matewos",
+            Evangelist::Marqos => "Marqos",
+            Evangelist::Luqas => "Luqas",
+            Evangelist::Yohannes => "Yohannes",
+        }
+    }
+}
+
+impl fmt::Display for Evangelist {
+    /// Formats the Evangelist's name in amharic.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Evangelist::Matewos => "ማቴዎስ",
+            Evangelist::Marqos => "ማርቆስ",
+            Evangelist::Luqas => "ሉቃስ",
+            Evangelist::Yohannes => "ዮሐንስ",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// A description of the leap year rule, for validators and explanations
+/// that shouldn't spell it out again.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LeapRule {
+    /// Years in a cycle, 4.
+    pub cycle: i32,
+    /// The remainder of leap years divided by `cycle`, 3.
+    pub remainder: i32,
+    /// The Evangelist of leap years, Luqas.
+    pub evangelist: Evangelist,
+    /// Days of Puagme in leap years, 6.
+    pub leap_puagme_days: u8,
+    /// Days of Puagme in other years, 5.
+    pub common_puagme_days: u8,
+}
+
+const LEAP_RULE: LeapRule = LeapRule {
+    cycle: 4,
+    remainder: Evangelist::Luqas.remainder(),
+    evangelist: Evangelist::Luqas,
+    leap_puagme_days: 6,
+    common_puagme_days: 5,
+};
+
+impl LeapRule {
+    /// Returns `true` if `year` is a leap year under this rule.
+    pub const fn applies_to(&self, year: i32) -> bool {
+        year.rem_euclid(self.cycle) == self.remainder
+    }
+}
+
+impl fmt::Display for LeapRule {
+    /// Writes the rule out in English.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::leap;
+    /// assert_eq!(
+    ///     leap::leap_rule().to_string(),
+    ///     "A year is a leap year, with 6 days in Puagme instead of 5, when its remainder divided by 4 is 3, the year of Luqas."
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A year is a leap year, with {} days in Puagme instead of {}, when its remainder divided by {} is {}, the year of {}.",
+            self.leap_puagme_days,
+            self.common_puagme_days,
+            self.cycle,
+            self.remainder,
+            self.evangelist.latin_name()
+        )
+    }
+}
+
+/// Get the leap year rule.
+pub const fn leap_rule() -> LeapRule {
+    LEAP_RULE
+}
+
+/// Returns `true` if Puagme has 6 days in `year`.
+pub const fn is_leap_year(year: i32) -> bool {
+    validator::is_leap_year(year)
+}
+
+/// Returns `true` if `year` is named after the Evangelist of leap years.
+/// The same as [`is_leap_year`], named after the cycle.
+pub const fn is_evangelist_leap(year: i32) -> bool {
+    Evangelist::of(year).remainder() == LEAP_RULE.evangelist.remainder()
+}
+
+/// Returns `true` if `year` is the year of Matewos.
+pub const fn is_matewos_year(year: i32) -> bool {
+    matches!(Evangelist::of(year), Evangelist::Matewos)
+}
+
+/// Returns `true` if `year` is the year of Marqos.
+pub const fn is_marqos_year(year: i32) -> bool {
+    matches!(Evangelist::of(year), Evangelist::Marqos)
+}
+
+/// Returns `true` if `year` is the year of Luqas, a leap year.
+pub const fn is_luqas_year(year: i32) -> bool {
+    matches!(Evangelist::of(year), Evangelist::Luqas)
+}
+
+/// Returns `true` if `year` is the year of Yohannes, the year after a leap
+/// year.
+pub const fn is_yohannes_year(year: i32) -> bool {
+    matches!(Evangelist::of(year), Evangelist::Yohannes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_matches_the_calendar() {
+        for year in -1000..3000 {
+            let evangelist = Evangelist::of(year);
+
+            assert_eq!(is_leap_year(year), leap_rule().applies_to(year));
+            assert_eq!(is_leap_year(year), is_evangelist_leap(year));
+            assert_eq!(
+                validator::days_in_month(year, 13),
+                if is_leap_year(year) {
+                    LEAP_RULE.leap_puagme_days
+                } else {
+                    LEAP_RULE.common_puagme_days
+                }
+            );
+            assert_eq!(
+                (year + AMETE_ALEM_OFFSET).rem_euclid(4),
+                evangelist.remainder()
+            );
+            assert_eq!(
+                Evangelist::of(year + 1).remainder(),
+                (evangelist.remainder() + 1) % 4
+            );
+
+            let predicates = [
+                is_matewos_year(year),
+                is_marqos_year(year),
+                is_luqas_year(year),
+                is_yohannes_year(year),
+            ];
+            assert_eq!(predicates.iter().filter(|p| **p).count(), 1);
+        }
+    }
+}
@@ -37,7 +37,8 @@
 //! println!("month(number): {}", qen.month() as u8);
 //! println!("day: {}", qen.day());
 //!
-//! // get the next, and previous date. `next` and `previous` consume `self`
+//! // get the next, and previous date. `Zemen` is `Copy`, so `qen` can still be
+//! // used after calling `next` and `previous`
 //! let nege = qen.next();
 //! println!("nege: {}", nege);
 //! let tilant = nege.previous().previous();
@@ -91,15 +92,164 @@
 //! # }
 //! ```
 //!
+//! ## `chrono` feature
+//! With the `chrono` feature, `chrono::NaiveDate` converts to `Zemen` with
+//! `From`, and back with [`Zemen::to_naive_date`].
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! # use zemen::*;
+//! # fn main() -> Result<(), error::Error> {
+//! let date = chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+//! let qen = Zemen::from(&date);
+//!
+//! assert_eq!(qen, Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?);
+//! assert_eq!(qen.to_naive_date()?, date);
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+//!
+//! ## `serde` feature
+//! With the `serde` feature, `Zemen` is serialized as its `YYYY-MM-DD` string,
+//! and `Werh` and `Samint` as their latin names. See [`serde`](crate::serde)
+//! for other ways to write dates.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! # use zemen::*;
+//! # fn main() -> Result<(), error::Error> {
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//!
+//! assert_eq!(serde_json::to_string(&qen).unwrap(), r#""2015-05-10""#);
+//! assert_eq!(serde_json::from_str::<Zemen>(r#""2015-05-10""#).unwrap(), qen);
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+//!
+//! ## Panics
+//!
+//! Parsing, converting, and formatting never panic, whatever the input:
+//! they return a `Result` or an `Option` instead. Counting the days between
+//! two dates, e.g. with [`Period::len_days`], is done in `i64`, so it holds
+//! even between [`Zemen::MIN`] and [`Zemen::MAX`].
+//!
+//! The functions that do panic say so under `# Panics`, and mostly have a
+//! fallible version. These are the arithmetic that can step past the ends
+//! of `Zemen`, like `+` and `-` with days or [`Zemen::next_weekday`] near
+//! [`Zemen::MAX`], with [`Zemen::checked_add_days`] and
+//! [`Zemen::checked_next_weekday`], and the conversions to types with a
+//! smaller range, like [`Zemen::to_gre`], with [`Zemen::try_to_gre`].
+//!
+//! The crate denies `unwrap`, `expect`, and `panic!` outside of tests, so
+//! each of those panics, and every invariant `expect`ed on, is opted into
+//! where it happens.
+//!
+
+#![cfg_attr(feature = "unstable-step", feature(step_trait))]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::missing_panics_doc
+    )
+)]
 
+mod base32;
 mod conversion;
+mod digits;
+mod duration;
 mod formatting;
+mod iso8601;
+mod locale;
+mod partition;
+mod period;
+mod range;
 mod samint;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod schema;
+mod season;
+mod series;
+mod sort_key;
+mod ussd;
 mod validator;
+mod version;
 mod werh;
+#[cfg(feature = "arbitrary_precision")]
+mod wide;
+mod year;
 mod zemen;
 
+pub mod agenda;
+#[cfg(feature = "anonymize")]
+pub mod anonymize;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "bson")]
+pub mod bson;
+pub mod calendar;
+pub mod codes;
 pub mod error;
+pub mod format;
+#[cfg(feature = "unstable-holidays")]
+pub mod greetings;
+#[cfg(feature = "hifitime")]
+pub mod hifitime;
+#[cfg(feature = "unstable-holidays")]
+pub mod holiday;
+pub mod humanize;
+pub mod leap;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "prost")]
+pub mod prost;
+pub mod quota;
+pub mod recurrence;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "svg")]
+pub mod render;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod snapshots;
+#[cfg(any(
+    feature = "rusqlite",
+    feature = "diesel",
+    feature = "sqlx",
+    feature = "postgres-types",
+    feature = "sea-orm",
+    feature = "redis"
+))]
+pub mod sql;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod system;
+pub mod tables;
+#[cfg(any(feature = "minijinja", feature = "tera"))]
+pub mod templates;
+pub mod unstable;
+
+pub use crate::digits::DigitStyle;
+pub use crate::duration::ZemenDuration;
+pub use crate::formatting::DisplayPreset;
+pub use crate::locale::Locale;
+pub use crate::partition::PartitionStyle;
+pub use crate::period::{Period, Weighting};
+pub use crate::range::{Days, ZemenRange};
 pub use crate::samint::Samint;
-pub use crate::werh::Werh;
-pub use crate::zemen::Zemen;
+pub use crate::season::Season;
+pub use crate::series::{FillPolicy, Series};
+pub use crate::sort_key::{CalendarTag, SortKey};
+pub use crate::version::{VersionInfo, VERSION_INFO};
+pub use crate::werh::{months_of, Werh};
+#[cfg(feature = "arbitrary_precision")]
+pub use crate::wide::WideZemen;
+pub use crate::year::{YearCache, YearInfo};
+pub use crate::zemen::{Granularity, MonthOverflow, Zemen};
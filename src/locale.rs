@@ -0,0 +1,99 @@
+//! Languages dates can be written out in.
+
+use std::str::FromStr;
+
+use crate::{error, Samint, Werh, Zemen};
+
+/// The language of generated text, like agendas and accessibility labels.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Locale {
+    /// Amharic, written in Ethiopic script.
+    #[default]
+    Amharic,
+    /// English, with the months' names written in latin script.
+    English,
+}
+
+impl Locale {
+    /// Every locale.
+    pub const ALL: [Locale; 2] = [Locale::Amharic, Locale::English];
+
+    /// Get the full name of `samint`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Locale, Samint};
+    /// assert_eq!(Locale::Amharic.weekday_name(Samint::Kidame), "ቅዳሜ");
+    /// assert_eq!(Locale::English.weekday_name(Samint::Kidame), "Saturday");
+    /// ```
+    pub fn weekday_name(self, samint: Samint) -> String {
+        match self {
+            Locale::Amharic => samint.to_string(),
+            Locale::English => match samint {
+                Samint::Ihud => "Sunday",
+                Samint::Senyo => "Monday",
+                Samint::Makisenyo => "Tuesday",
+                Samint::Irob => "Wednesday",
+                Samint::Hamus => "Thursday",
+                Samint::Arb => "Friday",
+                Samint::Kidame => "Saturday",
+            }
+            .to_string(),
+        }
+    }
+
+    /// Get the full name of `werh`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Locale, Werh};
+    /// assert_eq!(Locale::Amharic.month_name(Werh::Tir), "ጥር");
+    /// assert_eq!(Locale::English.month_name(Werh::Tir), "Tir");
+    /// ```
+    pub fn month_name(self, werh: Werh) -> String {
+        match self {
+            Locale::Amharic => werh.to_string(),
+            Locale::English => werh.latin_name().to_string(),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = error::Error;
+
+    /// Parse a locale from its name or ISO 639-1 code, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Locale};
+    /// assert_eq!("am".parse::<Locale>()?, Locale::Amharic);
+    /// assert_eq!("English".parse::<Locale>()?, Locale::English);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "am" | "amharic" => Ok(Locale::Amharic),
+            "en" | "english" => Ok(Locale::English),
+            _ => Err(error::Error::InvalidVariant("Locale", s.to_string())),
+        }
+    }
+}
+
+pub(crate) fn accessible_label(qen: &Zemen, locale: Locale) -> String {
+    let weekday = locale.weekday_name(qen.weekday());
+    let month = locale.month_name(qen.month());
+
+    match locale {
+        Locale::Amharic => format!(
+            "{} {} {} ቀን {} ዓመተ ምሕረት",
+            weekday,
+            month,
+            qen.day(),
+            qen.year()
+        ),
+        Locale::English => format!("{} {} {} {}", weekday, qen.day(), month, qen.year()),
+    }
+}
@@ -0,0 +1,85 @@
+//! Hive style partition paths, e.g. `year=2016/month=05/day=10`.
+
+use crate::{conversion, error, Zemen};
+
+/// Which calendar's components a partition path is written in.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PartitionStyle {
+    /// Ethiopian year, month (01..13), and day.
+    Ethiopian,
+    /// Gregorian year, month (01..12), and day of the same date.
+    Gregorian,
+}
+
+pub(crate) fn partition_path(qen: &Zemen, style: PartitionStyle) -> String {
+    let (year, month, day) = match style {
+        PartitionStyle::Ethiopian => (qen.year(), qen.month() as u8, qen.day()),
+        PartitionStyle::Gregorian => conversion::jdn_to_gre(qen.to_jdn()),
+    };
+
+    format!("year={:04}/month={:02}/day={:02}", year, month, day)
+}
+
+pub(crate) fn parse(path: &str, style: PartitionStyle) -> Result<Zemen, error::Error> {
+    let invalid = || error::Error::InvalidVariant("partition path", path.to_string());
+
+    let (mut year, mut month, mut day) = (None, None, None);
+    for segment in path.split('/') {
+        let Some((key, value)) = segment.split_once('=') else {
+            continue;
+        };
+
+        match key {
+            "year" => year = Some(value.parse::<i32>().map_err(|_| invalid())?),
+            "month" => month = Some(value.parse::<u8>().map_err(|_| invalid())?),
+            "day" => day = Some(value.parse::<u8>().map_err(|_| invalid())?),
+            _ => {}
+        }
+    }
+
+    let (Some(year), Some(month), Some(day)) = (year, month, day) else {
+        return Err(invalid());
+    };
+
+    match style {
+        PartitionStyle::Ethiopian => Zemen::new(year, month, day),
+        PartitionStyle::Gregorian => Zemen::checked_from_gre_cal(year, month, day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_partition_path_round_trip() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 11)?;
+
+        let path = partition_path(&qen, PartitionStyle::Ethiopian);
+        assert_eq!(path, "year=2015/month=05/day=11");
+        assert_eq!(parse(&path, PartitionStyle::Ethiopian)?, qen);
+
+        let path = partition_path(&qen, PartitionStyle::Gregorian);
+        assert_eq!(path, "year=2023/month=01/day=19");
+        assert_eq!(parse(&path, PartitionStyle::Gregorian)?, qen);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_partition_path() -> Result<(), error::Error> {
+        let qen = parse(
+            "s3://bucket/visits/year=2015/month=13/day=06/part-0000.parquet",
+            PartitionStyle::Ethiopian,
+        )?;
+        assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+
+        assert!(parse("year=2015/month=13", PartitionStyle::Ethiopian).is_err());
+        assert!(parse("year=2015/month=13/day=xx", PartitionStyle::Ethiopian).is_err());
+        assert!(parse("year=2015/month=02/day=30", PartitionStyle::Gregorian).is_err());
+        assert!(parse("year=1000000000/month=01/day=01", PartitionStyle::Gregorian).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,472 @@
+//! Spans of consecutive days.
+
+use crate::{error, validator, MonthOverflow, Werh, Zemen, ZemenDuration};
+
+/// The days from `start` up to, but not including, `end`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, Period, Zemen, Werh};
+/// let tir = Period::new(
+///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+///     Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?,
+/// );
+///
+/// assert_eq!(tir.len_days(), 30);
+/// assert!(tir.contains(&Zemen::from_eth_cal(2015, Werh::Tir, 30)?));
+/// assert!(!tir.contains(&Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?));
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Period {
+    start: Zemen,
+    end: Zemen,
+}
+
+impl Period {
+    /// Create a period from its first day and the day after its last day.
+    ///
+    /// If `end` is before `start` they are swapped.
+    pub fn new(start: Zemen, end: Zemen) -> Self {
+        if end.to_jdn() < start.to_jdn() {
+            Period {
+                start: end,
+                end: start,
+            }
+        } else {
+            Period { start, end }
+        }
+    }
+
+    /// Create a period from Julian day numbers, failing if either isn't a
+    /// date `Zemen` holds, e.g. the day after [`Zemen::MAX`].
+    pub(crate) fn from_jdn(start: i32, end: i32) -> Result<Self, error::Error> {
+        Ok(Period::new(Zemen::from_jdn(start)?, Zemen::from_jdn(end)?))
+    }
+
+    /// Create the period between two dates, in whichever order they are
+    /// given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, ZemenDuration, Werh};
+    /// let a = Zemen::from_eth_cal(2013, Werh::Hedar, 12)?;
+    /// let b = Zemen::from_eth_cal(2015, Werh::Megabit, 24)?;
+    ///
+    /// let period = Period::between(&b, &a);
+    /// assert_eq!(period.start(), &a);
+    /// assert_eq!(
+    ///     period.breakdown(),
+    ///     ZemenDuration { years: 2, months: 4, days: 12 }
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn between(a: &Zemen, b: &Zemen) -> Self {
+        Period::new(*a, *b)
+    }
+
+    /// Get the length of the period as whole years, then whole months, then
+    /// the remaining days, such that `start + breakdown == end`.
+    ///
+    /// Months are counted on the 13 month calendar, so e.g. from Nehase 10 to
+    /// Meskerem 10 is 2 months: one to Puagme 10 (clamped to Puagme 5 or 6),
+    /// and one to Meskerem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, ZemenDuration, Werh};
+    /// let period = Period::between(
+    ///     &Zemen::from_eth_cal(2015, Werh::Nehase, 10)?,
+    ///     &Zemen::from_eth_cal(2016, Werh::Meskerem, 10)?,
+    /// );
+    ///
+    /// assert_eq!(period.breakdown(), ZemenDuration::months(2));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn breakdown(&self) -> ZemenDuration {
+        let end = self.end.to_jdn();
+
+        let mut years = self.end.year() - self.start.year();
+        let mut anchor = self.add_clamped(ZemenDuration::years(years));
+        while anchor.to_jdn() > end {
+            years -= 1;
+            anchor = self.add_clamped(ZemenDuration::years(years));
+        }
+
+        let mut months = 12;
+        let mut duration = ZemenDuration {
+            years,
+            months,
+            days: 0,
+        };
+        let mut anchor = self.add_clamped(duration);
+        while anchor.to_jdn() > end {
+            months -= 1;
+            duration.months = months;
+            anchor = self.add_clamped(duration);
+        }
+
+        duration.days = end - anchor.to_jdn();
+        duration
+    }
+
+    /// Get the number of whole months in the period.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, Werh};
+    /// let period = Period::between(
+    ///     &Zemen::from_eth_cal(2013, Werh::Hedar, 12)?,
+    ///     &Zemen::from_eth_cal(2015, Werh::Megabit, 24)?,
+    /// );
+    ///
+    /// assert_eq!(period.whole_months(), 30);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[allow(clippy::expect_used, clippy::missing_panics_doc)]
+    pub fn whole_months(&self) -> i32 {
+        let end = self.end.to_jdn();
+        let add = |months| {
+            self.start
+                .add_months(months, MonthOverflow::Clamp)
+                .expect("clamping never rejects a date")
+                .to_jdn()
+        };
+
+        let mut months = (self.end.year() - self.start.year()) * 13
+            + (self.end.month() as i32 - self.start.month() as i32);
+        while add(months) > end {
+            months -= 1;
+        }
+
+        months
+    }
+
+    /// Get the number of whole weeks in the period.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, Werh};
+    /// let period = Period::between(
+    ///     &Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+    ///     &Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?,
+    /// );
+    ///
+    /// assert_eq!(period.whole_weeks(), 4);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn whole_weeks(&self) -> i32 {
+        // the weeks of any period fit an `i32`, unlike its days
+        (self.len_days() / 7) as i32
+    }
+
+    fn add_clamped(&self, duration: ZemenDuration) -> Zemen {
+        self.start + duration
+    }
+
+    /// Get the `dekad`th (1..3) ten day decade (አሥርት) of a month.
+    ///
+    /// Puagme is too short to split, so it only has a single dekad covering
+    /// all of its 5 or 6 days.
+    ///
+    /// Fails if the dekad ends on a day `Zemen` can't hold, i.e. it's the
+    /// Puagme of the year of [`Zemen::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, Werh};
+    /// let dekad = Period::dekad(2015, Werh::Tir, 2)?;
+    ///
+    /// assert_eq!(dekad.start(), &Zemen::from_eth_cal(2015, Werh::Tir, 11)?);
+    /// assert_eq!(dekad.end(), &Zemen::from_eth_cal(2015, Werh::Tir, 21)?);
+    ///
+    /// assert_eq!(Period::dekad(2015, Werh::Puagme, 1)?.len_days(), 6);
+    /// assert!(Period::dekad(2015, Werh::Puagme, 2).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn dekad(year: i32, month: Werh, dekad: u8) -> Result<Self, error::Error> {
+        let (count, len) = match month {
+            Werh::Puagme => (1, validator::days_in_month(year, month as u8)),
+            _ => (3, 10),
+        };
+        error::is_in_range(dekad as i32, 1, count, "dekad")?;
+
+        let start = Zemen::from_eth_cal(year, month, (dekad - 1) * 10 + 1)?.to_jdn();
+        Period::from_jdn(start, start + len as i32)
+    }
+
+    /// Iterate over the 37 dekads of a year: three for each month and one
+    /// for Puagme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Zemen, Werh};
+    /// let dekads: Vec<_> = Period::dekads(2015).collect();
+    ///
+    /// assert_eq!(dekads.len(), 37);
+    /// assert_eq!(dekads[4], Period::dekad(2015, Werh::Tikimit, 2)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn dekads(year: i32) -> impl Iterator<Item = Period> {
+        Werh::iter().flat_map(move |month| {
+            (1..=3).map_while(move |dekad| Period::dekad(year, month, dekad).ok())
+        })
+    }
+
+    /// Get the first day of the period.
+    pub fn start(&self) -> &Zemen {
+        &self.start
+    }
+
+    /// Get the day after the last day of the period.
+    pub fn end(&self) -> &Zemen {
+        &self.end
+    }
+
+    /// Get the number of days in the period.
+    ///
+    /// It's an `i64`, as the days between [`Zemen::MIN`] and [`Zemen::MAX`]
+    /// overflow an `i32`.
+    pub fn len_days(&self) -> i64 {
+        i64::from(self.end.to_jdn()) - i64::from(self.start.to_jdn())
+    }
+
+    /// Returns `true` if the period has no days, i.e. `start` and `end` are
+    /// the same date.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `qen` is one of the days in the period.
+    pub fn contains(&self, qen: &Zemen) -> bool {
+        let jdn = qen.to_jdn();
+        self.start.to_jdn() <= jdn && jdn < self.end.to_jdn()
+    }
+
+    /// Split the period where months start, the first and last part being
+    /// cut short if the period doesn't start or end on a month boundary.
+    #[allow(clippy::expect_used)]
+    pub(crate) fn split_months(&self) -> Vec<Period> {
+        let mut parts = Vec::new();
+        let mut start = self.start;
+
+        while start < self.end {
+            let next_month = start.end_of_month().to_jdn() + 1;
+            let end = next_month.min(self.end.to_jdn());
+            parts.push(
+                Period::from_jdn(start.to_jdn(), end).expect("a part ends within the period"),
+            );
+            start = *parts[parts.len() - 1].end();
+        }
+
+        parts
+    }
+}
+
+/// How [`Zemen::distribute`] weighs the months of a period against each
+/// other.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Weighting {
+    /// Every month gets the same share, however many of its days are in the
+    /// period.
+    Equal,
+    /// Months share by the number of their days in the period, so Puagme
+    /// gets a sixth of a whole month or less.
+    ByDayCount,
+    /// 30/360: twelve months of 30 days make a year, so months share by
+    /// their days in the period and Puagme gets nothing, unless the period
+    /// is only in Puagme.
+    Thirty360,
+}
+
+pub(crate) fn distribute(value: i64, period: &Period, weighting: Weighting) -> Vec<(Period, i64)> {
+    let months = period.split_months();
+    let mut weights: Vec<i128> = months
+        .iter()
+        .map(|month| match weighting {
+            Weighting::Equal => 1,
+            Weighting::ByDayCount => month.len_days().into(),
+            Weighting::Thirty360 if month.start().month() == Werh::Puagme => 0,
+            Weighting::Thirty360 => month.len_days().into(),
+        })
+        .collect();
+    if weights.iter().all(|weight| *weight == 0) {
+        weights.fill(1);
+    }
+
+    let total: i128 = weights.iter().sum();
+    let amount = (value as i128).abs();
+
+    // largest remainder: round every share down, then hand the units left
+    // over to the largest remainders, the earlier month first on a tie
+    let mut shares: Vec<(i128, i128)> = weights
+        .iter()
+        .map(|weight| (amount * weight / total, amount * weight % total))
+        .collect();
+    let left_over = amount - shares.iter().map(|(share, _)| share).sum::<i128>();
+
+    let mut order: Vec<usize> = (0..shares.len()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse(shares[*i].1));
+    for i in order.into_iter().take(left_over as usize) {
+        shares[i].0 += 1;
+    }
+
+    months
+        .into_iter()
+        .zip(shares)
+        .map(|(month, (share, _))| {
+            let share = if value < 0 { -share } else { share };
+            (month, share as i64)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_dekads_cover_the_year() {
+        for year in [2015, 2016] {
+            let mut expected_start = Zemen::from_eth_cal(year, Werh::Meskerem, 1).unwrap();
+
+            for dekad in Period::dekads(year) {
+                assert_eq!(dekad.start(), &expected_start);
+                expected_start = *dekad.end();
+            }
+
+            assert_eq!(
+                expected_start,
+                Zemen::from_eth_cal(year + 1, Werh::Meskerem, 1).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_dekads_at_the_end_of_zemen() {
+        let year = Zemen::MAX.year();
+
+        assert!(Period::dekad(year, Werh::Nehase, 3).is_ok());
+        assert!(Period::dekad(year, Werh::Puagme, 1).is_err());
+        assert!(Period::dekad(year + 1, Werh::Meskerem, 1).is_err());
+        assert_eq!(Period::dekads(year).count(), 36);
+    }
+
+    #[test]
+    fn test_len_of_all_of_zemen() {
+        let all = Period::new(Zemen::MIN, Zemen::MAX);
+        let days = i64::from(Zemen::MAX.to_jdn()) - i64::from(Zemen::MIN.to_jdn());
+
+        assert!(days > i64::from(i32::MAX));
+        assert_eq!(all.len_days(), days);
+        assert_eq!(all.whole_weeks() as i64, days / 7);
+    }
+
+    #[test]
+    fn test_breakdown_adds_back_up_to_the_end() {
+        let start = Zemen::from_eth_cal(2011, Werh::Puagme, 6).unwrap();
+
+        for days in (0..2000).step_by(7) {
+            let end = start + days;
+            let period = Period::between(&start, &end);
+            let breakdown = period.breakdown();
+
+            assert_eq!(start + breakdown, end, "{:?}", breakdown);
+            assert!((0..13).contains(&breakdown.months));
+            assert!(breakdown.days < 30);
+        }
+    }
+
+    #[test]
+    fn test_distribute_sums_to_the_value() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Hamle, 17)?;
+
+        for days in [0, 1, 14, 45, 100, 400] {
+            let period = Period::new(start, start + days);
+
+            for weighting in [
+                Weighting::Equal,
+                Weighting::ByDayCount,
+                Weighting::Thirty360,
+            ] {
+                for value in [0, 1, 7, -1_000_001, i64::MAX, i64::MIN] {
+                    let shares = distribute(value, &period, weighting);
+
+                    assert_eq!(
+                        shares.iter().map(|(_, share)| *share as i128).sum::<i128>(),
+                        if days == 0 { 0 } else { value as i128 },
+                        "{value} over {days} days by {weighting:?}"
+                    );
+                    assert_eq!(
+                        shares.iter().map(|(part, _)| part.len_days()).sum::<i64>(),
+                        i64::from(days)
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distribute_weights() -> Result<(), error::Error> {
+        // 14 days of Nehase, Puagme, and 10 days of Meskerem
+        let period = Period::new(
+            Zemen::from_eth_cal(2015, Werh::Nehase, 17)?,
+            Zemen::from_eth_cal(2016, Werh::Meskerem, 11)?,
+        );
+        let amounts = |weighting| -> Vec<i64> {
+            distribute(3000, &period, weighting)
+                .into_iter()
+                .map(|(_, share)| share)
+                .collect()
+        };
+
+        assert_eq!(amounts(Weighting::Equal), [1000, 1000, 1000]);
+        assert_eq!(amounts(Weighting::ByDayCount), [1400, 600, 1000]);
+        assert_eq!(amounts(Weighting::Thirty360), [1750, 0, 1250]);
+
+        let puagme = Period::new(
+            Zemen::from_eth_cal(2015, Werh::Puagme, 1)?,
+            Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?,
+        );
+        assert_eq!(distribute(10, &puagme, Weighting::Thirty360)[0].1, 10);
+
+        let thirds = Period::new(
+            Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+            Zemen::from_eth_cal(2015, Werh::Megabit, 1)?,
+        );
+        let shares = distribute(100, &thirds, Weighting::Equal);
+        assert_eq!(shares[0].1, 50);
+        assert_eq!(shares[1].1, 50);
+        let shares = distribute(-101, &thirds, Weighting::Equal);
+        assert_eq!((shares[0].1, shares[1].1), (-51, -50));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_period_bounds_are_ordered() -> Result<(), error::Error> {
+        let tir_1 = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+        let tir_5 = Zemen::from_eth_cal(2015, Werh::Tir, 5)?;
+
+        let period = Period::new(tir_5, tir_1);
+        assert_eq!(period.start(), &tir_1);
+        assert_eq!(period.end(), &tir_5);
+        assert_eq!(period.len_days(), 4);
+
+        let empty = Period::new(tir_1, tir_1);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(&tir_1));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+//! Ethiopian calendar columns for dataframes, with
+//! [polars](https://docs.rs/polars).
+//!
+//! [`to_components`] splits a `Date` series into the Ethiopian `year`,
+//! `werh`, and `day` columns, all `Int32`, and [`from_components`] puts them
+//! back together into a `Date` series. Null dates give null components, and
+//! a null component gives a null date, so both keep the length of their
+//! input.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use polars_core::prelude::*;
+//! # use zemen::{error, Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//! let dates = Int32Chunked::from_slice("sold".into(), &[qen.to_epoch_day(), qen.to_epoch_day() + 30])
+//!     .into_date()
+//!     .into_series();
+//!
+//! let components = zemen::polars::to_components(&dates).unwrap();
+//! assert_eq!(components.get_column_names(), ["year", "werh", "day"]);
+//! assert_eq!(components.column("werh").unwrap().i32().unwrap().get(1), Some(6));
+//!
+//! let back = zemen::polars::from_components(
+//!     components.column("year").unwrap().as_materialized_series(),
+//!     components.column("werh").unwrap().as_materialized_series(),
+//!     components.column("day").unwrap().as_materialized_series(),
+//! )
+//! .unwrap();
+//! assert!(back.equals(&dates));
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use polars_core::prelude::*;
+
+use crate::{error, Zemen};
+
+fn compute_error(err: error::Error) -> PolarsError {
+    PolarsError::ComputeError(err.to_string().into())
+}
+
+/// Split a `Date` series into the `year`, `werh`, and `day` columns of its
+/// Ethiopian dates, failing if it isn't a `Date` series or has dates beyond
+/// the range of `Zemen`.
+pub fn to_components(dates: &Series) -> PolarsResult<DataFrame> {
+    let days = dates.date()?.physical();
+
+    let mut year = Vec::with_capacity(days.len());
+    let mut werh = Vec::with_capacity(days.len());
+    let mut day = Vec::with_capacity(days.len());
+
+    for days in days.iter() {
+        let qen = days
+            .map(Zemen::from_epoch_day)
+            .transpose()
+            .map_err(compute_error)?;
+
+        year.push(qen.map(|qen| qen.year()));
+        werh.push(qen.map(|qen| qen.month() as i32));
+        day.push(qen.map(|qen| qen.day() as i32));
+    }
+
+    DataFrame::new(vec![
+        Column::new("year".into(), year),
+        Column::new("werh".into(), werh),
+        Column::new("day".into(), day),
+    ])
+}
+
+/// Put the Ethiopian `year`, `werh`, and `day` series together into a
+/// `Date` series named `date`.
+///
+/// The series can be of any integer type, and must have the same length.
+/// Fails if any of them isn't a valid date.
+pub fn from_components(year: &Series, werh: &Series, day: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        year.len() == werh.len() && werh.len() == day.len(),
+        ShapeMismatch: "year, werh, and day must have the same length"
+    );
+
+    let year = year.cast(&DataType::Int32)?;
+    let werh = werh.cast(&DataType::Int32)?;
+    let day = day.cast(&DataType::Int32)?;
+
+    let days = year
+        .i32()?
+        .iter()
+        .zip(werh.i32()?.iter())
+        .zip(day.i32()?.iter())
+        .map(|((year, werh), day)| match (year, werh, day) {
+            (Some(year), Some(werh), Some(day)) => {
+                let werh = u8::try_from(werh)
+                    .map_err(|_| error::Error::InvalidVariant("werh", werh.to_string()))
+                    .and_then(crate::Werh::try_from);
+                let day = u8::try_from(day)
+                    .map_err(|_| error::Error::InvalidVariant("day", day.to_string()));
+
+                werh.and_then(|werh| Zemen::from_eth_cal(year, werh, day?))
+                    .map(|qen| Some(qen.to_epoch_day()))
+                    .map_err(compute_error)
+            }
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<Int32Chunked>>()?;
+
+    Ok(days.with_name("date".into()).into_date().into_series())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_components_round_trip() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 28)?;
+        let days: Vec<Option<i32>> = start
+            .iter_days_until(&(start + 12))
+            .map(|qen| (qen.day() != 2).then_some(qen.to_epoch_day()))
+            .collect();
+        let dates = Int32Chunked::from_iter_options("dates".into(), days.into_iter())
+            .into_date()
+            .into_series();
+
+        let components = to_components(&dates).unwrap();
+        assert_eq!(components.height(), 12);
+        let werh = components.column("werh").unwrap().i32().unwrap();
+        assert_eq!(werh.get(3), Some(13));
+        assert_eq!(werh.null_count(), 2);
+
+        let column = |name| {
+            components
+                .column(name)
+                .unwrap()
+                .as_materialized_series()
+                .clone()
+        };
+        let back = from_components(&column("year"), &column("werh"), &column("day")).unwrap();
+        assert!(back.equals_missing(&dates));
+        assert_eq!(back.name().as_str(), "date");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_components_are_rejected() {
+        let series = |values: &[i64]| Series::new("".into(), values);
+
+        let invalid = [
+            (series(&[2016]), series(&[13]), series(&[6])),
+            (series(&[2015]), series(&[14]), series(&[1])),
+            (series(&[2015]), series(&[-1]), series(&[1])),
+            (series(&[2015]), series(&[1]), series(&[300])),
+            (series(&[2015, 2016]), series(&[1]), series(&[1])),
+        ];
+        for (year, werh, day) in invalid {
+            assert!(from_components(&year, &werh, &day).is_err());
+        }
+
+        let numbers = Series::new("".into(), &[1, 2, 3]);
+        assert!(to_components(&numbers).is_err());
+    }
+}
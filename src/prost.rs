@@ -0,0 +1,195 @@
+//! Protobuf messages for `Zemen`, for gRPC services built with
+//! [prost](https://docs.rs/prost).
+//!
+//! A `Zemen` is a day, not an instant, so it converts to and from the
+//! well-known `google.protobuf.Timestamp` at **midnight UTC**: a date is
+//! written as the timestamp of its first second in UTC, and any timestamp is
+//! read back as the UTC day it falls on. A client in Addis Ababa (UTC+3)
+//! sending its local midnight would be read back as the day before, so send
+//! [`Date`] instead when the date isn't tied to UTC.
+//!
+//! [`Date`] is a message of the Ethiopian year, month, and day, shaped like
+//! `google.type.Date`:
+//!
+//! ```proto
+//! message Date {
+//!   int32 year = 1;
+//!   int32 month = 2; // 1 to 13
+//!   int32 day = 3;
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use prost_types::Timestamp;
+//! # use zemen::{error, prost::Date, Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//!
+//! let timestamp = Timestamp::from(qen);
+//! assert_eq!(timestamp, Timestamp { seconds: 1_674_000_000, nanos: 0 });
+//! assert_eq!(Zemen::try_from(timestamp)?, qen);
+//!
+//! let date = Date::from(qen);
+//! assert_eq!((date.year, date.month, date.day), (2015, 5, 10));
+//! assert_eq!(Zemen::try_from(date)?, qen);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use prost_types::Timestamp;
+
+use crate::{error, Zemen};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+
+/// An Ethiopian date as a protobuf message, see the
+/// [module documentation](self).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Date {
+    /// The year, e.g. 2015.
+    #[prost(int32, tag = "1")]
+    pub year: i32,
+    /// The month, from 1 (Meskerem) to 13 (Puagme).
+    #[prost(int32, tag = "2")]
+    pub month: i32,
+    /// The day of the month, from 1.
+    #[prost(int32, tag = "3")]
+    pub day: i32,
+}
+
+impl From<Zemen> for Date {
+    fn from(qen: Zemen) -> Self {
+        let (year, month, day) = qen.to_calendar_date();
+
+        Date {
+            year,
+            month: month as i32,
+            day: day.into(),
+        }
+    }
+}
+
+impl TryFrom<Date> for Zemen {
+    type Error = error::Error;
+
+    /// Fails if the message isn't a valid date, e.g. a default message,
+    /// whose month and day are 0.
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let component = |name, given: i32, max| {
+            u8::try_from(given).map_err(|_| error::Error::InvalidRange {
+                name,
+                given,
+                min: 1,
+                max,
+            })
+        };
+
+        Zemen::new(
+            date.year,
+            component("month", date.month, 13)?,
+            component("day", date.day, 30)?,
+        )
+    }
+}
+
+impl From<Zemen> for Timestamp {
+    /// Get the timestamp of midnight UTC of the date.
+    fn from(qen: Zemen) -> Self {
+        Timestamp {
+            seconds: (qen.to_jdn() as i64 - UNIX_EPOCH_JDN) * SECONDS_PER_DAY,
+            nanos: 0,
+        }
+    }
+}
+
+impl TryFrom<Timestamp> for Zemen {
+    type Error = error::Error;
+
+    /// Get the UTC day `timestamp` falls on, failing if its `nanos` are out
+    /// of range or the day is beyond the range of `Zemen`.
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        if !(0..1_000_000_000).contains(&timestamp.nanos) {
+            return Err(error::Error::InvalidRange {
+                name: "nanos",
+                given: timestamp.nanos,
+                min: 0,
+                max: 999_999_999,
+            });
+        }
+
+        let jdn = timestamp.seconds.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_JDN;
+        let jdn = i32::try_from(jdn).map_err(|_| {
+            error::Error::InvalidVariant("timestamp", timestamp.seconds.to_string())
+        })?;
+
+        Zemen::from_jdn(jdn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::prost::Message;
+
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_timestamps_are_utc_midnight() -> Result<(), error::Error> {
+        let epoch = Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?;
+        assert_eq!(Timestamp::from(epoch), Timestamp::default());
+
+        let before = Zemen::from_eth_cal(1962, Werh::Tahasass, 22)?;
+        assert_eq!(Timestamp::from(before).seconds, -SECONDS_PER_DAY);
+
+        let late = Timestamp {
+            seconds: SECONDS_PER_DAY - 1,
+            nanos: 999_999_999,
+        };
+        assert_eq!(Zemen::try_from(late)?, epoch);
+
+        let just_before = Timestamp {
+            seconds: -1,
+            nanos: 0,
+        };
+        assert_eq!(Zemen::try_from(just_before)?, before);
+
+        for nanos in [-1, 1_000_000_000] {
+            assert!(Zemen::try_from(Timestamp { seconds: 0, nanos }).is_err());
+        }
+        let far = Timestamp {
+            seconds: i64::MAX,
+            nanos: 0,
+        };
+        assert!(Zemen::try_from(far).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_messages() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+        let date = Date::from(qen);
+        assert_eq!(
+            date,
+            Date {
+                year: 2015,
+                month: 13,
+                day: 6
+            }
+        );
+
+        let bytes = date.encode_to_vec();
+        assert_eq!(
+            Zemen::try_from(Date::decode(bytes.as_slice()).unwrap())?,
+            qen
+        );
+
+        assert!(Zemen::try_from(Date::default()).is_err());
+        assert!(Zemen::try_from(Date { month: 269, ..date }).is_err());
+        assert!(Zemen::try_from(Date { day: -1, ..date }).is_err());
+        assert!(Zemen::try_from(Date { year: 2016, ..date }).is_err());
+
+        Ok(())
+    }
+}
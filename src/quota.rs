@@ -0,0 +1,146 @@
+//! Windows of quota systems whose limits reset on Ethiopian days or months,
+//! e.g. an SMS allowance that resets on the first of every month.
+//!
+//! A monthly window is a whole month of the Ethiopian calendar, so Puagme is
+//! a window of its own, 5 or 6 days long, and the windows don't line up with
+//! Gregorian months.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::{error, quota::Window, Zemen, Werh};
+//! let window = Window::monthly_eth();
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//!
+//! let period = window.period_at(&qen);
+//! assert_eq!(period.start(), &Zemen::from_eth_cal(2015, Werh::Tir, 1)?);
+//! assert_eq!(period.len_days(), 30);
+//! assert_eq!(window.reset_after(&qen), Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use crate::{Period, Zemen};
+
+/// How long a quota lasts before it resets, see the
+/// [module documentation](self).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Window {
+    unit: Unit,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Unit {
+    Day,
+    Month,
+}
+
+impl Window {
+    /// A window of a single day, resetting every midnight.
+    pub fn daily_eth() -> Self {
+        Window { unit: Unit::Day }
+    }
+
+    /// A window of an Ethiopian month, resetting on the first of every month,
+    /// Puagme included.
+    pub fn monthly_eth() -> Self {
+        Window { unit: Unit::Month }
+    }
+
+    /// Get the window `qen` is in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, quota::Window, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 3)?;
+    ///
+    /// let month = Window::monthly_eth().period_at(&qen);
+    /// assert_eq!(month.start(), &Zemen::from_eth_cal(2015, Werh::Puagme, 1)?);
+    /// assert_eq!(month.len_days(), 6);
+    ///
+    /// let day = Window::daily_eth().period_at(&qen);
+    /// assert_eq!((day.start(), day.len_days()), (&qen, 1));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window would end after [`Zemen::MAX`], i.e. `qen` is in
+    /// the last month `Zemen` holds.
+    #[allow(clippy::expect_used)]
+    pub fn period_at(&self, qen: &Zemen) -> Period {
+        let (start, last) = match self.unit {
+            Unit::Day => (*qen, *qen),
+            Unit::Month => (qen.start_of_month(), qen.end_of_month()),
+        };
+
+        Period::from_jdn(start.to_jdn(), last.to_jdn() + 1)
+            .expect("the window ends on a date `Zemen` holds")
+    }
+
+    /// Get the date the window `qen` is in resets, the first day of the next
+    /// window.
+    ///
+    /// # Panics
+    ///
+    /// Panics like [`Window::period_at`].
+    pub fn reset_after(&self, qen: &Zemen) -> Zemen {
+        *self.period_at(qen).end()
+    }
+
+    /// Get the window of today, see [`Zemen::today`].
+    pub fn current(&self) -> Period {
+        self.period_at(&Zemen::today())
+    }
+
+    /// Get the date the window of today resets.
+    pub fn next_reset(&self) -> Zemen {
+        self.reset_after(&Zemen::today())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_windows_cover_every_day_once() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 1)?;
+
+        for window in [Window::daily_eth(), Window::monthly_eth()] {
+            let mut period = window.period_at(&start);
+            assert_eq!(period.start(), &start);
+
+            while period.start() < &Zemen::from_eth_cal(2016, Werh::Tikimit, 1)? {
+                let next = window.period_at(period.end());
+                assert_eq!(next.start(), period.end());
+                assert_eq!(window.reset_after(period.start()), *period.end());
+
+                for qen in period.start().iter_days_until(period.end()) {
+                    assert_eq!(window.period_at(&qen), period);
+                }
+                period = next;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_monthly_windows_follow_ethiopian_months() -> Result<(), error::Error> {
+        let window = Window::monthly_eth();
+        let lengths: Vec<i64> = Werh::iter()
+            .map(|month| {
+                let qen = Zemen::from_eth_cal(2015, month, 1)?;
+                Ok(window.period_at(&qen).len_days())
+            })
+            .collect::<Result<_, error::Error>>()?;
+
+        assert_eq!(lengths, [30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 6]);
+        assert_eq!(window.current(), window.period_at(&Zemen::today()));
+        assert!(window.next_reset() > Zemen::today());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,586 @@
+//! Ranges of dates.
+
+use std::iter::FusedIterator;
+
+use crate::{validator, MonthOverflow, Period, Samint, Zemen};
+
+/// The dates from `start` to `end`, with `end` either included or excluded.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, ZemenRange, Zemen, Werh};
+/// let permit = ZemenRange::new(
+///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+///     Zemen::from_eth_cal(2015, Werh::Tir, 30)?,
+/// );
+///
+/// assert_eq!(permit.len_days(), 30);
+/// assert!(permit.contains(&Zemen::from_eth_cal(2015, Werh::Tir, 30)?));
+/// assert!(!permit.contains(&Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?));
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct ZemenRange {
+    start: Zemen,
+    end: Zemen,
+    inclusive: bool,
+}
+
+impl ZemenRange {
+    /// Create a range from its first and last date.
+    ///
+    /// If `end` is before `start` the range is empty.
+    pub fn new(start: Zemen, end: Zemen) -> Self {
+        ZemenRange {
+            start,
+            end,
+            inclusive: true,
+        }
+    }
+
+    /// Create a range from its first date and the date after its last date.
+    ///
+    /// If `end` isn't after `start` the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::exclusive(
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 30)?,
+    /// );
+    ///
+    /// assert_eq!(range.len_days(), 29);
+    /// assert!(!range.contains(&Zemen::from_eth_cal(2015, Werh::Tir, 30)?));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn exclusive(start: Zemen, end: Zemen) -> Self {
+        ZemenRange {
+            start,
+            end,
+            inclusive: false,
+        }
+    }
+
+    /// Get the first date of the range.
+    pub fn start(&self) -> &Zemen {
+        &self.start
+    }
+
+    /// Get the date the range ends at, which is included in the range only if
+    /// [`ZemenRange::is_inclusive`] is `true`.
+    pub fn end(&self) -> &Zemen {
+        &self.end
+    }
+
+    /// Returns `true` if `end` is part of the range.
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Get the number of dates in the range, an `i64` like
+    /// [`Period::len_days`].
+    pub fn len_days(&self) -> i64 {
+        let (start, last) = self.jdn_bounds();
+        (i64::from(last) - i64::from(start) + 1).max(0)
+    }
+
+    /// Returns `true` if the range has no dates.
+    pub fn is_empty(&self) -> bool {
+        self.len_days() == 0
+    }
+
+    /// Returns `true` if `qen` is one of the dates in the range.
+    pub fn contains(&self, qen: &Zemen) -> bool {
+        let (start, last) = self.jdn_bounds();
+        (start..=last).contains(&qen.to_jdn())
+    }
+
+    /// Get the dates found in both ranges, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let tir = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 30)?,
+    /// );
+    /// let leave = ZemenRange::exclusive(
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 25)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Yekatit, 5)?,
+    /// );
+    ///
+    /// let overlap = tir.intersect(&leave).unwrap();
+    /// assert_eq!(overlap.start(), &Zemen::from_eth_cal(2015, Werh::Tir, 25)?);
+    /// assert_eq!(overlap.end(), &Zemen::from_eth_cal(2015, Werh::Tir, 30)?);
+    ///
+    /// assert_eq!(tir.intersect(&ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Megabit, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Megabit, 5)?,
+    /// )), None);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn intersect(&self, other: &ZemenRange) -> Option<ZemenRange> {
+        let (start, last) = self.jdn_bounds();
+        let (other_start, other_last) = other.jdn_bounds();
+
+        let start = start.max(other_start);
+        let last = last.min(other_last);
+
+        (start <= last).then(|| ZemenRange::new(from_jdn(start), from_jdn(last)))
+    }
+
+    /// Iterate over the dates of the range in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Puagme, 5)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?,
+    /// );
+    ///
+    /// let days: Vec<_> = range.iter().map(|qen| qen.to_string()).collect();
+    /// assert_eq!(days, ["2015-13-05", "2015-13-06", "2016-01-01"]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn iter(&self) -> Days {
+        Days::new(self.start, self.len_days() as usize)
+    }
+
+    /// Iterate over the dates of the range `weeks` weeks apart, starting with
+    /// the first date, e.g. every other week.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weeks` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Nehase, 25)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Meskerem, 30)?,
+    /// );
+    ///
+    /// let paydays: Vec<_> = range.step_by_weeks(2).map(|qen| qen.to_string()).collect();
+    /// assert_eq!(paydays, ["2015-12-25", "2016-01-03", "2016-01-17"]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn step_by_weeks(&self, weeks: u32) -> impl Iterator<Item = Zemen> {
+        assert!(weeks != 0, "step must be non-zero");
+
+        let (start, last) = self.jdn_bounds();
+        (start..=last).step_by(weeks as usize * 7).map(from_jdn)
+    }
+
+    /// Iterate over the dates of the range `months` months apart, starting
+    /// with the first date, e.g. the 21st of every month.
+    ///
+    /// Every date is worked out from the first one, so clamping a date to
+    /// the end of Puagme doesn't change the day of the following ones.
+    /// `overflow` decides what to do with days Puagme doesn't have, and with
+    /// `MonthOverflow::Reject` those are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `months` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, MonthOverflow, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Hamle, 21)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Tikimit, 30)?,
+    /// );
+    ///
+    /// let clamped: Vec<_> = range
+    ///     .step_by_months(1, MonthOverflow::Clamp)
+    ///     .map(|qen| qen.to_string())
+    ///     .collect();
+    /// assert_eq!(
+    ///     clamped,
+    ///     ["2015-11-21", "2015-12-21", "2015-13-06", "2016-01-21", "2016-02-21"]
+    /// );
+    ///
+    /// let skipped = range.step_by_months(1, MonthOverflow::Reject).count();
+    /// assert_eq!(skipped, 4);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn step_by_months(
+        &self,
+        months: u32,
+        overflow: MonthOverflow,
+    ) -> impl Iterator<Item = Zemen> {
+        assert!(months != 0, "step must be non-zero");
+
+        let first = self.start;
+        let (_, last) = self.jdn_bounds();
+        let mut step = 0;
+
+        std::iter::from_fn(move || loop {
+            let offset = i32::try_from(step * months as u64).ok()?;
+            step += 1;
+
+            let clamped = first.add_months(offset, MonthOverflow::Clamp).ok()?;
+            if clamped.to_jdn() > last {
+                return None;
+            }
+
+            match first.add_months(offset, overflow) {
+                Ok(qen) if qen.to_jdn() <= last => return Some(qen),
+                Ok(_) => return None,
+                Err(_) => continue,
+            }
+        })
+    }
+
+    /// Iterate over every date of the range that falls on `samint`, e.g.
+    /// every Saturday for a weekend rota.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 30)?,
+    /// );
+    ///
+    /// let saturdays: Vec<_> = range.weekdays(Samint::Kidame).map(|qen| qen.day()).collect();
+    /// assert_eq!(saturdays, [6, 13, 20, 27]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn weekdays(&self, samint: Samint) -> impl Iterator<Item = Zemen> {
+        let (start, last) = self.jdn_bounds();
+        let first = start + (samint as i32 - self.start.weekday() as i32).rem_euclid(7);
+
+        (first..=last).step_by(7).map(from_jdn)
+    }
+
+    /// Iterate over every `days` long period in the range, starting one day
+    /// apart, e.g. rolling seven day windows.
+    ///
+    /// Like `slice::windows`, nothing is yielded if the range is shorter than
+    /// `days`.
+    ///
+    /// The last window of a range ending on [`Zemen::MAX`] would end on a day
+    /// `Zemen` can't hold, so it is left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+    /// );
+    ///
+    /// let weeks: Vec<_> = range.windows(7).collect();
+    /// assert_eq!(weeks.len(), 4);
+    /// assert_eq!(weeks[3].start(), &Zemen::from_eth_cal(2015, Werh::Tir, 4)?);
+    /// assert_eq!(weeks[3].end(), &Zemen::from_eth_cal(2015, Werh::Tir, 11)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn windows(&self, days: u32) -> impl Iterator<Item = Period> {
+        assert!(days != 0, "window size must be non-zero");
+
+        let (start, last) = self.jdn_bounds();
+
+        // no range is more than `i32::MAX` days long
+        i32::try_from(days).into_iter().flat_map(move |days| {
+            (start..=last.saturating_sub(days - 1))
+                .map_while(move |jdn| Period::from_jdn(jdn, jdn + days).ok())
+        })
+    }
+
+    /// Iterate over consecutive, non overlapping, `days` long periods of the
+    /// range, e.g. the decades of a month.
+    ///
+    /// Like `slice::chunks`, the last period is shorter if the range doesn't
+    /// divide evenly.
+    ///
+    /// The last chunk of a range ending on [`Zemen::MAX`] would end on a day
+    /// `Zemen` can't hold, so it is left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, ZemenRange, Zemen, Werh};
+    /// let range = ZemenRange::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Nehase, 1)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+    /// );
+    ///
+    /// let lengths: Vec<_> = range.chunks(10).map(|period| period.len_days()).collect();
+    /// assert_eq!(lengths, [10, 10, 10, 6]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn chunks(&self, days: u32) -> impl Iterator<Item = Period> {
+        assert!(days != 0, "chunk size must be non-zero");
+
+        let (start, last) = self.jdn_bounds();
+        let days = i32::try_from(days).unwrap_or(i32::MAX);
+
+        (start..=last).step_by(days as usize).map_while(move |jdn| {
+            Period::from_jdn(jdn, jdn.saturating_add(days).min(last + 1)).ok()
+        })
+    }
+
+    /// Get the Julian day numbers of the first and the last date.
+    fn jdn_bounds(&self) -> (i32, i32) {
+        let end = self.end.to_jdn();
+        (
+            self.start.to_jdn(),
+            if self.inclusive { end } else { end - 1 },
+        )
+    }
+}
+
+/// An iterator over consecutive dates, see [`ZemenRange::iter`] and
+/// [`Zemen::iter_days_until`].
+///
+/// Dates are advanced through their year and day of the year, without going
+/// through Julian day numbers or month and day components.
+#[derive(Debug, Clone)]
+pub struct Days {
+    front: (i32, u16),
+    back: (i32, u16),
+    remaining: usize,
+}
+
+impl Days {
+    #[allow(clippy::expect_used)]
+    pub(crate) fn new(start: Zemen, len: usize) -> Self {
+        let front = start.ordinal_date();
+        let back = match len.checked_sub(1) {
+            None => front,
+            // the days between far apart dates overflow an `i32`
+            Some(last) => i64::try_from(last)
+                .ok()
+                .and_then(|last| i32::try_from(i64::from(start.to_jdn()) + last).ok())
+                .and_then(|jdn| Zemen::from_jdn(jdn).ok())
+                .expect("the days end within `Zemen`")
+                .ordinal_date(),
+        };
+
+        Days {
+            front,
+            back,
+            remaining: len,
+        }
+    }
+}
+
+#[allow(clippy::expect_used)]
+fn to_zemen((year, ordinal): (i32, u16)) -> Zemen {
+    Zemen::from_ordinal_date(year, ordinal).expect("iterated dates are valid")
+}
+
+impl Iterator for Days {
+    type Item = Zemen;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let current = self.front;
+        let (year, ordinal) = current;
+        self.front = if ordinal < validator::days_in_year(year) {
+            (year, ordinal + 1)
+        } else {
+            (year + 1, 1)
+        };
+
+        Some(to_zemen(current))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Days {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let current = self.back;
+        let (year, ordinal) = current;
+        self.back = if ordinal > 1 {
+            (year, ordinal - 1)
+        } else {
+            (year - 1, validator::days_in_year(year - 1))
+        };
+
+        Some(to_zemen(current))
+    }
+}
+
+impl ExactSizeIterator for Days {}
+
+impl FusedIterator for Days {}
+
+#[allow(clippy::expect_used)]
+fn from_jdn(jdn: i32) -> Zemen {
+    Zemen::from_jdn(jdn).expect("dates between two valid dates are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_days_match_stepping_by_jdn() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2014, Werh::Nehase, 20)?;
+        let end = start + 800;
+
+        let range = ZemenRange::exclusive(start, end);
+        assert_eq!(range.iter().len(), 800);
+
+        for (i, qen) in range.iter().enumerate() {
+            assert_eq!(qen, start + i as i32);
+        }
+
+        let forward: Vec<_> = range.iter().collect();
+        let mut backward: Vec<_> = range.iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_by_months_keeps_the_day() -> Result<(), error::Error> {
+        let range = ZemenRange::new(
+            Zemen::from_eth_cal(2015, Werh::Nehase, 30)?,
+            Zemen::from_eth_cal(2016, Werh::Tikimit, 30)?,
+        );
+
+        let rolled: Vec<_> = range
+            .step_by_months(1, MonthOverflow::Rollover)
+            .map(|qen| qen.to_string())
+            .collect();
+        assert_eq!(
+            rolled,
+            ["2015-12-30", "2016-01-24", "2016-01-30", "2016-02-30"]
+        );
+
+        let every_other: Vec<_> = range
+            .step_by_months(2, MonthOverflow::Clamp)
+            .map(|qen| qen.to_string())
+            .collect();
+        assert_eq!(every_other, ["2015-12-30", "2016-01-30"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weekdays_match_filtering_the_days() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 27)?;
+
+        for len in 0..15 {
+            let range = ZemenRange::exclusive(start, start + len);
+
+            for samint in Samint::iter() {
+                let expected: Vec<_> = range.iter().filter(|qen| qen.weekday() == samint).collect();
+
+                assert_eq!(range.weekdays(samint).collect::<Vec<_>>(), expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_windows_and_chunks_of_short_ranges() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+
+        let single = ZemenRange::new(qen, qen);
+        assert_eq!(single.windows(1).count(), 1);
+        assert_eq!(single.windows(2).count(), 0);
+        assert_eq!(
+            single.chunks(7).map(|p| p.len_days()).collect::<Vec<_>>(),
+            [1]
+        );
+
+        let empty = ZemenRange::new(qen + 1, qen);
+        assert_eq!(empty.windows(1).count(), 0);
+        assert_eq!(empty.chunks(1).count(), 0);
+
+        let empty = ZemenRange::exclusive(qen, qen);
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.intersect(&single), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_windows_and_chunks_longer_than_the_range() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+        let range = ZemenRange::new(start, start + 10);
+
+        assert_eq!(range.windows(11).count(), 1);
+        assert_eq!(range.windows(12).count(), 0);
+        assert_eq!(range.windows(u32::MAX).count(), 0);
+        assert_eq!(range.windows(1 << 31).count(), 0);
+
+        for days in [11, 12, u32::MAX] {
+            let chunks: Vec<_> = range.chunks(days).collect();
+            assert_eq!(chunks.len(), 1);
+            assert_eq!((chunks[0].start(), chunks[0].len_days()), (&start, 11));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_windows_and_chunks_at_the_end_of_zemen() {
+        // the day after `Zemen::MAX` can't end a period
+        let range = ZemenRange::new(Zemen::MAX + -10, Zemen::MAX);
+        assert_eq!(range.windows(3).count(), 8);
+        let lengths: Vec<_> = range.chunks(5).map(|period| period.len_days()).collect();
+        assert_eq!(lengths, [5, 5]);
+
+        let range = ZemenRange::exclusive(Zemen::MAX + -10, Zemen::MAX);
+        assert_eq!(range.windows(3).count(), 8);
+        assert_eq!(range.windows(3).last().map(|p| *p.end()), Some(Zemen::MAX));
+        let lengths: Vec<_> = range.chunks(5).map(|period| period.len_days()).collect();
+        assert_eq!(lengths, [5, 5]);
+    }
+
+    #[test]
+    fn test_len_of_all_of_zemen() {
+        let days = i64::from(Zemen::MAX.to_jdn()) - i64::from(Zemen::MIN.to_jdn());
+
+        assert_eq!(ZemenRange::new(Zemen::MIN, Zemen::MAX).len_days(), days + 1);
+        assert_eq!(
+            ZemenRange::exclusive(Zemen::MIN, Zemen::MAX).len_days(),
+            days
+        );
+        assert_eq!(
+            ZemenRange::new(Zemen::MIN, Zemen::MAX).iter().len() as i64,
+            days + 1
+        );
+    }
+}
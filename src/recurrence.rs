@@ -0,0 +1,607 @@
+//! Recurring dates, in the spirit of iCalendar's RRULE.
+//!
+//! A [`Rule`] is built from a [`Frequency`] and narrowed down with the
+//! builder methods, then [`Rule::occurrences`] lists the matching dates from
+//! a start date onwards:
+//!
+//! ```txt
+//!
+//! every month on day 21        Rule::monthly().on_day(21)
+//! every other Kidame           Rule::weekly().interval(2).on(Samint::Kidame)
+//! the 2nd Kidame of the month  Rule::monthly().on_nth(2, Samint::Kidame)
+//! yearly on Meskerem 17        Rule::yearly().in_month(Werh::Meskerem).on_day(17)
+//! ```
+//!
+//! Months are the 13 months of the Ethiopian year, so a monthly rule also
+//! visits Puagme, and dates Puagme doesn't have are skipped.
+//!
+//! Rules can also be parsed from a short cron-like schedule, e.g. from a
+//! config file, see [`Rule::from_str`](#impl-FromStr-for-Rule).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::{error, recurrence::Rule, Zemen, Werh};
+//! let start = Zemen::from_eth_cal(2015, Werh::Hamle, 1)?;
+//! let rule = Rule::monthly().on_day(21).count(3);
+//!
+//! let dates: Vec<_> = rule.occurrences(&start).map(|qen| qen.to_string()).collect();
+//! assert_eq!(dates, ["2015-11-21", "2015-12-21", "2016-01-21"]);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use crate::{conversion, error, validator, Samint, Werh, Zemen};
+
+/// Occurrences stop when none is found within this many days, so rules that
+/// can never match, e.g. Puagme 6 every 4 years from a common year, end.
+const SEARCH_LIMIT: i32 = 100 * 366;
+
+/// How often a [`Rule`] repeats.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A description of recurring dates.
+///
+/// Fields left unset are taken from the start date given to
+/// [`Rule::occurrences`]: a weekly rule repeats on the start's weekday, a
+/// monthly rule on the start's day, and a yearly rule on the start's month
+/// and day.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rule {
+    frequency: Frequency,
+    interval: u32,
+    month: Option<Werh>,
+    day: Option<u8>,
+    weekday: Option<Samint>,
+    nth: Option<i8>,
+    count: Option<usize>,
+    until: Option<Zemen>,
+}
+
+impl Rule {
+    /// Create a rule repeating every day, week, month, or year.
+    pub fn new(frequency: Frequency) -> Self {
+        Rule {
+            frequency,
+            interval: 1,
+            month: None,
+            day: None,
+            weekday: None,
+            nth: None,
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Create a rule repeating every day.
+    pub fn daily() -> Self {
+        Self::new(Frequency::Daily)
+    }
+
+    /// Create a rule repeating every week.
+    pub fn weekly() -> Self {
+        Self::new(Frequency::Weekly)
+    }
+
+    /// Create a rule repeating every month.
+    pub fn monthly() -> Self {
+        Self::new(Frequency::Monthly)
+    }
+
+    /// Create a rule repeating every year.
+    pub fn yearly() -> Self {
+        Self::new(Frequency::Yearly)
+    }
+
+    /// Repeat every `interval` days, weeks, months, or years instead of
+    /// every one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    pub fn interval(mut self, interval: u32) -> Self {
+        assert!(interval != 0, "interval must be non-zero");
+        self.interval = interval;
+        self
+    }
+
+    /// Only match dates in `month`.
+    pub fn in_month(mut self, month: Werh) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Only match the `day`th day of the month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `day` isn't between 1 and 30.
+    pub fn on_day(mut self, day: u8) -> Self {
+        assert!((1..=30).contains(&day), "day must be between 1 and 30");
+        self.day = Some(day);
+        self
+    }
+
+    /// Only match dates falling on `weekday`.
+    pub fn on(mut self, weekday: Samint) -> Self {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    /// Only match the `nth` `weekday` of the month. A negative `nth` counts
+    /// from the end, so `-1` is the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, recurrence::Rule, Samint, Zemen, Werh};
+    /// let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    ///
+    /// let second = Rule::monthly().on_nth(2, Samint::Kidame).count(2);
+    /// let dates: Vec<_> = second.occurrences(&start).map(|qen| qen.to_string()).collect();
+    /// assert_eq!(dates, ["2015-05-13", "2015-06-11"]);
+    ///
+    /// let last = Rule::monthly().on_nth(-1, Samint::Kidame).count(2);
+    /// let dates: Vec<_> = last.occurrences(&start).map(|qen| qen.to_string()).collect();
+    /// assert_eq!(dates, ["2015-05-27", "2015-06-25"]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nth` is 0.
+    pub fn on_nth(mut self, nth: i8, weekday: Samint) -> Self {
+        assert!(nth != 0, "nth must be non-zero");
+        self.nth = Some(nth);
+        self.weekday = Some(weekday);
+        self
+    }
+
+    /// Stop after `count` occurrences.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Stop after `until`, which is included if it matches.
+    pub fn until(mut self, until: Zemen) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Iterate over the matching dates, from `start` onwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, recurrence::Rule, Samint, Zemen, Werh};
+    /// let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// let rule = Rule::weekly()
+    ///     .interval(2)
+    ///     .on(Samint::Kidame)
+    ///     .until(Zemen::from_eth_cal(2015, Werh::Yekatit, 10)?);
+    ///
+    /// let dates: Vec<_> = rule.occurrences(&start).map(|qen| qen.to_string()).collect();
+    /// assert_eq!(dates, ["2015-05-06", "2015-05-20", "2015-06-04"]);
+    ///
+    /// let meskel = Rule::yearly().in_month(Werh::Meskerem).on_day(17);
+    /// let next = meskel.occurrences(&start).next();
+    /// assert_eq!(next, Some(Zemen::from_eth_cal(2016, Werh::Meskerem, 17)?));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn occurrences(&self, start: &Zemen) -> Occurrences {
+        let mut rule = self.clone();
+
+        match rule.frequency {
+            Frequency::Daily => {}
+            Frequency::Weekly => {
+                rule.weekday.get_or_insert(start.weekday());
+            }
+            Frequency::Monthly => {
+                if rule.weekday.is_none() {
+                    rule.day.get_or_insert(start.day());
+                }
+            }
+            Frequency::Yearly => {
+                rule.month.get_or_insert(start.month());
+                if rule.weekday.is_none() {
+                    rule.day.get_or_insert(start.day());
+                }
+            }
+        }
+
+        let start = start.to_jdn();
+        Occurrences {
+            rule,
+            start,
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            last_hit: start,
+            done: false,
+        }
+    }
+
+    /// Get the first and one past the last Julian day number of the
+    /// `period`th period after the one `start` falls in.
+    fn period(&self, start: i32, period: u64) -> (i32, i32) {
+        let step = period as i64 * self.interval as i64;
+        let (year, month, _) = conversion::jdn_to_eth(start);
+
+        match self.frequency {
+            Frequency::Daily => {
+                let day = (start as i64 + step) as i32;
+                (day, day + 1)
+            }
+            Frequency::Weekly => {
+                let weekday = (start + 1) % 7;
+                let first = (start as i64 - weekday as i64 + 7 * step) as i32;
+                (first, first + 7)
+            }
+            Frequency::Monthly => {
+                let index = year as i64 * 13 + month as i64 - 1 + step;
+                let year = index.div_euclid(13) as i32;
+                let month = index.rem_euclid(13) as u8 + 1;
+
+                let first = conversion::eth_to_jdn(year, month as i32, 1);
+                (first, first + validator::days_in_month(year, month) as i32)
+            }
+            Frequency::Yearly => {
+                let year = (year as i64 + step) as i32;
+
+                let first = conversion::eth_to_jdn(year, 1, 1);
+                (first, first + validator::days_in_year(year) as i32)
+            }
+        }
+    }
+
+    /// Get the matching days, as Julian day numbers, between `first` and
+    /// `end`.
+    fn matches(&self, first: i32, end: i32) -> Vec<i32> {
+        let mut days: Vec<i32> = (first..end)
+            .filter(|jdn| {
+                let (_, month, day) = conversion::jdn_to_eth(*jdn);
+
+                self.month.map_or(true, |m| m as u8 == month)
+                    && self.day.map_or(true, |d| d == day)
+                    && self.weekday.map_or(true, |w| w as i32 == (jdn + 1) % 7)
+            })
+            .collect();
+
+        if let Some(nth) = self.nth {
+            let index = if nth > 0 {
+                nth as usize - 1
+            } else {
+                match days.len().checked_sub(nth.unsigned_abs() as usize) {
+                    Some(index) => index,
+                    None => return Vec::new(),
+                }
+            };
+
+            days = days.get(index).copied().into_iter().collect();
+        }
+
+        days
+    }
+}
+
+impl FromStr for Rule {
+    type Err = error::Error;
+
+    /// Parse a cron-like schedule over the Ethiopian date fields.
+    ///
+    /// A schedule is a list of `field=value` pairs separated by spaces, where
+    /// `*` matches anything and is the same as leaving the field out:
+    ///
+    /// ```txt
+    /// day=1..30          the day of the month
+    /// werh=NAME|1..13    the month, by any name `Werh` parses
+    /// samint=NAME|0..6   the weekday, by any name `Samint` parses
+    /// nth=N              the nth `samint` of the month, negative from the end
+    /// every=N            every N days, weeks, months, or years
+    /// ```
+    ///
+    /// The rule repeats yearly when `werh` is given, otherwise monthly when
+    /// `day` or `nth` is, otherwise weekly when `samint` is, and otherwise
+    /// daily. There are shorthands too:
+    ///
+    /// ```txt
+    /// @daily                       every day
+    /// @weekly                      samint=ihud
+    /// @monthly_eth                 day=1
+    /// @yearly_eth, @enkutatash     werh=meskerem day=1
+    /// ```
+    ///
+    /// Schedules only cover dates, the time of day is up to the scheduler.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, recurrence::Rule, Samint, Zemen, Werh};
+    /// let rule: Rule = "day=1 werh=*".parse()?;
+    /// assert_eq!(rule, Rule::monthly().on_day(1));
+    /// assert_eq!("@monthly_eth".parse::<Rule>()?, rule);
+    ///
+    /// let rule: Rule = "samint=kidame nth=-1".parse()?;
+    /// assert_eq!(rule, Rule::monthly().on_nth(-1, Samint::Kidame));
+    ///
+    /// let rule: Rule = "@enkutatash".parse()?;
+    /// let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// assert_eq!(rule.occurrences(&start).next(), Some(Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?));
+    ///
+    /// assert!("day=31".parse::<Rule>().is_err());
+    /// assert!("0 0 * * *".parse::<Rule>().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |token: &str| error::Error::InvalidVariant("schedule", token.to_string());
+
+        let fields = match s.trim() {
+            "@daily" => "",
+            "@weekly" => "samint=ihud",
+            "@monthly_eth" => "day=1",
+            "@yearly_eth" | "@enkutatash" => "werh=meskerem day=1",
+            shorthand if shorthand.starts_with('@') => return Err(invalid(shorthand)),
+            fields => fields,
+        };
+
+        let (mut day, mut month, mut weekday, mut nth, mut every) = (None, None, None, None, None);
+        for token in fields.split_whitespace() {
+            let (key, value) = token.split_once('=').ok_or_else(|| invalid(token))?;
+            let number = || value.parse::<i32>().map_err(|_| invalid(token));
+
+            let seen = match key {
+                "day" | "werh" | "samint" if value == "*" => false,
+                "day" => {
+                    error::is_in_range(number()?, 1, 30, "day")?;
+                    day.replace(number()? as u8).is_some()
+                }
+                "werh" => {
+                    let werh = match value.parse::<u8>() {
+                        Ok(value) => Werh::try_from(value)?,
+                        Err(_) => value.parse()?,
+                    };
+                    month.replace(werh).is_some()
+                }
+                "samint" => {
+                    let samint = match value.parse::<u8>() {
+                        Ok(value) => Samint::try_from(value)?,
+                        Err(_) => value.parse()?,
+                    };
+                    weekday.replace(samint).is_some()
+                }
+                "nth" => {
+                    let value = number()?;
+                    if value == 0 {
+                        return Err(invalid(token));
+                    }
+                    error::is_in_range(value, -5, 5, "nth")?;
+                    nth.replace(value as i8).is_some()
+                }
+                "every" => {
+                    let value = value.parse::<u32>().ok().filter(|value| *value != 0);
+                    every
+                        .replace(value.ok_or_else(|| invalid(token))?)
+                        .is_some()
+                }
+                _ => return Err(invalid(token)),
+            };
+            if seen {
+                return Err(invalid(token));
+            }
+        }
+
+        let frequency = if month.is_some() {
+            Frequency::Yearly
+        } else if day.is_some() || nth.is_some() {
+            Frequency::Monthly
+        } else if weekday.is_some() {
+            Frequency::Weekly
+        } else {
+            Frequency::Daily
+        };
+
+        let mut rule = Rule::new(frequency).interval(every.unwrap_or(1));
+        if let Some(month) = month {
+            rule = rule.in_month(month);
+        }
+        if let Some(day) = day {
+            rule = rule.on_day(day);
+        }
+        match (nth, weekday) {
+            (Some(nth), Some(weekday)) => rule = rule.on_nth(nth, weekday),
+            (Some(_), None) => return Err(invalid(s)),
+            (None, Some(weekday)) => rule = rule.on(weekday),
+            (None, None) => {}
+        }
+
+        Ok(rule)
+    }
+}
+
+/// Iterator over the dates matching a [`Rule`], see [`Rule::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    rule: Rule,
+    start: i32,
+    period: u64,
+    pending: VecDeque<i32>,
+    emitted: usize,
+    last_hit: i32,
+    done: bool,
+}
+
+impl Iterator for Occurrences {
+    type Item = Zemen;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done || self.rule.count.is_some_and(|count| self.emitted >= count) {
+                return None;
+            }
+
+            if let Some(jdn) = self.pending.pop_front() {
+                if self
+                    .rule
+                    .until
+                    .as_ref()
+                    .is_some_and(|until| jdn > until.to_jdn())
+                {
+                    self.done = true;
+                    return None;
+                }
+
+                self.emitted += 1;
+                self.last_hit = jdn;
+                return Zemen::from_jdn(jdn).ok();
+            }
+
+            let (first, end) = self.rule.period(self.start, self.period);
+            self.period += 1;
+
+            let past_until = self
+                .rule
+                .until
+                .as_ref()
+                .is_some_and(|until| first > until.to_jdn());
+            if past_until || first - self.last_hit > SEARCH_LIMIT {
+                self.done = true;
+                return None;
+            }
+
+            let start = self.start;
+            self.pending.extend(
+                self.rule
+                    .matches(first, end)
+                    .into_iter()
+                    .filter(|jdn| *jdn >= start),
+            );
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Occurrences {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates(rule: &Rule, start: &Zemen) -> Vec<String> {
+        rule.occurrences(start).map(|qen| qen.to_string()).collect()
+    }
+
+    #[test]
+    fn test_monthly_skips_days_puagme_lacks() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 10)?;
+
+        assert_eq!(
+            dates(&Rule::monthly().count(3), &start),
+            ["2015-12-10", "2016-01-10", "2016-02-10"]
+        );
+        assert_eq!(
+            dates(&Rule::monthly().on_day(6).count(3), &start),
+            ["2015-13-06", "2016-01-06", "2016-02-06"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_yearly_on_leap_day() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2012, Werh::Meskerem, 1)?;
+        let rule = Rule::yearly().in_month(Werh::Puagme).on_day(6).count(2);
+
+        assert_eq!(dates(&rule, &start), ["2015-13-06", "2019-13-06"]);
+
+        let never = Rule::yearly().interval(4).in_month(Werh::Puagme).on_day(6);
+        assert_eq!(never.occurrences(&start).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_schedules() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 10)?;
+
+        for (schedule, rule) in [
+            ("", Rule::daily()),
+            ("@daily", Rule::daily()),
+            ("every=2", Rule::daily().interval(2)),
+            ("@weekly", Rule::weekly().on(Samint::Ihud)),
+            (
+                "samint=5 every=2",
+                Rule::weekly().on(Samint::Arb).interval(2),
+            ),
+            ("  day=21  ", Rule::monthly().on_day(21)),
+            (
+                "samint=ቅዳሜ nth=2",
+                Rule::monthly().on_nth(2, Samint::Kidame),
+            ),
+            (
+                "werh=13 day=6",
+                Rule::yearly().in_month(Werh::Puagme).on_day(6),
+            ),
+            (
+                "@yearly_eth",
+                Rule::yearly().in_month(Werh::Meskerem).on_day(1),
+            ),
+            (
+                "werh=Meskerem day=17 samint=*",
+                Rule::yearly().in_month(Werh::Meskerem).on_day(17),
+            ),
+        ] {
+            assert_eq!(schedule.parse::<Rule>()?, rule, "{schedule}");
+        }
+
+        let rule: Rule = "@monthly_eth".parse()?;
+        assert_eq!(
+            dates(&rule.count(3), &start),
+            ["2015-13-01", "2016-01-01", "2016-02-01"]
+        );
+
+        for schedule in [
+            "@hourly",
+            "0 day=1",
+            "day=0",
+            "day=31",
+            "day=1 day=2",
+            "werh=14",
+            "werh=tirr",
+            "samint=7",
+            "nth=2",
+            "samint=ihud nth=0",
+            "samint=ihud nth=6",
+            "every=0",
+            "every=-1",
+            "day",
+            "Day=1",
+        ] {
+            assert!(schedule.parse::<Rule>().is_err(), "{schedule}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_until_is_inclusive() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+        let rule = Rule::daily()
+            .interval(3)
+            .until(Zemen::from_eth_cal(2015, Werh::Tir, 7)?);
+
+        assert_eq!(
+            dates(&rule, &start),
+            ["2015-05-01", "2015-05-04", "2015-05-07"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! Storing `Zemen` in Redis with [redis](https://docs.rs/redis).
+//!
+//! `ToRedisArgs` and `FromRedisValue` are implemented for `Zemen`, as its
+//! `YYYY-MM-DD` string, and for [`Jdn`], as an integer, which lets sorted
+//! sets score members by their date.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use redis::{FromRedisValue, ToRedisArgs, Value};
+//! # use zemen::{sql::Jdn, Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10).unwrap();
+//! assert_eq!(qen.to_redis_args(), [b"2015-05-10".to_vec()]);
+//! assert_eq!(Jdn(qen).to_redis_args(), [b"2459963".to_vec()]);
+//!
+//! let read = Jdn::from_redis_value(&Value::Int(2_459_963)).unwrap();
+//! assert_eq!(read, Jdn(qen));
+//! ```
+
+use ::redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::sql::Jdn;
+use crate::Zemen;
+
+fn invalid(value: &Value, err: crate::error::Error) -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "Response was not a date",
+        format!("{err} (response was {value:?})"),
+    ))
+}
+
+impl ToRedisArgs for Zemen {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        out.write_arg(self.to_string().as_bytes())
+    }
+}
+
+impl FromRedisValue for Zemen {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        String::from_redis_value(value)?
+            .parse()
+            .map_err(|err| invalid(value, err))
+    }
+}
+
+impl ToRedisArgs for Jdn {
+    fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+        self.0.to_jdn().write_redis_args(out)
+    }
+}
+
+impl FromRedisValue for Jdn {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        // redis casts integers to `i32` without checking their range
+        let jdn = i64::from_redis_value(value)?;
+        let jdn = i32::try_from(jdn).map_err(|_| {
+            invalid(
+                value,
+                crate::error::Error::InvalidVariant("JDN", jdn.to_string()),
+            )
+        })?;
+
+        Zemen::from_jdn(jdn)
+            .map(Jdn)
+            .map_err(|err| invalid(value, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_values_round_trip() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+        assert_eq!(qen.to_redis_args(), [b"2015-05-10".to_vec()]);
+        assert_eq!(Jdn(qen).to_redis_args(), [b"2459963".to_vec()]);
+
+        let text = Value::BulkString(b"2015-05-10".to_vec());
+        assert_eq!(Zemen::from_redis_value(&text).unwrap(), qen);
+        assert_eq!(
+            Jdn::from_redis_value(&Value::Int(2_459_963)).unwrap(),
+            Jdn(qen)
+        );
+        assert_eq!(
+            Jdn::from_redis_value(&Value::BulkString(b"2459963".to_vec())).unwrap(),
+            Jdn(qen)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        for value in [
+            Value::Nil,
+            Value::Int(2_459_963),
+            Value::BulkString(b"2015-13-07".to_vec()),
+        ] {
+            assert!(Zemen::from_redis_value(&value).is_err(), "{value:?}");
+        }
+        for value in [
+            Value::Nil,
+            Value::BulkString(b"2015-05-10".to_vec()),
+            Value::Int(i64::MAX),
+            Value::Int(i32::MIN.into()),
+        ] {
+            assert!(Jdn::from_redis_value(&value).is_err(), "{value:?}");
+        }
+    }
+}
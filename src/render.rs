@@ -0,0 +1,197 @@
+//! Printable calendar pages.
+//!
+//! Enable the `svg` feature to render a month as a standalone SVG image, e.g.
+//! to serve calendar pages from a web service without a front-end rendering
+//! step.
+
+use std::fmt::Write;
+
+use crate::{Locale, Samint, Werh, Zemen};
+
+/// How [`month_svg`] draws a month.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MonthSvgOptions {
+    /// Width and height of a day, in pixels.
+    pub cell_size: u32,
+    /// Days of the month to highlight, e.g. holidays.
+    pub highlights: Vec<u8>,
+    /// Fill color of the highlighted days.
+    pub highlight_fill: String,
+    /// Font family of every label.
+    pub font_family: String,
+    /// Language of the `aria-label`s read out by screen readers.
+    pub locale: Locale,
+}
+
+impl Default for MonthSvgOptions {
+    fn default() -> Self {
+        MonthSvgOptions {
+            cell_size: 48,
+            highlights: Vec::new(),
+            highlight_fill: "#f4c542".to_string(),
+            font_family: "Noto Sans Ethiopic, sans-serif".to_string(),
+            locale: Locale::Amharic,
+        }
+    }
+}
+
+/// Render `werh` of the year `year` as an SVG month grid.
+///
+/// The grid has the month's name and year on top, followed by a row of
+/// weekday names starting on እሑድ, and one row per week. Days listed in
+/// `options.highlights` are filled with `options.highlight_fill`; days the
+/// month doesn't have are ignored.
+///
+/// Every day carries an `aria-label` with its
+/// [`Zemen::accessible_label`] in `options.locale`, so screen readers read
+/// out full dates rather than bare numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{render::{self, MonthSvgOptions}, Werh};
+/// let options = MonthSvgOptions {
+///     highlights: vec![11],
+///     ..Default::default()
+/// };
+/// let svg = render::month_svg(2015, Werh::Tir, &options);
+///
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains(">ጥር 2015</text>"));
+/// assert!(svg.contains(">እሑ</text>"));
+/// assert!(svg.contains(r#"aria-label="ሐሙስ ጥር 11 ቀን 2015 ዓመተ ምሕረት""#));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `year` is beyond the years of [`Zemen::MIN`] and [`Zemen::MAX`].
+#[allow(clippy::expect_used)]
+pub fn month_svg(year: i32, werh: Werh, options: &MonthSvgOptions) -> String {
+    let cell = options.cell_size;
+    let days = werh.num_days(year) as u32;
+    let first_day =
+        Zemen::from_eth_cal(year, werh, 1).expect("`year` is beyond the years of `Zemen`");
+    let first = first_day.weekday() as u32;
+    let weeks = (first + days).div_ceil(7);
+
+    let width = cell * 7;
+    let height = cell * (weeks + 2);
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="{}" text-anchor="middle" dominant-baseline="middle" role="img" aria-label="{} {year}">"#,
+        escape(&options.font_family),
+        escape(&options.locale.month_name(werh))
+    );
+    let _ = write!(
+        svg,
+        r#"<rect width="{width}" height="{height}" fill="white"/>"#
+    );
+    let _ = write!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="{}" font-weight="bold">{werh} {year}</text>"#,
+        width / 2,
+        cell / 2,
+        cell / 2
+    );
+
+    for samint in Samint::iter() {
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}">{}</text>"#,
+            samint as u32 * cell + cell / 2,
+            cell + cell / 2,
+            cell / 3,
+            samint.to_string().chars().take(2).collect::<String>()
+        );
+    }
+
+    for day in 1..=days {
+        let slot = first + day - 1;
+        let x = (slot % 7) * cell;
+        let y = (slot / 7 + 2) * cell;
+        let fill = if options.highlights.contains(&(day as u8)) {
+            escape(&options.highlight_fill)
+        } else {
+            "none".to_string()
+        };
+
+        let label = escape(&(first_day + (day as i32 - 1)).accessible_label(options.locale));
+
+        let _ = write!(
+            svg,
+            r##"<rect x="{x}" y="{y}" width="{cell}" height="{cell}" fill="{fill}" stroke="#cccccc" aria-label="{label}"/>"##
+        );
+        let _ = write!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}">{day}</text>"#,
+            x + cell / 2,
+            y + cell / 2,
+            cell / 3
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Escape `text` to be put in an attribute, or between tags.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_svg_draws_every_day() {
+        let options = MonthSvgOptions {
+            highlights: vec![1, 6, 7],
+            ..Default::default()
+        };
+
+        for (year, werh, days, highlighted) in
+            [(2015, Werh::Tir, 30, 3), (2015, Werh::Puagme, 6, 2)]
+        {
+            let svg = month_svg(year, werh, &options);
+
+            assert_eq!(svg.matches(r##"stroke="#cccccc""##).count(), days);
+            assert_eq!(svg.matches(r##"fill="#f4c542""##).count(), highlighted);
+            assert!(svg.ends_with("</svg>"));
+        }
+    }
+
+    #[test]
+    fn test_month_svg_escapes_options() {
+        let options = MonthSvgOptions {
+            highlights: vec![1],
+            highlight_fill: r#"red"/><script>alert(1)</script><rect fill="x"#.to_string(),
+            font_family: r#"serif" onload="alert('&')"#.to_string(),
+            ..Default::default()
+        };
+        let svg = month_svg(2015, Werh::Tir, &options);
+
+        assert!(!svg.contains("<script>"));
+        assert!(!svg.contains(r#"onload=""#));
+        assert!(svg.contains(r#"font-family="serif&quot; onload=&quot;alert(&apos;&amp;&apos;)""#));
+        assert!(svg.contains(
+            r#"fill="red&quot;/&gt;&lt;script&gt;alert(1)&lt;/script&gt;&lt;rect fill=&quot;x""#
+        ));
+        assert_eq!(svg.matches("<rect").count(), 31);
+    }
+}
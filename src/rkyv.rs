@@ -0,0 +1,169 @@
+//! Zero-copy serialization of `Zemen` with [rkyv](https://docs.rs/rkyv).
+//!
+//! A `Zemen` is archived as [`ArchivedZemen`], the packed `i32` of
+//! [`Zemen::to_bits`] in little-endian, so an archived `Vec<Zemen>` is an
+//! array of 4 byte dates that can be memory-mapped and read in place.
+//! Archived dates compare in the same order as the dates themselves, without
+//! being deserialized.
+//!
+//! Accessing an archive with validation checks that every date is one
+//! [`Zemen::from_bits`] accepts.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use rkyv::{rancor, vec::ArchivedVec};
+//! # use zemen::{error, rkyv::ArchivedZemen, Werh, Zemen};
+//! let dates = vec![
+//!     Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+//!     Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?,
+//! ];
+//! let bytes = rkyv::to_bytes::<rancor::Error>(&dates).unwrap();
+//! assert_eq!(bytes.len(), 16);
+//!
+//! let archived = rkyv::access::<ArchivedVec<ArchivedZemen>, rancor::Error>(&bytes).unwrap();
+//! assert!(archived[0] < archived[1]);
+//! assert_eq!(archived[1].to_zemen()?, dates[1]);
+//!
+//! let read = rkyv::deserialize::<Vec<Zemen>, rancor::Error>(archived).unwrap();
+//! assert_eq!(read, dates);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use ::rkyv::bytecheck::CheckBytes;
+use ::rkyv::rancor::{Fallible, Source};
+use ::rkyv::traits::NoUndef;
+use ::rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use crate::{error, Zemen};
+
+/// The archived form of a [`Zemen`], its packed bits in little-endian.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(transparent)]
+pub struct ArchivedZemen(Archived<i32>);
+
+impl ArchivedZemen {
+    /// Get the bits of the date, as [`Zemen::to_bits`] would.
+    pub fn to_bits(&self) -> i32 {
+        self.0.to_native()
+    }
+
+    /// Get the date back, failing only if the archive wasn't validated and
+    /// holds bits that aren't a date.
+    pub fn to_zemen(&self) -> Result<Zemen, error::Error> {
+        Zemen::from_bits(self.to_bits())
+    }
+}
+
+impl PartialOrd for ArchivedZemen {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ArchivedZemen {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_bits().cmp(&other.to_bits())
+    }
+}
+
+impl PartialEq<Zemen> for ArchivedZemen {
+    fn eq(&self, other: &Zemen) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+// SAFETY: `ArchivedZemen` is a transparent wrapper of `Archived<i32>`, which
+// is portable and has no padding.
+unsafe impl Portable for ArchivedZemen {}
+
+// SAFETY: see above.
+unsafe impl NoUndef for ArchivedZemen {}
+
+// SAFETY: every bit pattern is an `Archived<i32>`, and the date is checked to
+// be one `Zemen::from_bits` accepts.
+unsafe impl<C> CheckBytes<C> for ArchivedZemen
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees `value` is aligned and points to
+        // initialized bytes.
+        let archived = unsafe { &*value };
+        archived.to_zemen().map(|_| ()).map_err(C::Error::new)
+    }
+}
+
+impl Archive for Zemen {
+    type Archived = ArchivedZemen;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(ArchivedZemen(Archived::<i32>::from_native(self.to_bits())));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Zemen {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D> Deserialize<Zemen, D> for ArchivedZemen
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Zemen, D::Error> {
+        self.to_zemen().map_err(D::Error::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rkyv::rancor;
+    use ::rkyv::vec::ArchivedVec;
+
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_archive_round_trip() -> Result<(), error::Error> {
+        let dates = [
+            Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+            Zemen::from_eth_cal(-5, Werh::Meskerem, 1)?,
+            Zemen::from_eth_cal(2016, Werh::Tir, 10)?,
+        ];
+        let bytes = ::rkyv::to_bytes::<rancor::Error>(&dates.to_vec()).unwrap();
+        let archived = ::rkyv::access::<ArchivedVec<ArchivedZemen>, rancor::Error>(&bytes).unwrap();
+
+        for (archived, qen) in archived.iter().zip(&dates) {
+            assert_eq!(archived, qen);
+            assert_eq!(archived.to_bits(), qen.to_bits());
+        }
+        assert!(archived[1] < archived[0] && archived[0] < archived[2]);
+
+        let read = ::rkyv::deserialize::<Vec<Zemen>, rancor::Error>(archived).unwrap();
+        assert_eq!(read, dates);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_bits_are_rejected() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let mut bytes = ::rkyv::to_bytes::<rancor::Error>(&qen).unwrap();
+        assert_eq!(bytes.as_slice(), qen.to_bits().to_le_bytes());
+
+        // 2016 isn't a leap year, so it doesn't have a 366th day
+        bytes.copy_from_slice(&((2016 << 9) | 366_i32).to_le_bytes());
+        assert!(::rkyv::access::<ArchivedZemen, rancor::Error>(&bytes).is_err());
+
+        let unchecked = unsafe { ::rkyv::access_unchecked::<ArchivedZemen>(&bytes) };
+        assert!(unchecked.to_zemen().is_err());
+        assert!(::rkyv::deserialize::<Zemen, rancor::Error>(unchecked).is_err());
+
+        Ok(())
+    }
+}
@@ -3,10 +3,12 @@
 use crate::error;
 use std::{fmt, str::FromStr};
 
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
 ///  Weekdays of the Ethiopian calendar, `Samint` directly translates to week, but in our case it
 ///  is enough
 #[repr(u8)]
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Samint {
     Ihud = 0,
     Senyo = 1,
@@ -18,6 +20,59 @@ pub enum Samint {
 }
 
 impl Samint {
+    /// Every day of the week, from Ihud to Kidame.
+    pub const ALL: [Samint; 7] = [
+        Self::Ihud,
+        Self::Senyo,
+        Self::Makisenyo,
+        Self::Irob,
+        Self::Hamus,
+        Self::Arb,
+        Self::Kidame,
+    ];
+
+    /// The number of days of the week, 7.
+    pub const COUNT: usize = Self::ALL.len();
+
+    /// The latin names of the days of the week, see [`Samint::latin_name`],
+    /// in the order of [`Samint::ALL`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Samint;
+    /// assert_eq!(Samint::VARIANTS.len(), Samint::COUNT);
+    /// assert_eq!(Samint::VARIANTS[0], Samint::Ihud.latin_name());
+    /// ```
+    pub const VARIANTS: &'static [&'static str] = &[
+        "Ihud",
+        "Senyo",
+        "Makisenyo",
+        "Irob",
+        "Hamus",
+        "Arb",
+        "Kidame",
+    ];
+
+    /// Iterate over every day of the week, from Ihud to Kidame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Samint;
+    /// let names: Vec<_> = Samint::iter().map(|samint| samint.to_string()).collect();
+    ///
+    /// assert_eq!(names, ["እሑድ", "ሰኞ", "ማክሰኞ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ"]);
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Samint> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Get the weekday of the Julian day number `jdn`, for any `jdn`.
+    pub(crate) fn of_jdn(jdn: i128) -> Self {
+        Self::ALL[(jdn + 1).rem_euclid(7) as usize]
+    }
+
     /// Get the next day in the week.
     ///
     /// # Examples
@@ -73,8 +128,95 @@ impl Samint {
     pub fn short_name(&self) -> String {
         self.to_string().chars().take(3).collect()
     }
+
+    /// Get the name of the day written in latin script.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Samint;
+    /// assert_eq!(Samint::Ihud.latin_name(), "Ihud");
+    /// assert_eq!(Samint::Makisenyo.latin_name(), "Makisenyo");
+    /// ```
+    pub fn latin_name(&self) -> &'static str {
+        match self {
+            Self::Ihud => "Ihud",
+            Self::Senyo => "Senyo",
+            Self::Makisenyo => "Makisenyo",
+            Self::Irob => "Irob",
+            Self::Hamus => "Hamus",
+            Self::Arb => "Arb",
+            Self::Kidame => "Kidame",
+        }
+    }
+
+    /// Parse only the spellings `Samint` is written with, i.e. its latin
+    /// name, ignoring case, or its `Display` form.
+    ///
+    /// Use [`FromStr`] to also accept the other common spellings of a day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint};
+    /// assert_eq!(Samint::parse_strict("ሐሙስ")?, Samint::Hamus);
+    /// assert_eq!(Samint::parse_strict("HAMUS")?, Samint::Hamus);
+    ///
+    /// assert!(Samint::parse_strict("ሀሙስ").is_err());
+    /// assert_eq!("ሀሙስ".parse::<Samint>()?, Samint::Hamus);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Self> {
+        Self::iter()
+            .find(|samint| {
+                samint.latin_name().eq_ignore_ascii_case(s) || samint.amharic_name() == s
+            })
+            .ok_or_else(|| error::Error::InvalidVariant("Samint", s.to_string()))
+    }
+
+    fn amharic_name(&self) -> &'static str {
+        match self {
+            Self::Ihud => "እሑድ",
+            Self::Senyo => "ሰኞ",
+            Self::Makisenyo => "ማክሰኞ",
+            Self::Irob => "ረቡዕ",
+            Self::Hamus => "ሐሙስ",
+            Self::Arb => "ዓርብ",
+            Self::Kidame => "ቅዳሜ",
+        }
+    }
 }
 
+/// Other spellings of the days seen in real input, checked by `FromStr` after
+/// the names from [`Samint::parse_strict`]. The latin ones are lowercase.
+const ALIASES: [(&str, Samint); 21] = [
+    // ሁ for ሑ, and ኁ, which sounds the same
+    ("እሁድ", Samint::Ihud),
+    ("እኁድ", Samint::Ihud),
+    ("ehud", Samint::Ihud),
+    ("segno", Samint::Senyo),
+    ("ማግሰኞ", Samint::Makisenyo),
+    ("maksegno", Samint::Makisenyo),
+    ("maksenyo", Samint::Makisenyo),
+    // እ for ዕ, and the short form
+    ("ረቡእ", Samint::Irob),
+    ("ሮብ", Samint::Irob),
+    ("rob", Samint::Irob),
+    ("erob", Samint::Irob),
+    ("rebue", Samint::Irob),
+    // ሀ and ኀ for ሐ
+    ("ሀሙስ", Samint::Hamus),
+    ("ኀሙስ", Samint::Hamus),
+    ("hamis", Samint::Hamus),
+    // አ and ዐ for ዓ
+    ("አርብ", Samint::Arb),
+    ("ዐርብ", Samint::Arb),
+    ("arib", Samint::Arb),
+    ("ቀዳሜ", Samint::Kidame),
+    ("qidame", Samint::Kidame),
+    ("kedame", Samint::Kidame),
+];
+
 impl TryFrom<u8> for Samint {
     type Error = error::Error;
 
@@ -89,7 +231,7 @@ impl TryFrom<u8> for Samint {
     /// assert_eq!(Samint::Ihud, elet);
     /// # Ok::<(), error::Error>(())
     /// ```
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
+    fn try_from(value: u8) -> Result<Self> {
         match value {
             0 => Ok(Self::Ihud),
             1 => Ok(Self::Senyo),
@@ -107,6 +249,40 @@ impl TryFrom<u8> for Samint {
         }
     }
 }
+
+impl TryFrom<&str> for Samint {
+    type Error = error::Error;
+
+    /// Parses a day name like [`FromStr`], or its number like
+    /// [`Samint::try_from`] a `u8`, from `0` for እሑድ to `6` for ቅዳሜ.
+    ///
+    /// `FromStr` only takes names, so numbers are only accepted where this
+    /// is asked for explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint};
+    /// assert_eq!(Samint::try_from("3")?, Samint::Irob);
+    /// assert_eq!(Samint::try_from("ረቡዕ")?, Samint::Irob);
+    ///
+    /// assert!(Samint::try_from("7").is_err());
+    /// assert!("3".parse::<Samint>().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn try_from(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() || !trimmed.bytes().all(|byte| byte.is_ascii_digit()) {
+            return s.parse();
+        }
+
+        let num = trimmed
+            .parse::<u8>()
+            .map_err(|_| error::Error::InvalidVariant("Samint", s.to_string()))?;
+        Samint::try_from(num)
+    }
+}
+
 impl fmt::Display for Samint {
     /// Formats a `Samint` into amharic.
     ///
@@ -119,15 +295,7 @@ impl fmt::Display for Samint {
     /// assert_eq!("እሑድ", qen.to_string())
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Self::Ihud => "እሑድ",
-            Self::Senyo => "ሰኞ",
-            Self::Makisenyo => "ማክሰኞ",
-            Self::Irob => "ረቡዕ",
-            Self::Hamus => "ሐሙስ",
-            Self::Arb => "ዓርብ",
-            Self::Kidame => "ቅዳሜ",
-        })
+        f.write_str(self.amharic_name())
     }
 }
 
@@ -136,6 +304,10 @@ impl FromStr for Samint {
 
     /// Given a string `s` it will try to parse it into `Samint` case-insensitively.
     ///
+    /// Surrounding whitespace is ignored, and the common variant spellings of
+    /// the days are accepted as well, e.g. `እሁድ` for `እሑድ` and `ረቡእ` for `ረቡዕ`.
+    /// The error has `s` as it was given.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -143,21 +315,24 @@ impl FromStr for Samint {
     /// let qen: Samint = "ihuD".parse()?;
     ///
     /// assert_eq!(Samint::Ihud, qen);
+    /// assert_eq!(" እሁድ ".parse::<Samint>()?, Samint::Ihud);
+    ///
+    /// let err = "ሰኞው".parse::<Samint>().unwrap_err();
+    /// assert_eq!(err.to_string(), "can not parse Samint, invalid token `ሰኞው`");
     /// # Ok::<(), error::Error>(())
     /// ```
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lower = s.to_lowercase();
-
-        match lower.as_str() {
-            "ihud" | "እሑድ" => Ok(Samint::Ihud),
-            "senyo" | "ሰኞ" => Ok(Samint::Senyo),
-            "makisenyo" | "ማክሰኞ" => Ok(Samint::Makisenyo),
-            "irob" | "ረቡዕ" => Ok(Samint::Irob),
-            "hamus" | "ሐሙስ" => Ok(Samint::Hamus),
-            "arb" | "ዓርብ" => Ok(Samint::Arb),
-            "kidame" | "ቅዳሜ" => Ok(Samint::Kidame),
-            _ => Err(error::Error::InvalidVariant("Samint", lower)),
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if let Ok(samint) = Self::parse_strict(trimmed) {
+            return Ok(samint);
         }
+
+        let lower = trimmed.to_lowercase();
+        ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == lower)
+            .map(|(_, samint)| *samint)
+            .ok_or_else(|| error::Error::InvalidVariant("Samint", s.to_string()))
     }
 }
 
@@ -165,12 +340,29 @@ impl FromStr for Samint {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_variants_are_the_latin_names() {
+        assert_eq!(Samint::COUNT, Samint::iter().len());
+        for (samint, name) in Samint::iter().zip(Samint::VARIANTS) {
+            assert_eq!(samint.latin_name(), *name);
+            assert_eq!(name.parse::<Samint>().unwrap(), samint);
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_samint_from_u8() {
         let _elet = Samint::try_from(8).unwrap();
     }
 
+    #[test]
+    fn test_all_is_in_order() {
+        for (i, samint) in Samint::iter().enumerate() {
+            assert_eq!(Samint::try_from(i as u8).unwrap(), samint);
+        }
+        assert_eq!(Samint::iter().len(), 7);
+    }
+
     #[test]
     fn test_short_weekday_names() {
         let names = ["እሑድ", "ሰኞ", "ማክሰ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ"];
@@ -182,7 +374,8 @@ mod tests {
     }
 
     #[test]
-    fn test_from_english_text() -> Result<(), error::Error> {
+    #[allow(clippy::unused_enumerate_index)]
+    fn test_from_english_text() -> Result<()> {
         let amh_week_name = ["እሑድ", "ሰኞ", "ማክሰኞ", "ረቡዕ", "ሐሙስ", "ዓርብ", "ቅዳሜ"];
         let eng_week_name = [
             "ihud",
@@ -203,4 +396,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_numeric_fallback() -> Result<()> {
+        for samint in Samint::iter() {
+            assert_eq!(
+                Samint::try_from((samint as u8).to_string().as_str())?,
+                samint
+            );
+            assert_eq!(Samint::try_from(samint.latin_name())?, samint);
+        }
+
+        for token in ["7", "", "-0", "1e1", "256"] {
+            assert!(Samint::try_from(token).is_err(), "{token}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_variant_spellings() -> Result<()> {
+        for (alias, samint) in ALIASES {
+            assert_eq!(Samint::from_str(alias)?, samint, "{alias}");
+            assert!(Samint::parse_strict(alias).is_err(), "{alias}");
+        }
+
+        for samint in Samint::iter() {
+            assert_eq!(Samint::parse_strict(&samint.to_string())?, samint);
+            assert_eq!(Samint::parse_strict(samint.latin_name())?, samint);
+        }
+
+        assert_eq!(Samint::from_str("EHUD")?, Samint::Ihud);
+        assert!(Samint::parse_strict(" ihud").is_err());
+
+        match Samint::from_str(" Sunday ") {
+            Err(error::Error::InvalidVariant("Samint", token)) => assert_eq!(token, " Sunday "),
+            other => panic!("unexpected {other:?}"),
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,172 @@
+//! Schemas describing the form the types are written in with the `serde`
+//! feature: `JsonSchema` with the `schemars` feature, and utoipa's
+//! `ToSchema` with the `utoipa` feature.
+//!
+//! A `Zemen` is a `YYYY-MM-DD` string. It doesn't claim the `date` format,
+//! which is for Gregorian dates and would reject e.g. ጳጉሜ 5, `2015-13-05`.
+//! `Werh` and `Samint` are one of their latin names.
+
+/// The `Display` form of `Zemen`. Years below 1000 are padded to 4
+/// characters, the `-` of negative ones included, e.g. `-005-01-01`.
+const ZEMEN_PATTERN: &str = "^-?[0-9]{3,}-(0[1-9]|1[0-3])-(0[1-9]|[12][0-9]|30)$";
+const ZEMEN_DESCRIPTION: &str = "An Ethiopian date as YYYY-MM-DD, the month being 01 to 13.";
+const ZEMEN_EXAMPLES: [&str; 2] = ["2015-05-10", "2015-13-06"];
+
+#[cfg(feature = "schemars")]
+mod json_schema {
+    use std::borrow::Cow;
+
+    use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+    use super::*;
+    use crate::{Samint, Werh, Zemen};
+
+    impl JsonSchema for Zemen {
+        fn schema_name() -> Cow<'static, str> {
+            "Zemen".into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            "zemen::Zemen".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "pattern": ZEMEN_PATTERN,
+                "description": ZEMEN_DESCRIPTION,
+                "examples": ZEMEN_EXAMPLES,
+            })
+        }
+    }
+
+    impl JsonSchema for Werh {
+        fn schema_name() -> Cow<'static, str> {
+            "Werh".into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            "zemen::Werh".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            let names: Vec<_> = Werh::iter().map(|werh| werh.latin_name()).collect();
+
+            json_schema!({
+                "type": "string",
+                "enum": names,
+                "description": "A month of the Ethiopian calendar.",
+            })
+        }
+    }
+
+    impl JsonSchema for Samint {
+        fn schema_name() -> Cow<'static, str> {
+            "Samint".into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            "zemen::Samint".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            let names: Vec<_> = Samint::iter().map(|samint| samint.latin_name()).collect();
+
+            json_schema!({
+                "type": "string",
+                "enum": names,
+                "description": "A day of the week, starting from Ihud (Sunday).",
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use schemars::schema_for;
+
+        #[test]
+        fn test_schemas() {
+            let zemen = schema_for!(Zemen);
+            assert_eq!(zemen.get("type").unwrap(), "string");
+            assert_eq!(zemen.get("pattern").unwrap(), ZEMEN_PATTERN);
+            assert!(zemen.get("format").is_none());
+
+            let werh = schema_for!(Werh);
+            let months = werh.get("enum").unwrap().as_array().unwrap();
+            assert_eq!(months.len(), 13);
+            assert_eq!(months[4], "Tir");
+
+            let samint = schema_for!(Samint);
+            let days = samint.get("enum").unwrap().as_array().unwrap();
+            assert_eq!(days.first().unwrap(), "Ihud");
+            assert_eq!(days.last().unwrap(), "Kidame");
+        }
+
+        #[test]
+        fn test_schemas_are_referenced_by_fields() {
+            #[derive(JsonSchema)]
+            #[allow(dead_code)]
+            struct Holiday {
+                date: Zemen,
+                month: Option<Werh>,
+            }
+
+            let schema = serde_json::to_value(schema_for!(Holiday)).unwrap();
+            assert_eq!(schema["properties"]["date"]["$ref"], "#/$defs/Zemen");
+            assert_eq!(schema["$defs"]["Werh"]["enum"][12], "Puagme");
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod openapi {
+    use std::borrow::Cow;
+
+    use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+    use utoipa::openapi::RefOr;
+    use utoipa::{PartialSchema, ToSchema};
+
+    use super::*;
+    use crate::Zemen;
+
+    impl PartialSchema for Zemen {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::Custom("ethiopian-date".to_string())))
+                .pattern(Some(ZEMEN_PATTERN))
+                .description(Some(ZEMEN_DESCRIPTION))
+                .examples(ZEMEN_EXAMPLES)
+                .into()
+        }
+    }
+
+    impl ToSchema for Zemen {
+        fn name() -> Cow<'static, str> {
+            "Zemen".into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_schema() {
+            let schema = serde_json::to_value(Zemen::schema()).unwrap();
+
+            assert_eq!(
+                schema,
+                serde_json::json!({
+                    "type": "string",
+                    "format": "ethiopian-date",
+                    "pattern": ZEMEN_PATTERN,
+                    "description": ZEMEN_DESCRIPTION,
+                    "examples": ZEMEN_EXAMPLES,
+                })
+            );
+            assert_eq!(Zemen::name(), "Zemen");
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Meteorological seasons of Ethiopia.
+
+use std::fmt;
+
+use crate::{conversion, error, Period, Werh, Zemen};
+
+/// The three rainfall seasons used in Ethiopian climate reporting.
+///
+/// Seasons follow whole months:
+/// ```txt
+///
+/// Bega     Tikimit  .. Tir       dry season
+/// Belg     Yekatit  .. Ginbot    short rains
+/// Kiremt   Sene     .. Meskerem  main rains
+/// ```
+///
+/// Kiremt runs across the new year, so the Kiremt of a year ends with
+/// Meskerem of the following year.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Season {
+    Bega,
+    Belg,
+    Kiremt,
+}
+
+impl Season {
+    /// Get the season a date falls in. Note that Meskerem dates fall in the
+    /// Kiremt of the previous year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Season, Zemen, Werh};
+    /// assert_eq!(Season::of(&Zemen::from_eth_cal(2015, Werh::Tir, 30)?), Season::Bega);
+    /// assert_eq!(Season::of(&Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?), Season::Belg);
+    /// assert_eq!(Season::of(&Zemen::from_eth_cal(2015, Werh::Meskerem, 1)?), Season::Kiremt);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn of(qen: &Zemen) -> Self {
+        match qen.month() {
+            Werh::Tikimit | Werh::Hedar | Werh::Tahasass | Werh::Tir => Season::Bega,
+            Werh::Yekatit | Werh::Megabit | Werh::Miyazia | Werh::Ginbot => Season::Belg,
+            _ => Season::Kiremt,
+        }
+    }
+
+    /// Get the days of the season in the Ethiopian year `year`, failing if
+    /// they are beyond the range of `Zemen`, e.g. the Kiremt of the year of
+    /// [`Zemen::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Season, Zemen, Werh};
+    /// let kiremt = Season::Kiremt.period(2015)?;
+    ///
+    /// assert_eq!(kiremt.start(), &Zemen::from_eth_cal(2015, Werh::Sene, 1)?);
+    /// assert_eq!(kiremt.end(), &Zemen::from_eth_cal(2016, Werh::Tikimit, 1)?);
+    /// assert_eq!(kiremt.len_days(), 126);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn period(self, year: i32) -> Result<Period, error::Error> {
+        // the first month, and the month after the last one
+        let (start, end, next_year) = match self {
+            Season::Bega => (Werh::Tikimit, 6, 0),
+            Season::Belg => (Werh::Yekatit, 10, 0),
+            Season::Kiremt => (Werh::Sene, 2, 1),
+        };
+        let start = Zemen::from_eth_cal(year, start, 1)?.to_jdn();
+
+        Period::from_jdn(start, conversion::eth_to_jdn(year + next_year, end, 1))
+    }
+}
+
+impl fmt::Display for Season {
+    /// Formats the season's name in amharic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Season;
+    /// assert_eq!(Season::Kiremt.to_string(), "ክረምት");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bega => "በጋ",
+            Self::Belg => "በልግ",
+            Self::Kiremt => "ክረምት",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seasons_cover_the_year() -> Result<(), error::Error> {
+        let year = 2016;
+        let bega = Season::Bega.period(year)?;
+        let belg = Season::Belg.period(year)?;
+        let kiremt = Season::Kiremt.period(year)?;
+
+        assert_eq!(bega.end(), belg.start());
+        assert_eq!(belg.end(), kiremt.start());
+        assert_eq!(kiremt.end(), Season::Bega.period(year + 1)?.start());
+
+        for period in [bega, belg, kiremt] {
+            for days in [0, period.len_days() - 1] {
+                let qen = *period.start() + days as i32;
+                assert!(Season::of(&qen).period(year)?.contains(&qen));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seasons_beyond_the_range_of_zemen() {
+        let last = Zemen::MAX.year();
+
+        assert!(Season::Belg.period(last).is_ok());
+        assert!(Season::Kiremt.period(last).is_err());
+        assert!(Season::Bega.period(last + 1).is_err());
+        assert!(Season::Bega.period(i32::MAX).is_err());
+    }
+}
@@ -0,0 +1,509 @@
+//! `Serialize` and `Deserialize` for `Zemen`, `Werh`, and `Samint`, and
+//! other representations of `Zemen` for `#[serde(with = "...")]`.
+//!
+//! A `Zemen` is written as its `YYYY-MM-DD` string, the `Display` form, and
+//! read back with `FromStr`. `Werh` and `Samint` are written as their latin
+//! names, e.g. `"Tir"` and `"Irob"`, and can be read back from any name
+//! `FromStr` accepts, or from their number.
+//!
+//! When a wire format or a database wants something else, pick one of the
+//! modules below for the field:
+//!
+//! | module             | 2015 Tir 10                                |
+//! |--------------------|--------------------------------------------|
+//! | [`iso`]            | `"2015-05-10"`, same as the default        |
+//! | [`components`]     | `{"year": 2015, "month": 5, "day": 10}`    |
+//! | [`ordinal`]        | `{"year": 2015, "ordinal": 130}`           |
+//! | [`jdn`]            | `2459963`, the Julian day number           |
+//! | [`unix_timestamp`] | `1674000000`, seconds at midnight UTC      |
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use zemen::{error, Werh, Zemen};
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(with = "zemen::serde::components")]
+//!     issued: Zemen,
+//!     #[serde(with = "zemen::serde::jdn")]
+//!     due: Zemen,
+//! }
+//!
+//! let invoice = Invoice {
+//!     issued: Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+//!     due: Zemen::from_eth_cal(2015, Werh::Yekatit, 10)?,
+//! };
+//! let json = serde_json::to_string(&invoice).unwrap();
+//!
+//! assert_eq!(json, r#"{"issued":{"year":2015,"month":5,"day":10},"due":2459993}"#);
+//! assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+//! # Ok::<(), error::Error>(())
+//! ```
+//!
+//! # `serde_with`
+//!
+//! With the `serde_with` feature, [`Iso`], [`Components`], [`Ordinal`],
+//! [`Jdn`], and [`UnixTimestamp`] pick the same representations through
+//! [`serde_with`](https://docs.rs/serde_with), which also reaches dates
+//! inside of `Option`s, `Vec`s, and map keys.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde_with")]
+//! # {
+//! # use std::collections::BTreeMap;
+//! # use serde::{Deserialize, Serialize};
+//! # use zemen::{error, serde::{Iso, Jdn}, Werh, Zemen};
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Shifts {
+//!     #[serde(with = "serde_with::As::<Option<Jdn>>")]
+//!     closed: Option<Zemen>,
+//!     #[serde(with = "serde_with::As::<BTreeMap<Iso, serde_with::Same>>")]
+//!     staff: BTreeMap<Zemen, u8>,
+//! }
+//!
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+//! let shifts = Shifts {
+//!     closed: None,
+//!     staff: BTreeMap::from([(qen, 4)]),
+//! };
+//! let json = serde_json::to_string(&shifts).unwrap();
+//!
+//! assert_eq!(json, r#"{"closed":null,"staff":{"2015-05-10":4}}"#);
+//! assert_eq!(serde_json::from_str::<Shifts>(&json).unwrap(), shifts);
+//! # }
+//! # Ok::<(), zemen::error::Error>(())
+//! ```
+
+use std::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{Samint, Werh, Zemen};
+
+/// `Zemen` as its `YYYY-MM-DD` string, the same as its own `Serialize`.
+pub mod iso {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        qen.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        Zemen::deserialize(deserializer)
+    }
+}
+
+/// `Zemen` as a `year`, `month`, and `day` struct, the month being its
+/// number.
+pub mod components {
+    use super::*;
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename = "Zemen")]
+    struct Components {
+        year: i32,
+        month: u8,
+        day: u8,
+    }
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        let (year, month, day) = qen.to_calendar_date();
+        Components {
+            year,
+            month: month as u8,
+            day,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        let Components { year, month, day } = Components::deserialize(deserializer)?;
+        Zemen::new(year, month, day).map_err(de::Error::custom)
+    }
+}
+
+/// `Zemen` as a `year` and `ordinal` struct, the ordinal being the day of
+/// the year from 1.
+pub mod ordinal {
+    use super::*;
+
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(rename = "Zemen")]
+    struct Ordinal {
+        year: i32,
+        ordinal: u16,
+    }
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        Ordinal {
+            year: qen.year(),
+            ordinal: qen.ordinal(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        let Ordinal { year, ordinal } = Ordinal::deserialize(deserializer)?;
+        Zemen::from_ordinal_date(year, ordinal).map_err(de::Error::custom)
+    }
+}
+
+/// `Zemen` as its Julian day number.
+pub mod jdn {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(qen.to_jdn())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        Zemen::from_jdn(i32::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// `Zemen` as the Unix timestamp, in seconds, of its midnight in UTC.
+///
+/// Any time of the day is read back as that day.
+pub mod unix_timestamp {
+    use super::*;
+
+    const SECONDS_PER_DAY: i64 = 86_400;
+    const UNIX_EPOCH_JDN: i64 = 2_440_588;
+
+    pub fn serialize<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64((qen.to_jdn() as i64 - UNIX_EPOCH_JDN) * SECONDS_PER_DAY)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+        let timestamp = i64::deserialize(deserializer)?;
+        let jdn = timestamp.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_JDN;
+
+        i32::try_from(jdn)
+            .map_err(|_| de::Error::custom(format!("timestamp {timestamp} is out of range")))
+            .and_then(|jdn| Zemen::from_jdn(jdn).map_err(de::Error::custom))
+    }
+}
+
+/// Implements `serde_with`'s traits for a representation with the
+/// `serialize` and `deserialize` functions of `$module`.
+#[cfg(feature = "serde_with")]
+macro_rules! serde_with_adapter {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl serde_with::SerializeAs<Zemen> for $name {
+            fn serialize_as<S: Serializer>(qen: &Zemen, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(qen, serializer)
+            }
+        }
+
+        impl<'de> serde_with::DeserializeAs<'de, Zemen> for $name {
+            fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Zemen, D::Error> {
+                $module::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde_with")]
+serde_with_adapter!(
+    /// `serde_with` adapter for [`iso`].
+    Iso,
+    iso
+);
+#[cfg(feature = "serde_with")]
+serde_with_adapter!(
+    /// `serde_with` adapter for [`components`].
+    Components,
+    components
+);
+#[cfg(feature = "serde_with")]
+serde_with_adapter!(
+    /// `serde_with` adapter for [`ordinal`].
+    Ordinal,
+    ordinal
+);
+#[cfg(feature = "serde_with")]
+serde_with_adapter!(
+    /// `serde_with` adapter for [`jdn`].
+    Jdn,
+    jdn
+);
+#[cfg(feature = "serde_with")]
+serde_with_adapter!(
+    /// `serde_with` adapter for [`unix_timestamp`].
+    UnixTimestamp,
+    unix_timestamp
+);
+
+impl Serialize for Zemen {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Zemen {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ZemenVisitor;
+
+        impl Visitor<'_> for ZemenVisitor {
+            type Value = Zemen;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a date in the form YYYY-MM-DD")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Zemen, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ZemenVisitor)
+    }
+}
+
+impl Serialize for Werh {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_variant("Werh", *self as u32 - 1, self.latin_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Werh {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NameVisitor::<Werh>::new("a month name or number"))
+    }
+}
+
+impl Serialize for Samint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_variant("Samint", *self as u32, self.latin_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Samint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NameVisitor::<Samint>::new("a weekday name or number"))
+    }
+}
+
+/// Reads `Werh` and `Samint` from their names with `FromStr`, or from their
+/// numbers with `TryFrom<u8>`.
+struct NameVisitor<T> {
+    expecting: &'static str,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T> NameVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        NameVisitor {
+            expecting,
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for NameVisitor<T>
+where
+    T: std::str::FromStr<Err = crate::error::Error> + TryFrom<u8, Error = crate::error::Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        u8::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            .and_then(|value| T::try_from(value).map_err(E::custom))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error;
+
+    #[test]
+    fn test_json_round_trip() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let json = serde_json::to_string(&(qen, qen.month(), qen.weekday())).unwrap();
+        assert_eq!(json, r#"["2015-05-10","Tir","Irob"]"#);
+
+        let back: (Zemen, Werh, Samint) = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, (qen, Werh::Tir, Samint::Irob));
+
+        for qen in [
+            Zemen::MIN,
+            Zemen::MAX,
+            Zemen::from_eth_cal(-5, Werh::Puagme, 5)?,
+        ] {
+            let json = serde_json::to_string(&qen).unwrap();
+            assert_eq!(serde_json::from_str::<Zemen>(&json).unwrap(), qen);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_names_and_numbers_are_accepted() {
+        assert_eq!(serde_json::from_str::<Werh>(r#""ጥር""#).unwrap(), Werh::Tir);
+        assert_eq!(serde_json::from_str::<Werh>("13").unwrap(), Werh::Puagme);
+        assert_eq!(
+            serde_json::from_str::<Samint>(r#""ihud""#).unwrap(),
+            Samint::Ihud
+        );
+        assert_eq!(serde_json::from_str::<Samint>("6").unwrap(), Samint::Kidame);
+
+        assert!(serde_json::from_str::<Werh>("14").is_err());
+        assert!(serde_json::from_str::<Werh>("-1").is_err());
+        assert!(serde_json::from_str::<Samint>(r#""Friday""#).is_err());
+        assert!(serde_json::from_str::<Zemen>(r#""2015-13-07""#).is_err());
+        assert!(serde_json::from_str::<Zemen>("20150510").is_err());
+    }
+
+    #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+    struct Every {
+        #[serde(with = "iso")]
+        iso: Zemen,
+        #[serde(with = "components")]
+        components: Zemen,
+        #[serde(with = "ordinal")]
+        ordinal: Zemen,
+        #[serde(with = "jdn")]
+        jdn: Zemen,
+        #[serde(with = "unix_timestamp")]
+        unix_timestamp: Zemen,
+    }
+
+    impl Every {
+        fn new(qen: Zemen) -> Self {
+            Every {
+                iso: qen,
+                components: qen,
+                ordinal: qen,
+                jdn: qen,
+                unix_timestamp: qen,
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_modules() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let json = serde_json::to_value(Every::new(qen)).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "iso": "2015-05-10",
+                "components": {"year": 2015, "month": 5, "day": 10},
+                "ordinal": {"year": 2015, "ordinal": 130},
+                "jdn": 2_459_963,
+                "unix_timestamp": 1_674_000_000,
+            })
+        );
+
+        for qen in [
+            qen,
+            Zemen::MIN,
+            Zemen::MAX,
+            Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?,
+        ] {
+            let json = serde_json::to_string(&Every::new(qen)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Every>(&json).unwrap(),
+                Every::new(qen)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde_with")]
+    #[test]
+    fn test_serde_with_containers() -> Result<(), error::Error> {
+        use std::collections::HashMap;
+
+        #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+        struct Containers {
+            #[serde(with = "serde_with::As::<Option<Components>>")]
+            maybe: Option<Zemen>,
+            #[serde(with = "serde_with::As::<Vec<Ordinal>>")]
+            many: Vec<Zemen>,
+            #[serde(with = "serde_with::As::<HashMap<Jdn, UnixTimestamp>>")]
+            keys: HashMap<Zemen, Zemen>,
+        }
+
+        let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+        let containers = Containers {
+            maybe: Some(qen),
+            many: vec![qen, qen + 1],
+            keys: HashMap::from([(qen, qen)]),
+        };
+
+        let json = serde_json::to_value(&containers).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "maybe": {"year": 2015, "month": 5, "day": 10},
+                "many": [{"year": 2015, "ordinal": 130}, {"year": 2015, "ordinal": 131}],
+                "keys": {"2459963": 1_674_000_000},
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Containers>(json).unwrap(),
+            containers
+        );
+
+        let none = r#"{"maybe":null,"many":[],"keys":{}}"#;
+        assert_eq!(
+            serde_json::from_str::<Containers>(none).unwrap().maybe,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_modules_validate() {
+        let read = |json: &str| serde_json::from_str::<Every>(json);
+        let valid = r#"{"iso":"2015-05-10","components":{"year":2015,"month":5,"day":10},"ordinal":{"year":2015,"ordinal":130},"jdn":2459963,"unix_timestamp":1674000000}"#;
+        assert!(read(valid).is_ok());
+
+        for (from, to) in [
+            (r#""day":10"#, r#""day":31"#),
+            (r#""month":5"#, r#""month":14"#),
+            (r#""ordinal":130"#, r#""ordinal":367"#),
+            ("2459963", "-2147483648"),
+            ("1674000000", "9223372036854775807"),
+        ] {
+            assert!(read(&valid.replace(from, to)).is_err(), "{to}");
+        }
+
+        let afternoon = valid.replace("1674000000", "1674050000");
+        assert_eq!(
+            read(&afternoon).unwrap().unix_timestamp,
+            read(valid).unwrap().unix_timestamp
+        );
+        let before_1970 = serde_json::from_str::<Every>(&valid.replace("1674000000", "-1"));
+        assert_eq!(
+            before_1970.unwrap().unix_timestamp,
+            Zemen::from_eth_cal(1962, Werh::Tahasass, 22).unwrap()
+        );
+    }
+}
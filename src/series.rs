@@ -0,0 +1,311 @@
+//! A small time series keyed by `Zemen`.
+
+use std::collections::BTreeMap;
+
+use crate::{conversion, Granularity, Zemen};
+
+/// How `Series::fill_missing` comes up with values for missing periods.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FillPolicy<T> {
+    /// Use the same value for every missing period.
+    Value(T),
+    /// Repeat the value of the closest earlier period.
+    Forward,
+}
+
+/// Values keyed by date, ordered from the earliest to the latest date.
+///
+/// A series has a [`Granularity`]: the keys of a daily series are days, and
+/// the keys of e.g. a monthly series are the first days of the months.
+/// Series start out daily, and [`Series::resample`] produces coarser ones.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, Granularity, Series, Zemen, Werh};
+/// let mut visits = Series::new();
+/// visits.insert(&Zemen::from_eth_cal(2015, Werh::Tir, 1)?, 3);
+/// visits.insert(&Zemen::from_eth_cal(2015, Werh::Tir, 20)?, 4);
+/// visits.insert(&Zemen::from_eth_cal(2015, Werh::Yekatit, 2)?, 1);
+///
+/// let monthly = visits.resample(Granularity::Month, |days| days.iter().copied().sum::<i32>());
+///
+/// assert_eq!(monthly.get(&Zemen::from_eth_cal(2015, Werh::Tir, 1)?), Some(&7));
+/// assert_eq!(monthly.get(&Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?), Some(&1));
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Series<T> {
+    granularity: Granularity,
+    points: BTreeMap<i32, T>,
+}
+
+impl<T> Default for Series<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Zemen, T)> for Series<T> {
+    fn from_iter<I: IntoIterator<Item = (Zemen, T)>>(iter: I) -> Self {
+        let mut series = Series::new();
+        for (qen, value) in iter {
+            series.insert(&qen, value);
+        }
+        series
+    }
+}
+
+impl<T> Series<T> {
+    /// Create an empty daily series.
+    pub fn new() -> Self {
+        Self::with_granularity(Granularity::Day)
+    }
+
+    /// Create an empty series whose keys are `granularity` sized periods.
+    pub fn with_granularity(granularity: Granularity) -> Self {
+        Series {
+            granularity,
+            points: BTreeMap::new(),
+        }
+    }
+
+    /// Get the granularity of the keys.
+    pub fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    /// Set the value of the period `qen` falls in, returning the value it
+    /// replaced, if any.
+    pub fn insert(&mut self, qen: &Zemen, value: T) -> Option<T> {
+        self.points
+            .insert(bucket_start(qen.to_jdn(), self.granularity), value)
+    }
+
+    /// Get the value of the period `qen` falls in.
+    pub fn get(&self, qen: &Zemen) -> Option<&T> {
+        self.points
+            .get(&bucket_start(qen.to_jdn(), self.granularity))
+    }
+
+    /// Get the number of values.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the series has no values.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Iterate over the keys and values from the earliest to the latest.
+    pub fn iter(&self) -> impl Iterator<Item = (Zemen, &T)> {
+        self.points
+            .iter()
+            .map(|(jdn, value)| (from_jdn(*jdn), value))
+    }
+
+    /// Group the values into `granularity` sized periods, and combine each
+    /// group into one value with `fold`.
+    ///
+    /// Resampling to a finer granularity than the series already has leaves
+    /// the keys as they are.
+    pub fn resample<U, F>(&self, granularity: Granularity, mut fold: F) -> Series<U>
+    where
+        F: FnMut(&[&T]) -> U,
+    {
+        let mut resampled = Series::with_granularity(granularity);
+        let mut group: Vec<&T> = Vec::new();
+        let mut current = None;
+
+        for (jdn, value) in &self.points {
+            let start = bucket_start(*jdn, granularity);
+
+            if let Some(finished) = current.filter(|current| *current != start) {
+                resampled.points.insert(finished, fold(&group));
+                group.clear();
+            }
+
+            current = Some(start);
+            group.push(value);
+        }
+
+        if let Some(current) = current {
+            resampled.points.insert(current, fold(&group));
+        }
+
+        resampled
+    }
+
+    /// Pair up the values of two series by date. Dates present in only one
+    /// of the series are paired with `None`.
+    ///
+    /// The keys are compared as they are, so both series should have the
+    /// same granularity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Series, Zemen, Werh};
+    /// let tir_1 = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// let tir_2 = Zemen::from_eth_cal(2015, Werh::Tir, 2)?;
+    ///
+    /// let visits: Series<_> = [(tir_1, 3), (tir_2, 5)].into_iter().collect();
+    /// let rain: Series<_> = [(tir_2, 1.5)].into_iter().collect();
+    ///
+    /// let aligned = visits.align(&rain);
+    /// assert_eq!(aligned.get(&tir_1), Some(&(Some(&3), None)));
+    /// assert_eq!(aligned.get(&tir_2), Some(&(Some(&5), Some(&1.5))));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn align<'a, U>(&'a self, other: &'a Series<U>) -> Series<(Option<&'a T>, Option<&'a U>)> {
+        let mut aligned = Series::with_granularity(self.granularity);
+
+        for (jdn, value) in &self.points {
+            aligned
+                .points
+                .insert(*jdn, (Some(value), other.points.get(jdn)));
+        }
+
+        for (jdn, value) in &other.points {
+            aligned.points.entry(*jdn).or_insert((None, Some(value)));
+        }
+
+        aligned
+    }
+}
+
+impl<T: Clone> Series<T> {
+    /// Add a value for every period missing between the earliest and the
+    /// latest key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, FillPolicy, Series, Zemen, Werh};
+    /// let tir_1 = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// let tir_4 = Zemen::from_eth_cal(2015, Werh::Tir, 4)?;
+    ///
+    /// let visits: Series<_> = [(tir_1, 3), (tir_4, 5)].into_iter().collect();
+    ///
+    /// let zeros = visits.fill_missing(FillPolicy::Value(0));
+    /// assert_eq!(zeros.iter().map(|(_, v)| *v).collect::<Vec<_>>(), [3, 0, 0, 5]);
+    ///
+    /// let carried = visits.fill_missing(FillPolicy::Forward);
+    /// assert_eq!(carried.iter().map(|(_, v)| *v).collect::<Vec<_>>(), [3, 3, 3, 5]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn fill_missing(&self, policy: FillPolicy<T>) -> Series<T> {
+        let mut filled = self.clone();
+
+        let (Some(&first), Some(&last)) = (self.points.keys().next(), self.points.keys().last())
+        else {
+            return filled;
+        };
+
+        let mut previous = &self.points[&first];
+        let mut jdn = first;
+        while jdn < last {
+            match self.points.get(&jdn) {
+                Some(value) => previous = value,
+                None => {
+                    let value = match &policy {
+                        FillPolicy::Value(value) => value.clone(),
+                        FillPolicy::Forward => previous.clone(),
+                    };
+                    filled.points.insert(jdn, value);
+                }
+            }
+
+            jdn = next_bucket(jdn, self.granularity);
+        }
+
+        filled
+    }
+}
+
+#[allow(clippy::expect_used)]
+fn from_jdn(jdn: i32) -> Zemen {
+    Zemen::from_jdn(jdn).expect("keys are created from valid dates")
+}
+
+/// Get the Julian day number of the first day of the `granularity` sized
+/// period `jdn` falls in.
+fn bucket_start(jdn: i32, granularity: Granularity) -> i32 {
+    let (year, month, day) = conversion::jdn_to_eth(jdn);
+
+    match granularity {
+        Granularity::Day => jdn,
+        Granularity::Week => {
+            let ordinal = conversion::to_ordinal(month as i32, day as i32);
+            jdn - (ordinal - 1) % 7
+        }
+        Granularity::Month => jdn - (day as i32 - 1),
+        Granularity::Year => conversion::eth_to_jdn(year, 1, 1),
+    }
+}
+
+/// Get the Julian day number of the first day of the period following the one
+/// starting at `start`.
+fn next_bucket(start: i32, granularity: Granularity) -> i32 {
+    let longest = match granularity {
+        Granularity::Day => 1,
+        Granularity::Week => 7,
+        Granularity::Month => 30,
+        Granularity::Year => 366,
+    };
+
+    bucket_start(start + longest, granularity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_resample_by_week_across_new_year() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 28)?;
+        let daily: Series<_> = (0..20).map(|i| (start + i, 1)).collect();
+
+        let weekly = daily.resample(Granularity::Week, |days| days.len());
+        let counts: Vec<_> = weekly
+            .iter()
+            .map(|(qen, n)| (qen.to_string(), *n))
+            .collect();
+
+        assert_eq!(
+            counts,
+            [
+                ("2015-12-28".to_string(), 7),
+                ("2015-13-05".to_string(), 2),
+                ("2016-01-01".to_string(), 7),
+                ("2016-01-08".to_string(), 4),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_missing_months() -> Result<(), error::Error> {
+        let mut monthly = Series::with_granularity(Granularity::Month);
+        monthly.insert(&Zemen::from_eth_cal(2015, Werh::Nehase, 10)?, 1);
+        monthly.insert(&Zemen::from_eth_cal(2016, Werh::Tikimit, 3)?, 2);
+
+        let filled = monthly.fill_missing(FillPolicy::Forward);
+        let months: Vec<_> = filled.iter().map(|(qen, n)| (qen.month(), *n)).collect();
+
+        assert_eq!(
+            months,
+            [
+                (Werh::Nehase, 1),
+                (Werh::Puagme, 1),
+                (Werh::Meskerem, 1),
+                (Werh::Tikimit, 2),
+            ]
+        );
+
+        Ok(())
+    }
+}
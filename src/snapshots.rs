@@ -0,0 +1,132 @@
+//! Golden snapshots of the localized names and the display presets.
+//!
+//! The snapshots are plain text files kept in the repository and compared
+//! against what the crate renders on every test run, so a changed
+//! translation or preset shows up as a diff in review. They're also exposed
+//! here, so apps can check their own UI against the same names.
+//!
+//! After an intended change, set `ZEMEN_UPDATE_SNAPSHOTS=1` when running the
+//! tests to write the snapshots again.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use zemen::{snapshots, Locale};
+//! assert_eq!(snapshots::golden(Locale::English), snapshots::render_locale(Locale::English));
+//! assert!(snapshots::golden(Locale::Amharic).contains("5   ጥር"));
+//! assert_eq!(snapshots::PRESETS, snapshots::render_presets());
+//! ```
+
+use std::fmt::Write;
+
+use crate::{DisplayPreset, Locale, Samint, Werh, Zemen};
+
+/// The snapshot of [`Locale::Amharic`], see [`render_locale`].
+pub const AMHARIC: &str = include_str!("snapshots/amharic.txt");
+
+/// The snapshot of [`Locale::English`], see [`render_locale`].
+pub const ENGLISH: &str = include_str!("snapshots/english.txt");
+
+/// The snapshot of every [`DisplayPreset`], see [`render_presets`].
+pub const PRESETS: &str = include_str!("snapshots/presets.txt");
+
+/// The dates the snapshots write out: the first day of a year, a day in the
+/// middle of one, a leap day, which is also the last day of its year, and a
+/// day before the year 1.
+const SAMPLES: [(i32, Werh, u8); 4] = [
+    (2016, Werh::Meskerem, 1),
+    (2015, Werh::Tir, 10),
+    (2015, Werh::Puagme, 6),
+    (-5, Werh::Hedar, 3),
+];
+
+#[allow(clippy::expect_used)]
+fn samples() -> impl Iterator<Item = Zemen> {
+    SAMPLES.into_iter().map(|(year, month, day)| {
+        Zemen::from_eth_cal(year, month, day).expect("samples are valid dates")
+    })
+}
+
+/// Get the snapshot of `locale` kept in the repository.
+pub fn golden(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Amharic => AMHARIC,
+        Locale::English => ENGLISH,
+    }
+}
+
+/// Write out the month and weekday names of `locale`, one per line with
+/// their number, and the accessible labels of a few dates.
+pub fn render_locale(locale: Locale) -> String {
+    let mut out = String::from("# months\n");
+    for werh in Werh::iter() {
+        // writing to a `String` never fails
+        let _ = writeln!(out, "{:<3} {}", werh as u8, locale.month_name(werh));
+    }
+
+    out.push_str("\n# weekdays\n");
+    for samint in Samint::iter() {
+        let _ = writeln!(out, "{:<3} {}", samint as u8, locale.weekday_name(samint));
+    }
+
+    out.push_str("\n# labels\n");
+    for qen in samples() {
+        let _ = writeln!(out, "{:<11} {}", qen, qen.accessible_label(locale));
+    }
+
+    out
+}
+
+/// Write out every [`DisplayPreset`] for a few dates, one line per date and
+/// preset.
+pub fn render_presets() -> String {
+    let mut out = String::new();
+
+    for qen in samples() {
+        let _ = writeln!(out, "# {qen}");
+        for preset in DisplayPreset::ALL {
+            let _ = writeln!(out, "{:<16} {}", preset.name(), preset.format(&qen));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::*;
+
+    /// Compare `rendered` with the snapshot `file`, or write it when
+    /// `ZEMEN_UPDATE_SNAPSHOTS` is set.
+    fn check(file: &str, golden: &str, rendered: &str) {
+        if env::var_os("ZEMEN_UPDATE_SNAPSHOTS").is_some() {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src/snapshots")
+                .join(file);
+            fs::write(path, rendered).unwrap();
+            return;
+        }
+
+        assert!(
+            golden == rendered,
+            "snapshot {file} changed, run the tests with ZEMEN_UPDATE_SNAPSHOTS=1 to write it again\n\
+             --- {file}\n{golden}\n+++ rendered\n{rendered}"
+        );
+    }
+
+    #[test]
+    fn test_locale_snapshots() {
+        for locale in Locale::ALL {
+            let file = format!("{}.txt", format!("{locale:?}").to_lowercase());
+            check(&file, golden(locale), &render_locale(locale));
+        }
+    }
+
+    #[test]
+    fn test_preset_snapshots() {
+        check("presets.txt", PRESETS, &render_presets());
+    }
+}
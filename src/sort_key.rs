@@ -0,0 +1,132 @@
+//! Ordering records dated in different calendars together.
+
+use crate::{conversion, error, validator, Zemen};
+
+/// The calendar a record was dated in. Ethiopian dates sort before
+/// Gregorian dates of the same day.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum CalendarTag {
+    Ethiopian,
+    Gregorian,
+}
+
+/// A key ordering dates from either calendar by the day they fall on.
+///
+/// Keys compare by Julian day number first, and by the calendar the date was
+/// given in second, so merging and sorting Ethiopian and Gregorian dated
+/// records is stable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, CalendarTag, SortKey, Zemen, Werh};
+/// let mut keys = vec![
+///     SortKey::from_gregorian(2023, 1, 20)?,
+///     SortKey::from(&Zemen::from_eth_cal(2015, Werh::Tir, 12)?),
+///     SortKey::from_gregorian(2023, 1, 19)?,
+///     SortKey::from(&Zemen::from_eth_cal(2015, Werh::Tir, 11)?),
+/// ];
+/// keys.sort();
+///
+/// let tags: Vec<_> = keys.iter().map(SortKey::calendar).collect();
+/// assert_eq!(
+///     tags,
+///     [
+///         CalendarTag::Ethiopian,
+///         CalendarTag::Gregorian,
+///         CalendarTag::Ethiopian,
+///         CalendarTag::Gregorian,
+///     ]
+/// );
+/// assert_eq!(keys[0].jdn(), keys[1].jdn());
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct SortKey {
+    jdn: i32,
+    calendar: CalendarTag,
+}
+
+impl SortKey {
+    /// Create the key of the proleptic Gregorian date `year`-`month`-`day`.
+    pub fn from_gregorian(year: i32, month: u8, day: u8) -> Result<Self, error::Error> {
+        validator::gre::is_valid_date(year, month, day)?;
+
+        Ok(SortKey {
+            jdn: conversion::gre_to_jdn(year, month, day),
+            calendar: CalendarTag::Gregorian,
+        })
+    }
+
+    /// Get the Julian day number of the date.
+    pub fn jdn(&self) -> i32 {
+        self.jdn
+    }
+
+    /// Get the calendar the date was given in.
+    pub fn calendar(&self) -> CalendarTag {
+        self.calendar
+    }
+
+    /// Get the date as a `Zemen`, whichever calendar it was given in.
+    pub fn to_zemen(&self) -> Result<Zemen, error::Error> {
+        Zemen::from_jdn(self.jdn)
+    }
+}
+
+impl From<&Zemen> for SortKey {
+    fn from(qen: &Zemen) -> Self {
+        SortKey {
+            jdn: qen.to_jdn(),
+            calendar: CalendarTag::Ethiopian,
+        }
+    }
+}
+
+impl From<Zemen> for SortKey {
+    fn from(qen: Zemen) -> Self {
+        SortKey::from(&qen)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<&time::Date> for SortKey {
+    fn from(date: &time::Date) -> Self {
+        SortKey {
+            jdn: date.to_julian_day(),
+            calendar: CalendarTag::Gregorian,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for SortKey {
+    fn from(date: time::Date) -> Self {
+        SortKey::from(&date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_both_calendars_agree_on_the_day() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?;
+        let gregorian = SortKey::from_gregorian(2023, 9, 12)?;
+
+        assert_eq!(SortKey::from(&qen).jdn(), gregorian.jdn());
+        assert_eq!(gregorian.to_zemen()?, qen);
+        assert!(SortKey::from(&qen) < gregorian);
+        assert!(SortKey::from_gregorian(2023, 2, 29).is_err());
+
+        #[cfg(feature = "time")]
+        {
+            let date = time::Date::from_calendar_date(2023, time::Month::September, 12)?;
+            assert_eq!(SortKey::from(date), gregorian);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,727 @@
+//! Storing `Zemen` in SQL databases.
+//!
+//! A `Zemen` column is `TEXT` holding the `YYYY-MM-DD` `Display` form, which
+//! sorts like the dates themselves for the years 0 to 9999. Wrap a date in
+//! [`Jdn`] to store it as an `INTEGER` Julian day number instead, which
+//! sorts for every year and lets SQL compute distances in days.
+//!
+//! Enable the feature of a driver to get its impls:
+//!
+//! - `rusqlite`: `ToSql` and `FromSql` for `Zemen` and `Jdn`.
+//! - `diesel-postgres`, `diesel-mysql`, and `diesel-sqlite`: `ToSql` and
+//!   `FromSql` for `Zemen` as `Date`, through its Gregorian date, and as
+//!   `Text`, for the backend. `Zemen` derives `AsExpression` and
+//!   `FromSqlRow`, so it can be a field of a model or a bind parameter.
+//! - `sqlx-postgres`, `sqlx-mysql`, and `sqlx-sqlite`: `Type`, `Encode`, and
+//!   `Decode` for `Zemen` as the `DATE` of the database, through its
+//!   Gregorian date, so rows decode straight into Ethiopian dates.
+//! - `postgres-types`: `ToSql` and `FromSql` for `Zemen` as `DATE`, for
+//!   tokio-postgres and postgres. The wire format is the days since
+//!   2000-01-01, so it doesn't go through a Gregorian date, and the
+//!   `infinity` and `-infinity` dates fail to be read.
+//! - `sea-orm`: `TryGetable`, `ValueType`, `Nullable`, and `IntoActiveValue`
+//!   for `Zemen` as `DATE`, through its Gregorian date, and for [`Text`] as
+//!   `TEXT`, so either can be a field of an entity.
+//!
+//! The `redis` feature enables this module too, for `Jdn`, see `zemen::redis`.
+//!
+//! ```rust
+//! # #[cfg(feature = "diesel-sqlite")]
+//! # {
+//! # use diesel::{dsl::sql, prelude::*, sql_types::{Date, Text}};
+//! # use zemen::{Werh, Zemen};
+//! let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10).unwrap();
+//! let mut db = SqliteConnection::establish(":memory:").unwrap();
+//!
+//! let gregorian: String = diesel::select(sql::<Text>("").bind::<Date, _>(qen))
+//!     .get_result(&mut db)
+//!     .unwrap();
+//! assert_eq!(gregorian, "2023-01-18");
+//!
+//! let read: Zemen = diesel::select(sql::<Text>("'2015-05-10'")).get_result(&mut db).unwrap();
+//! assert_eq!(read, qen);
+//! # }
+//! ```
+
+use crate::Zemen;
+
+/// A `Zemen` stored as its Julian day number, see the
+/// [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "rusqlite")]
+/// # {
+/// # use zemen::{sql::Jdn, Werh, Zemen};
+/// # let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10).unwrap();
+/// let db = rusqlite::Connection::open_in_memory().unwrap();
+/// db.execute("CREATE TABLE invoices (issued TEXT, due INTEGER)", ()).unwrap();
+/// db.execute("INSERT INTO invoices VALUES (?1, ?2)", (qen, Jdn(qen + 30))).unwrap();
+///
+/// let (issued, due): (Zemen, Jdn) = db
+///     .query_row("SELECT issued, due FROM invoices", (), |row| Ok((row.get(0)?, row.get(1)?)))
+///     .unwrap();
+/// assert_eq!((issued, due.0), (qen, qen + 30));
+///
+/// let raw: (String, i64) = db
+///     .query_row("SELECT issued, due FROM invoices", (), |row| Ok((row.get(0)?, row.get(1)?)))
+///     .unwrap();
+/// assert_eq!(raw, ("2015-05-10".to_string(), 2_459_993));
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Jdn(pub Zemen);
+
+impl From<Zemen> for Jdn {
+    fn from(qen: Zemen) -> Self {
+        Jdn(qen)
+    }
+}
+
+impl From<Jdn> for Zemen {
+    fn from(jdn: Jdn) -> Self {
+        jdn.0
+    }
+}
+
+/// A `Zemen` stored as its `YYYY-MM-DD` `Display` form, for the libraries
+/// where a `Zemen` itself is stored as a `DATE`, see the
+/// [module documentation](self).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Text(pub Zemen);
+
+impl From<Zemen> for Text {
+    fn from(qen: Zemen) -> Self {
+        Text(qen)
+    }
+}
+
+impl From<Text> for Zemen {
+    fn from(text: Text) -> Self {
+        text.0
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite {
+    use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+    use super::Jdn;
+    use crate::Zemen;
+
+    impl ToSql for Zemen {
+        fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(ToSqlOutput::from(self.to_string()))
+        }
+    }
+
+    impl FromSql for Zemen {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            value
+                .as_str()?
+                .parse()
+                .map_err(|err| FromSqlError::Other(Box::new(err)))
+        }
+    }
+
+    impl ToSql for Jdn {
+        fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(ToSqlOutput::from(self.0.to_jdn()))
+        }
+    }
+
+    impl FromSql for Jdn {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            let jdn = value.as_i64()?;
+            let jdn = i32::try_from(jdn).map_err(|_| FromSqlError::OutOfRange(jdn))?;
+
+            Zemen::from_jdn(jdn)
+                .map(Jdn)
+                .map_err(|_| FromSqlError::OutOfRange(jdn.into()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ::rusqlite::Connection;
+
+        use super::*;
+        use crate::{error, Werh};
+
+        #[test]
+        fn test_columns_round_trip() -> Result<(), error::Error> {
+            let db = Connection::open_in_memory().unwrap();
+            db.execute("CREATE TABLE dates (text TEXT, jdn INTEGER)", ())
+                .unwrap();
+
+            let dates = [
+                Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+                Zemen::from_eth_cal(-5, Werh::Meskerem, 1)?,
+                Zemen::MAX,
+                Zemen::MIN,
+            ];
+            for qen in dates {
+                db.execute("INSERT INTO dates VALUES (?1, ?2)", (qen, Jdn(qen)))
+                    .unwrap();
+            }
+
+            let mut statement = db.prepare("SELECT text, jdn FROM dates").unwrap();
+            let read = statement
+                .query_map((), |row| {
+                    Ok((row.get::<_, Zemen>(0)?, row.get::<_, Jdn>(1)?))
+                })
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            assert_eq!(read, dates.map(|qen| (qen, Jdn(qen))));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_invalid_columns_are_rejected() {
+            let db = Connection::open_in_memory().unwrap();
+            let read = |sql: &str| db.query_row(sql, (), |row| row.get::<_, Zemen>(0));
+            let read_jdn = |sql: &str| db.query_row(sql, (), |row| row.get::<_, Jdn>(0));
+
+            assert!(read("SELECT '2015-13-07'").is_err());
+            assert!(read("SELECT 2459993").is_err());
+            assert!(read_jdn("SELECT '2459993'").is_err());
+            assert!(read_jdn("SELECT 9223372036854775807").is_err());
+            assert!(read_jdn("SELECT -2147483648").is_err());
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel {
+    #[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+    use std::io::Write;
+
+    use ::diesel::backend::Backend;
+    use ::diesel::deserialize::{self, FromSql};
+    #[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+    use ::diesel::serialize::{self, Output, ToSql};
+    use ::diesel::sql_types::{Date, Text};
+
+    use crate::Zemen;
+
+    impl<DB> FromSql<Date, DB> for Zemen
+    where
+        DB: Backend,
+        time::Date: FromSql<Date, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            let date = time::Date::from_sql(bytes)?;
+            Ok(Zemen::from_date(&date))
+        }
+    }
+
+    impl<DB> FromSql<Text, DB> for Zemen
+    where
+        DB: Backend,
+        String: FromSql<Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(String::from_sql(bytes)?.parse()?)
+        }
+    }
+
+    // Backends writing binds to bytes can take a borrow of a date made here.
+    #[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+    macro_rules! raw_bytes_to_sql {
+        ($backend:ty) => {
+            impl ToSql<Date, $backend> for Zemen {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                    ToSql::<Date, $backend>::to_sql(&self.try_to_gre()?, &mut out.reborrow())
+                }
+            }
+
+            impl ToSql<Text, $backend> for Zemen {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                    write!(out, "{self}")?;
+                    Ok(serialize::IsNull::No)
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "diesel-postgres")]
+    raw_bytes_to_sql!(::diesel::pg::Pg);
+    #[cfg(feature = "diesel-mysql")]
+    raw_bytes_to_sql!(::diesel::mysql::Mysql);
+
+    #[cfg(feature = "diesel-sqlite")]
+    mod sqlite {
+        use ::diesel::serialize::{self, Output, ToSql};
+        use ::diesel::sql_types::{Date, Text};
+        use ::diesel::sqlite::Sqlite;
+
+        use crate::Zemen;
+
+        impl ToSql<Date, Sqlite> for Zemen {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+                let date = self.try_to_gre()?;
+                let (year, month, day) = (date.year(), u8::from(date.month()), date.day());
+                out.set_value(format!("{year:04}-{month:02}-{day:02}"));
+                Ok(serialize::IsNull::No)
+            }
+        }
+
+        impl ToSql<Text, Sqlite> for Zemen {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+                out.set_value(self.to_string());
+                Ok(serialize::IsNull::No)
+            }
+        }
+    }
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "diesel-postgres",
+            feature = "diesel-mysql",
+            feature = "diesel-sqlite"
+        )
+    ))]
+    mod tests {
+        use super::*;
+        use crate::{error, Werh};
+
+        #[cfg(feature = "diesel-sqlite")]
+        #[test]
+        fn test_sqlite_columns() -> Result<(), error::Error> {
+            use ::diesel::dsl::sql;
+            use ::diesel::prelude::*;
+
+            ::diesel::table! {
+                letters (id) {
+                    id -> Integer,
+                    written -> Date,
+                    filed -> Text,
+                }
+            }
+
+            #[derive(Queryable, Insertable, Debug, PartialEq)]
+            #[diesel(table_name = letters)]
+            struct Letter {
+                id: i32,
+                written: Zemen,
+                filed: Zemen,
+            }
+
+            let mut db = SqliteConnection::establish(":memory:").unwrap();
+            ::diesel::sql_query("CREATE TABLE letters (id INTEGER, written DATE, filed TEXT)")
+                .execute(&mut db)
+                .unwrap();
+
+            let letter = Letter {
+                id: 1,
+                written: Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+                filed: Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?,
+            };
+            ::diesel::insert_into(letters::table)
+                .values(&letter)
+                .execute(&mut db)
+                .unwrap();
+
+            let read: Letter = letters::table.first(&mut db).unwrap();
+            assert_eq!(read, letter);
+
+            let raw: (String, String) = letters::table
+                .select((sql::<Text>("written"), sql::<Text>("filed")))
+                .first(&mut db)
+                .unwrap();
+            assert_eq!(raw, ("2023-09-11".to_string(), "2016-01-01".to_string()));
+
+            let found: i64 = letters::table
+                .filter(letters::written.lt(Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?))
+                .count()
+                .get_result(&mut db)
+                .unwrap();
+            assert_eq!(found, 1);
+
+            let far = Letter {
+                id: 2,
+                written: Zemen::MAX,
+                filed: Zemen::MAX,
+            };
+            assert!(::diesel::insert_into(letters::table)
+                .values(&far)
+                .execute(&mut db)
+                .is_err());
+
+            Ok(())
+        }
+
+        // The binds of these backends can't be read back outside of diesel,
+        // this checks the impls are there and don't fail.
+        #[cfg(feature = "diesel-postgres")]
+        #[test]
+        fn test_postgres_binds() -> Result<(), error::Error> {
+            use ::diesel::pg::{Pg, PgMetadataLookup, PgTypeMetadata};
+            use ::diesel::query_builder::bind_collector::RawBytesBindCollector;
+            use ::diesel::query_builder::BindCollector;
+
+            struct NoLookup;
+
+            impl PgMetadataLookup for NoLookup {
+                fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+                    unreachable!("dates are built in types")
+                }
+            }
+
+            let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+            let mut binds = RawBytesBindCollector::<Pg>::new();
+            let lookup: &mut dyn PgMetadataLookup = &mut NoLookup;
+            binds.push_bound_value::<Date, _>(&qen, lookup).unwrap();
+            binds.push_bound_value::<Text, _>(&qen, lookup).unwrap();
+
+            Ok(())
+        }
+
+        #[cfg(feature = "diesel-mysql")]
+        #[test]
+        fn test_mysql_binds() -> Result<(), error::Error> {
+            use ::diesel::mysql::Mysql;
+            use ::diesel::query_builder::bind_collector::RawBytesBindCollector;
+            use ::diesel::query_builder::BindCollector;
+
+            let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+            let mut binds = RawBytesBindCollector::<Mysql>::new();
+            binds.push_bound_value::<Date, _>(&qen, &mut ()).unwrap();
+            binds.push_bound_value::<Text, _>(&qen, &mut ()).unwrap();
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use ::sqlx::encode::IsNull;
+    use ::sqlx::error::BoxDynError;
+    use ::sqlx::{Database, Decode, Encode, Type};
+
+    use crate::Zemen;
+
+    impl<DB> Type<DB> for Zemen
+    where
+        DB: Database,
+        time::Date: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <time::Date as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <time::Date as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB> Encode<'q, DB> for Zemen
+    where
+        DB: Database,
+        time::Date: Encode<'q, DB>,
+    {
+        /// Fails for dates beyond [`Zemen::MIN_GREGORIAN_INTEROP`] and
+        /// [`Zemen::MAX_GREGORIAN_INTEROP`].
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.try_to_gre()?.encode_by_ref(buf)
+        }
+    }
+
+    impl<'r, DB> Decode<'r, DB> for Zemen
+    where
+        DB: Database,
+        time::Date: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let date = <time::Date as Decode<DB>>::decode(value)?;
+            Ok(Zemen::from_date(&date))
+        }
+    }
+
+    #[cfg(all(test, feature = "sqlx-sqlite"))]
+    mod tests {
+        use ::sqlx::{Connection, SqliteConnection};
+
+        use super::*;
+        use crate::{error, Werh};
+
+        #[test]
+        fn test_sqlite_columns() -> Result<(), error::Error> {
+            futures::executor::block_on(sqlite_columns())
+        }
+
+        async fn sqlite_columns() -> Result<(), error::Error> {
+            let mut db = SqliteConnection::connect(":memory:").await.unwrap();
+            ::sqlx::query("CREATE TABLE letters (written DATE)")
+                .execute(&mut db)
+                .await
+                .unwrap();
+
+            let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+            ::sqlx::query("INSERT INTO letters VALUES (?)")
+                .bind(qen)
+                .execute(&mut db)
+                .await
+                .unwrap();
+
+            let (read, raw): (Zemen, String) =
+                ::sqlx::query_as("SELECT written, CAST(written AS TEXT) FROM letters")
+                    .fetch_one(&mut db)
+                    .await
+                    .unwrap();
+            assert_eq!(read, qen);
+            assert_eq!(raw, "2023-09-11");
+
+            let far = Zemen::from_eth_cal(20_000, Werh::Tir, 1)?;
+            assert!(::sqlx::query("INSERT INTO letters VALUES (?)")
+                .bind(far)
+                .execute(&mut db)
+                .await
+                .is_err());
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "postgres-types")]
+mod postgres {
+    use std::error::Error;
+
+    use ::bytes::BytesMut;
+    use ::postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+    use crate::{error, Zemen};
+
+    /// The Julian day number of 2000-01-01, the day 0 of `DATE`.
+    const POSTGRES_EPOCH_JDN: i32 = 2_451_545;
+
+    impl ToSql for Zemen {
+        fn to_sql(
+            &self,
+            _: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            // every `Zemen` is a Julian day number, so this doesn't overflow
+            let days = self.to_jdn() - POSTGRES_EPOCH_JDN;
+            out.extend_from_slice(&days.to_be_bytes());
+            Ok(IsNull::No)
+        }
+
+        accepts!(DATE);
+        to_sql_checked!();
+    }
+
+    impl<'a> FromSql<'a> for Zemen {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            let days = <[u8; 4]>::try_from(raw)
+                .map(i32::from_be_bytes)
+                .map_err(|_| error::Error::InvalidVariant("DATE", format!("{raw:?}")))?;
+            let jdn = days
+                .checked_add(POSTGRES_EPOCH_JDN)
+                .ok_or_else(|| error::Error::InvalidVariant("DATE", days.to_string()))?;
+
+            Ok(Zemen::from_jdn(jdn)?)
+        }
+
+        accepts!(DATE);
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::Werh;
+
+        #[test]
+        fn test_dates_round_trip() -> Result<(), error::Error> {
+            let epoch = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+            let mut out = BytesMut::new();
+            epoch.to_sql_checked(&Type::DATE, &mut out).unwrap();
+            assert_eq!(out.as_ref(), [0, 0, 0, 0]);
+
+            for qen in [
+                epoch - 1,
+                Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+                Zemen::MIN,
+                Zemen::MAX,
+            ] {
+                let mut out = BytesMut::new();
+                qen.to_sql_checked(&Type::DATE, &mut out).unwrap();
+                assert_eq!(Zemen::from_sql(&Type::DATE, &out).unwrap(), qen);
+            }
+            assert_eq!(Zemen::from_sql(&Type::DATE, &[0xff; 4]).unwrap(), epoch - 1);
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_invalid_dates_are_rejected() {
+            let mut out = BytesMut::new();
+            let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10).unwrap();
+            assert!(qen.to_sql_checked(&Type::TEXT, &mut out).is_err());
+            assert!(!<Zemen as FromSql>::accepts(&Type::TIMESTAMP));
+
+            // infinity and -infinity
+            assert!(Zemen::from_sql(&Type::DATE, &i32::MAX.to_be_bytes()).is_err());
+            assert!(Zemen::from_sql(&Type::DATE, &i32::MIN.to_be_bytes()).is_err());
+            assert!(Zemen::from_sql(&Type::DATE, &[0, 0, 0]).is_err());
+        }
+    }
+}
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm {
+    use ::sea_orm::sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr};
+    use ::sea_orm::{ActiveValue, ColIdx, DbErr, IntoActiveValue, QueryResult, TryGetError};
+    use ::sea_orm::{TryGetable, Value};
+
+    use super::Text;
+    use crate::Zemen;
+
+    impl From<Zemen> for Value {
+        /// # Panics
+        ///
+        /// Panics like [`Zemen::to_gre`] for dates beyond
+        /// [`Zemen::MIN_GREGORIAN_INTEROP`] and
+        /// [`Zemen::MAX_GREGORIAN_INTEROP`].
+        fn from(qen: Zemen) -> Self {
+            Value::TimeDate(Some(Box::new(qen.to_gre())))
+        }
+    }
+
+    impl Nullable for Zemen {
+        fn null() -> Value {
+            Value::TimeDate(None)
+        }
+    }
+
+    impl ValueType for Zemen {
+        fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+            match value {
+                Value::TimeDate(Some(date)) => Ok(Zemen::from_date(&date)),
+                _ => Err(ValueTypeErr),
+            }
+        }
+
+        fn type_name() -> String {
+            "Zemen".to_string()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::TimeDate
+        }
+
+        fn column_type() -> ColumnType {
+            ColumnType::Date
+        }
+    }
+
+    impl TryGetable for Zemen {
+        fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+            time::Date::try_get_by(res, index).map(|date| Zemen::from_date(&date))
+        }
+    }
+
+    impl IntoActiveValue<Zemen> for Zemen {
+        fn into_active_value(self) -> ActiveValue<Zemen> {
+            ActiveValue::Set(self)
+        }
+    }
+
+    impl From<Text> for Value {
+        fn from(text: Text) -> Self {
+            Value::String(Some(Box::new(text.0.to_string())))
+        }
+    }
+
+    impl Nullable for Text {
+        fn null() -> Value {
+            Value::String(None)
+        }
+    }
+
+    impl ValueType for Text {
+        fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+            match value {
+                Value::String(Some(text)) => text.parse().map(Text).map_err(|_| ValueTypeErr),
+                _ => Err(ValueTypeErr),
+            }
+        }
+
+        fn type_name() -> String {
+            "Text".to_string()
+        }
+
+        fn array_type() -> ArrayType {
+            ArrayType::String
+        }
+
+        fn column_type() -> ColumnType {
+            ColumnType::Text
+        }
+    }
+
+    impl TryGetable for Text {
+        fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+            let text = String::try_get_by(res, index)?;
+            text.parse().map(Text).map_err(|err: crate::error::Error| {
+                TryGetError::DbErr(DbErr::Type(err.to_string()))
+            })
+        }
+    }
+
+    impl IntoActiveValue<Text> for Text {
+        fn into_active_value(self) -> ActiveValue<Text> {
+            ActiveValue::Set(self)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{error, Werh};
+
+        #[test]
+        fn test_values() -> Result<(), error::Error> {
+            let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+            assert_eq!(
+                Value::from(qen),
+                Value::from(
+                    time::Date::from_calendar_date(2023, time::Month::September, 11).unwrap()
+                )
+            );
+            assert_eq!(Value::from(Text(qen + 1)), Value::from("2016-01-01"));
+            assert_eq!(Zemen::null(), Value::TimeDate(None));
+            assert_eq!(Text::null(), Value::String(None));
+
+            assert!(<Text as ValueType>::try_from(Value::from("2015-13-07")).is_err());
+            assert!(<Zemen as ValueType>::try_from(Value::from("2016-01-01")).is_err());
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_value_types() -> Result<(), error::Error> {
+            let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+
+            assert_eq!(Zemen::unwrap(qen.into()), qen);
+            assert_eq!(Text::unwrap(Text(qen).into()), Text(qen));
+            assert!(<Zemen as ValueType>::try_from(Text(qen).into()).is_err());
+            assert!(<Zemen as ValueType>::try_from(Zemen::null()).is_err());
+            assert_eq!(Zemen::column_type(), ColumnType::Date);
+            assert_eq!(Text::column_type(), ColumnType::Text);
+            assert_eq!(qen.into_active_value(), ActiveValue::Set(qen));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Days as a [`Stream`], for async pipelines that go through dates one by
+//! one, e.g. a backfill fetching a page per day.
+//!
+//! A [`DayStream`] yields the same days as [`Days`], always ready, so it can
+//! be driven with `StreamExt` adapters like `.then` without blocking an
+//! executor on an iterator.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use futures::{executor::block_on, StreamExt};
+//! # use zemen::{error, Zemen, Werh};
+//! async fn fetch(day: Zemen) -> String {
+//!     format!("page of {day}")
+//! }
+//!
+//! let start = Zemen::from_eth_cal(2015, Werh::Puagme, 5)?;
+//! let end = Zemen::from_eth_cal(2016, Werh::Meskerem, 2)?;
+//!
+//! let pages: Vec<String> = block_on(start.stream_days(&end).then(fetch).collect());
+//! assert_eq!(pages, ["page of 2015-13-05", "page of 2015-13-06", "page of 2016-01-01"]);
+//! # Ok::<(), error::Error>(())
+//! ```
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::{FusedStream, Stream};
+
+use crate::{Days, Zemen, ZemenRange};
+
+/// A [`Stream`] of consecutive days, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DayStream {
+    days: Days,
+}
+
+impl From<Days> for DayStream {
+    fn from(days: Days) -> Self {
+        DayStream { days }
+    }
+}
+
+impl Stream for DayStream {
+    type Item = Zemen;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.days.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.days.size_hint()
+    }
+}
+
+impl FusedStream for DayStream {
+    fn is_terminated(&self) -> bool {
+        self.days.len() == 0
+    }
+}
+
+impl Zemen {
+    /// Stream the dates from this date up to, but not including, `end`, like
+    /// [`Zemen::iter_days_until`].
+    pub fn stream_days(&self, end: &Zemen) -> DayStream {
+        self.iter_days_until(end).into()
+    }
+}
+
+impl ZemenRange {
+    /// Stream the dates of the range in order, like [`ZemenRange::iter`].
+    pub fn stream(&self) -> DayStream {
+        self.iter().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_streams_match_iterators() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 20)?;
+        let end = Zemen::from_eth_cal(2016, Werh::Tikimit, 3)?;
+
+        let streamed: Vec<Zemen> = block_on(start.stream_days(&end).collect());
+        assert_eq!(streamed, start.iter_days_until(&end).collect::<Vec<_>>());
+
+        let range = ZemenRange::new(start, end);
+        let mut stream = range.stream();
+        assert_eq!(
+            stream.size_hint(),
+            (range.len_days() as usize, Some(range.len_days() as usize))
+        );
+        assert_eq!(block_on(stream.by_ref().count()), range.len_days() as usize);
+        assert!(stream.is_terminated());
+
+        let mut empty = end.stream_days(&start);
+        assert!(empty.is_terminated());
+        assert_eq!(block_on(empty.next()), None);
+
+        Ok(())
+    }
+}
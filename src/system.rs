@@ -0,0 +1,193 @@
+//! Converting dates between calendars through their Julian day number.
+//!
+//! Every calendar implementing [`CalendarSystem`] can be converted to every
+//! other one with [`convert`]. The Ethiopian, Gregorian, Coptic, and Julian
+//! calendars are provided; others, e.g. the Islamic calendar, can be added by
+//! implementing the trait outside of the crate.
+
+use crate::{conversion, error, validator, Zemen};
+
+/// A calendar, bridged to the others by the Julian day number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, system::{self, CalendarSystem, Ethiopian}, Zemen, Werh};
+/// /// Days counted from 1 January 1970, as in Unix time.
+/// struct UnixDays;
+///
+/// impl CalendarSystem for UnixDays {
+///     type Date = i32;
+///
+///     fn to_jdn(date: &i32) -> Result<i32, error::Error> {
+///         Ok(date + 2_440_588)
+///     }
+///
+///     fn from_jdn(jdn: i32) -> Result<i32, error::Error> {
+///         Ok(jdn - 2_440_588)
+///     }
+/// }
+///
+/// let qen = Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?;
+/// assert_eq!(system::convert::<Ethiopian, UnixDays>(&qen)?, 0);
+/// # Ok::<(), error::Error>(())
+/// ```
+pub trait CalendarSystem {
+    /// How a date of the calendar is written down.
+    type Date;
+
+    /// Get the Julian day number of `date`, failing if the date doesn't
+    /// exist.
+    fn to_jdn(date: &Self::Date) -> Result<i32, error::Error>;
+
+    /// Get the date of the Julian day number `jdn`.
+    fn from_jdn(jdn: i32) -> Result<Self::Date, error::Error>;
+}
+
+/// The Ethiopian calendar, with dates as [`Zemen`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Ethiopian;
+
+impl CalendarSystem for Ethiopian {
+    type Date = Zemen;
+
+    fn to_jdn(date: &Zemen) -> Result<i32, error::Error> {
+        Ok(date.to_jdn())
+    }
+
+    fn from_jdn(jdn: i32) -> Result<Zemen, error::Error> {
+        Zemen::from_jdn(jdn)
+    }
+}
+
+/// The proleptic Gregorian calendar, with dates as `(year, month, day)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Gregorian;
+
+impl CalendarSystem for Gregorian {
+    type Date = (i32, u8, u8);
+
+    fn to_jdn(&(year, month, day): &(i32, u8, u8)) -> Result<i32, error::Error> {
+        validator::gre::is_valid_date(year, month, day)?;
+        Ok(conversion::gre_to_jdn(year, month, day))
+    }
+
+    fn from_jdn(jdn: i32) -> Result<(i32, u8, u8), error::Error> {
+        Ok(conversion::jdn_to_gre(jdn))
+    }
+}
+
+/// The Coptic calendar, with dates as `(year, month, day)`.
+///
+/// It has the same months as the Ethiopian calendar, but counts its years
+/// from 284, 276 years after the Ethiopian calendar.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Coptic;
+
+/// The Coptic year 1 is the Ethiopian year 277.
+const COPTIC_YEAR_OFFSET: i32 = 276;
+
+impl CalendarSystem for Coptic {
+    type Date = (i32, u8, u8);
+
+    fn to_jdn(&(year, month, day): &(i32, u8, u8)) -> Result<i32, error::Error> {
+        let year = year + COPTIC_YEAR_OFFSET;
+        error::is_in_range(month as i32, 1, 13, "month")?;
+        validator::is_valid_date(year, month, day)?;
+
+        Ok(conversion::eth_to_jdn(year, month as i32, day as i32))
+    }
+
+    fn from_jdn(jdn: i32) -> Result<(i32, u8, u8), error::Error> {
+        let (year, month, day) = conversion::jdn_to_eth(jdn);
+        Ok((year - COPTIC_YEAR_OFFSET, month, day))
+    }
+}
+
+/// The proleptic Julian calendar, with dates as `(year, month, day)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Julian;
+
+impl CalendarSystem for Julian {
+    type Date = (i32, u8, u8);
+
+    fn to_jdn(&(year, month, day): &(i32, u8, u8)) -> Result<i32, error::Error> {
+        validator::julian::is_valid_date(year, month, day)?;
+        Ok(conversion::julian_to_jdn(year, month, day))
+    }
+
+    fn from_jdn(jdn: i32) -> Result<(i32, u8, u8), error::Error> {
+        Ok(conversion::jdn_to_julian(jdn))
+    }
+}
+
+/// Convert `date` from the calendar `F` to the calendar `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, system::{self, Coptic, Ethiopian, Gregorian, Julian}, Zemen, Werh};
+/// let qen = system::convert::<Gregorian, Ethiopian>(&(2023, 1, 18))?;
+/// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+///
+/// assert_eq!(system::convert::<Ethiopian, Coptic>(&qen)?, (1739, 5, 10));
+/// assert_eq!(system::convert::<Gregorian, Julian>(&(2023, 1, 18))?, (2023, 1, 5));
+/// assert!(system::convert::<Julian, Gregorian>(&(2023, 2, 29)).is_err());
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn convert<F: CalendarSystem, T: CalendarSystem>(
+    date: &F::Date,
+) -> Result<T::Date, error::Error> {
+    T::from_jdn(F::to_jdn(date)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_round_trips() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(1, Werh::Meskerem, 1)?.to_jdn();
+
+        for jdn in (start..start + 1_000_000).step_by(37) {
+            let gregorian = Gregorian::from_jdn(jdn)?;
+            let coptic = Coptic::from_jdn(jdn)?;
+            let julian = Julian::from_jdn(jdn)?;
+
+            assert_eq!(Gregorian::to_jdn(&gregorian)?, jdn);
+            assert_eq!(Coptic::to_jdn(&coptic)?, jdn);
+            assert_eq!(Julian::to_jdn(&julian)?, jdn);
+            assert_eq!(convert::<Julian, Ethiopian>(&julian)?.to_jdn(), jdn);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_known_dates() -> Result<(), error::Error> {
+        // the Gregorian reform: 4 October 1582 was followed by 15 October
+        assert_eq!(
+            convert::<Julian, Gregorian>(&(1582, 10, 4))?,
+            (1582, 10, 14)
+        );
+        assert_eq!(
+            Julian::to_jdn(&(1582, 10, 4))? + 1,
+            Gregorian::to_jdn(&(1582, 10, 15))?
+        );
+
+        // Nayrouz, the Coptic new year, is Enkutatash
+        let enkutatash = Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?;
+        assert_eq!(convert::<Ethiopian, Coptic>(&enkutatash)?, (1740, 1, 1));
+        assert_eq!(convert::<Ethiopian, Gregorian>(&enkutatash)?, (2023, 9, 12));
+
+        assert!(Coptic::to_jdn(&(1739, 13, 6)).is_ok());
+        assert!(Coptic::to_jdn(&(1740, 13, 6)).is_err());
+        assert!(Coptic::to_jdn(&(1740, 0, 1)).is_err());
+        assert!(Coptic::to_jdn(&(1739, 14, 1)).is_err());
+        assert!(Gregorian::to_jdn(&(2023, 2, 29)).is_err());
+        assert!(Julian::to_jdn(&(1900, 2, 29)).is_ok());
+
+        Ok(())
+    }
+}
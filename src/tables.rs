@@ -0,0 +1,322 @@
+//! Prebuilt conversion tables, e.g. for POS terminals and other offline
+//! devices that would rather look dates up than compute them.
+//!
+//! [`export_binary`] writes a table for a range of dates, and [`Table`] loads
+//! one back. Looking a date up in either direction reads two entries and
+//! doesn't depend on the size of the table.
+//!
+//! # Format
+//!
+//! Every number is little-endian.
+//!
+//! ```txt
+//! offset  size      field
+//! 0       4         magic, the bytes `ZMTB`
+//! 4       1         version, 1
+//! 5       3         reserved, zero
+//! 8       4         i32: Julian day number of the first day, J
+//! 12      4         u32: number of days, N
+//! 16      4         i32: first Ethiopian year, E
+//! 20      4         u32: number of Ethiopian years, EY
+//! 24      4         i32: first Gregorian year, G
+//! 28      4         u32: number of Gregorian years, GY
+//! 32      4 * EY    i32: index of Meskerem 1 of every year from E on
+//! ...     48 * GY   i32: index of the 1st of every month of every year from G on
+//! ...     12 * N    one record per day from J on
+//! ```
+//!
+//! A record is the Ethiopian year (i32), month (u8), and day (u8), followed by
+//! the Gregorian year (i32), month (u8), and day (u8) of the same day.
+//!
+//! To look a date up, get the index of the first day of its month from the
+//! year tables, add the day of the month minus one (and, for Ethiopian
+//! dates, 30 days for every month before it), and read that record. Indexes
+//! of days outside of the table are below 0 or at least N. A record that
+//! doesn't hold the date that was looked up means the date doesn't exist,
+//! e.g. the 30th of February.
+
+use crate::{conversion, error, Zemen, ZemenRange};
+
+const MAGIC: &[u8; 4] = b"ZMTB";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 32;
+const RECORD_LEN: usize = 12;
+
+type Ymd = (i32, u8, u8);
+
+/// Write the conversion table of every day of `range`, in the format
+/// described in the [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, tables::{self, Table}, Zemen, ZemenRange, Werh};
+/// let range = ZemenRange::new(
+///     Zemen::from_eth_cal(2015, Werh::Meskerem, 1)?,
+///     Zemen::from_eth_cal(2016, Werh::Puagme, 5)?,
+/// );
+/// let bytes = tables::export_binary(&range);
+///
+/// let table = Table::from_bytes(&bytes)?;
+/// assert_eq!(table.to_gre(&Zemen::from_eth_cal(2015, Werh::Tir, 10)?), Some((2023, 1, 18)));
+/// assert_eq!(table.to_eth(2023, 1, 18), Some(Zemen::from_eth_cal(2015, Werh::Tir, 10)?));
+/// # Ok::<(), error::Error>(())
+/// ```
+pub fn export_binary(range: &ZemenRange) -> Vec<u8> {
+    let days: Vec<_> = range.iter().collect();
+    let first_jdn = range.start().to_jdn();
+
+    let (eth_years, gre_years) = match (days.first(), days.last()) {
+        (Some(first), Some(last)) => {
+            let (first_gre, last_gre) = (first.to_jdn(), last.to_jdn());
+            (
+                first.year()..last.year() + 1,
+                conversion::jdn_to_gre(first_gre).0..conversion::jdn_to_gre(last_gre).0 + 1,
+            )
+        }
+        _ => (0..0, 0..0),
+    };
+
+    let mut bytes = Vec::with_capacity(
+        HEADER_LEN + 4 * eth_years.len() + 48 * gre_years.len() + RECORD_LEN * days.len(),
+    );
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[VERSION, 0, 0, 0]);
+    bytes.extend_from_slice(&first_jdn.to_le_bytes());
+    bytes.extend_from_slice(&(days.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&eth_years.start.to_le_bytes());
+    bytes.extend_from_slice(&(eth_years.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&gre_years.start.to_le_bytes());
+    bytes.extend_from_slice(&(gre_years.len() as u32).to_le_bytes());
+
+    for year in eth_years {
+        let index = conversion::eth_to_jdn(year, 1, 1) - first_jdn;
+        bytes.extend_from_slice(&index.to_le_bytes());
+    }
+    for year in gre_years {
+        for month in 1..=12 {
+            let index = conversion::gre_to_jdn(year, month, 1) - first_jdn;
+            bytes.extend_from_slice(&index.to_le_bytes());
+        }
+    }
+
+    for qen in days {
+        let (year, month, day) = qen.to_calendar_date();
+        let (gre_year, gre_month, gre_day) = conversion::jdn_to_gre(qen.to_jdn());
+
+        bytes.extend_from_slice(&year.to_le_bytes());
+        bytes.extend_from_slice(&[month as u8, day]);
+        bytes.extend_from_slice(&gre_year.to_le_bytes());
+        bytes.extend_from_slice(&[gre_month, gre_day]);
+    }
+
+    bytes
+}
+
+/// A conversion table loaded from [`export_binary`]'s output.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Table {
+    first_eth_year: i32,
+    eth_years: Vec<i32>,
+    first_gre_year: i32,
+    gre_months: Vec<i32>,
+    records: Vec<(Zemen, Ymd)>,
+}
+
+impl Table {
+    /// Load a table, failing if `bytes` isn't a version 1 table or is cut
+    /// short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::tables::Table;
+    /// assert!(Table::from_bytes(b"not a table").is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::Error> {
+        let invalid = |reason: &str| error::Error::InvalidVariant("date table", reason.to_string());
+
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(invalid("missing header"));
+        }
+        if bytes[4] != VERSION {
+            return Err(invalid(&format!("version {}", bytes[4])));
+        }
+
+        let mut reader = Reader { bytes, at: 8 };
+        let _first_jdn = reader.i32()?;
+        let days = reader.u32()? as usize;
+        let first_eth_year = reader.i32()?;
+        let eth_count = reader.u32()? as usize;
+        let first_gre_year = reader.i32()?;
+        let gre_count = reader.u32()? as usize;
+
+        let expected = eth_count
+            .checked_mul(4)
+            .zip(gre_count.checked_mul(48))
+            .zip(days.checked_mul(RECORD_LEN))
+            .and_then(|((eth, gre), records)| HEADER_LEN.checked_add(eth + gre + records));
+        if expected != Some(bytes.len()) {
+            return Err(invalid("wrong length"));
+        }
+
+        let eth_years = (0..eth_count)
+            .map(|_| reader.i32())
+            .collect::<Result<_, _>>()?;
+        let gre_months = (0..gre_count * 12)
+            .map(|_| reader.i32())
+            .collect::<Result<_, _>>()?;
+        let records = (0..days)
+            .map(|_| {
+                let (year, month, day) = (reader.i32()?, reader.u8()?, reader.u8()?);
+                let qen = Zemen::new(year, month, day)?;
+                Ok((qen, (reader.i32()?, reader.u8()?, reader.u8()?)))
+            })
+            .collect::<Result<_, error::Error>>()?;
+
+        Ok(Table {
+            first_eth_year,
+            eth_years,
+            first_gre_year,
+            gre_months,
+            records,
+        })
+    }
+
+    /// Get the number of days in the table.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if the table has no days.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Look up the Gregorian date of `qen`, as `(year, month, day)`, or
+    /// `None` if it isn't in the table.
+    pub fn to_gre(&self, qen: &Zemen) -> Option<Ymd> {
+        let (year, month, day) = qen.to_calendar_date();
+        let start = lookup(&self.eth_years, year as i64 - self.first_eth_year as i64)?;
+        let index = start as i64 + 30 * (month as i64 - 1) + day as i64 - 1;
+
+        self.record(index)
+            .filter(|(found, _)| found == qen)
+            .map(|(_, gre)| gre)
+    }
+
+    /// Look up the Ethiopian date of a Gregorian date, or `None` if it isn't
+    /// in the table or doesn't exist.
+    pub fn to_eth(&self, year: i32, month: u8, day: u8) -> Option<Zemen> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+
+        let row = (year as i64 - self.first_gre_year as i64) * 12 + month as i64 - 1;
+        let index = lookup(&self.gre_months, row)? as i64 + day as i64 - 1;
+
+        self.record(index)
+            .filter(|(_, gre)| *gre == (year, month, day))
+            .map(|(qen, _)| qen)
+    }
+
+    fn record(&self, index: i64) -> Option<(Zemen, Ymd)> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.records.get(index))
+            .copied()
+    }
+}
+
+fn lookup(table: &[i32], index: i64) -> Option<i32> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| table.get(index))
+        .copied()
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    at: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], error::Error> {
+        let bytes = self
+            .bytes
+            .get(self.at..self.at + N)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| error::Error::InvalidVariant("date table", "cut short".to_string()))?;
+        self.at += N;
+
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, error::Error> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn i32(&mut self) -> Result<i32, error::Error> {
+        Ok(i32::from_le_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, error::Error> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Werh;
+
+    #[test]
+    fn test_lookups_match_the_arithmetic() -> Result<(), error::Error> {
+        let range = ZemenRange::exclusive(
+            Zemen::from_eth_cal(2010, Werh::Tir, 17)?,
+            Zemen::from_eth_cal(2018, Werh::Hedar, 3)?,
+        );
+        let table = Table::from_bytes(&export_binary(&range))?;
+        assert_eq!(table.len(), range.len_days() as usize);
+
+        for qen in range.iter() {
+            let gre = conversion::jdn_to_gre(qen.to_jdn());
+
+            assert_eq!(table.to_gre(&qen), Some(gre));
+            assert_eq!(table.to_eth(gre.0, gre.1, gre.2), Some(qen));
+        }
+
+        assert_eq!(table.to_gre(range.end()), None);
+        assert_eq!(table.to_gre(&(*range.start() - 1)), None);
+        assert_eq!(table.to_eth(2020, 2, 30), None);
+        assert_eq!(table.to_eth(2020, 13, 1), None);
+        assert_eq!(table.to_eth(1990, 1, 1), None);
+        assert_eq!(
+            table.to_gre(&Zemen::from_eth_cal(2015, Werh::Puagme, 6)?),
+            Some((2023, 9, 11))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_broken_tables_are_rejected() -> Result<(), error::Error> {
+        let range = ZemenRange::new(
+            Zemen::from_eth_cal(2015, Werh::Tir, 1)?,
+            Zemen::from_eth_cal(2015, Werh::Tir, 30)?,
+        );
+        let bytes = export_binary(&range);
+
+        assert!(Table::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Table::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+
+        let mut newer = bytes.clone();
+        newer[4] = 2;
+        assert!(Table::from_bytes(&newer).is_err());
+
+        let empty = ZemenRange::exclusive(*range.start(), *range.start());
+        assert!(Table::from_bytes(&export_binary(&empty))?.is_empty());
+
+        Ok(())
+    }
+}
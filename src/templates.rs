@@ -0,0 +1,145 @@
+//! Template filters for rendering `Zemen` dates server side.
+//!
+//! Dates reach templates as `YYYY-MM-DD` strings (the `Display` form of
+//! `Zemen`). Two filters are provided for every supported engine:
+//!
+//! - `eth_format(pattern)` formats the date with [`Zemen::format`].
+//! - `eth_humanize` describes the date relative to today with
+//!   [`humanize::relative`].
+//! - `eth_label(locale)` writes the date out for screen readers with
+//!   [`Zemen::accessible_label`], in Amharic unless `locale` is given.
+//!
+//! Enable the `minijinja` or `tera` feature to get the registration helper
+//! for that engine.
+
+use crate::{error, humanize, Zemen};
+
+fn eth_format(value: &str, pattern: &str) -> Result<String, error::Error> {
+    let qen: Zemen = value.parse()?;
+    Ok(qen.format(pattern))
+}
+
+fn eth_humanize(value: &str) -> Result<String, error::Error> {
+    let qen: Zemen = value.parse()?;
+    Ok(humanize::relative(&qen, &Zemen::today()))
+}
+
+fn eth_label(value: &str, locale: Option<&str>) -> Result<String, error::Error> {
+    let qen: Zemen = value.parse()?;
+    let locale = locale.map(str::parse).transpose()?.unwrap_or_default();
+    Ok(qen.accessible_label(locale))
+}
+
+/// Registers the `eth_format`, `eth_humanize`, and `eth_label` filters on a
+/// MiniJinja environment.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut env = minijinja::Environment::new();
+/// zemen::templates::register_minijinja(&mut env);
+///
+/// let out = env
+///     .render_str("{{ qen | eth_format('MMM D YYYY') }}", minijinja::context! { qen => "2015-05-10" })
+///     .unwrap();
+/// assert_eq!(out, "ጥር 10 2015");
+/// ```
+#[cfg(feature = "minijinja")]
+pub fn register_minijinja(env: &mut minijinja::Environment) {
+    use minijinja::{Error, ErrorKind};
+
+    let to_minijinja = |err: error::Error| Error::new(ErrorKind::InvalidOperation, err.to_string());
+
+    env.add_filter("eth_format", move |value: String, pattern: String| {
+        eth_format(&value, &pattern).map_err(to_minijinja)
+    });
+    env.add_filter("eth_humanize", move |value: String| {
+        eth_humanize(&value).map_err(to_minijinja)
+    });
+    env.add_filter("eth_label", move |value: String, locale: Option<String>| {
+        eth_label(&value, locale.as_deref()).map_err(to_minijinja)
+    });
+}
+
+/// Registers the `eth_format`, `eth_humanize`, and `eth_label` filters on a
+/// Tera instance.
+///
+/// Tera only supports named filter arguments, so the pattern is passed as
+/// `eth_format(pattern="...")`, and the locale as `eth_label(locale="...")`.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut tera = tera::Tera::default();
+/// zemen::templates::register_tera(&mut tera);
+///
+/// let mut context = tera::Context::new();
+/// context.insert("qen", "2015-05-10");
+///
+/// let out = tera
+///     .render_str("{{ qen | eth_format(pattern='MMM D YYYY') }}", &context)
+///     .unwrap();
+/// assert_eq!(out, "ጥር 10 2015");
+/// ```
+#[cfg(feature = "tera")]
+pub fn register_tera(tera: &mut tera::Tera) {
+    use std::collections::HashMap;
+    use tera::{Error, Value};
+
+    fn as_str<'a>(value: &'a Value, filter: &str) -> tera::Result<&'a str> {
+        value
+            .as_str()
+            .ok_or_else(|| Error::msg(format!("`{}` expects a date string", filter)))
+    }
+
+    tera.register_filter(
+        "eth_format",
+        |value: &Value, args: &HashMap<String, Value>| {
+            let value = as_str(value, "eth_format")?;
+            let pattern = args
+                .get("pattern")
+                .and_then(Value::as_str)
+                .ok_or_else(|| Error::msg("`eth_format` requires a `pattern` argument"))?;
+
+            eth_format(value, pattern)
+                .map(Value::String)
+                .map_err(Error::msg)
+        },
+    );
+    tera.register_filter(
+        "eth_humanize",
+        |value: &Value, _: &HashMap<String, Value>| {
+            let value = as_str(value, "eth_humanize")?;
+
+            eth_humanize(value).map(Value::String).map_err(Error::msg)
+        },
+    );
+    tera.register_filter(
+        "eth_label",
+        |value: &Value, args: &HashMap<String, Value>| {
+            let value = as_str(value, "eth_label")?;
+            let locale = args.get("locale").and_then(Value::as_str);
+
+            eth_label(value, locale)
+                .map(Value::String)
+                .map_err(Error::msg)
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filters_reject_invalid_dates() {
+        assert_eq!(eth_format("2015-05-10", "D/M/YYYY").unwrap(), "10/05/2015");
+        assert!(eth_format("2015-14-10", "D/M/YYYY").is_err());
+        assert!(eth_humanize("not a date").is_err());
+        assert_eq!(
+            eth_label("2015-05-13", Some("en")).unwrap(),
+            "Saturday 13 Tir 2015"
+        );
+        assert!(eth_label("2015-05-13", Some("fr")).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Subsystems whose APIs aren't settled yet.
+//!
+//! Big new subsystems first ship behind an `unstable-<name>` cargo feature,
+//! so they can be tried out before their API is committed to. Every
+//! `unstable-<name>` feature also enables the `unstable` feature, and the
+//! items it gates may change or disappear in any release, including patch
+//! releases. Building the crate with any of them enabled prints a warning.
+//!
+//! Current unstable features:
+//!
+//! - `unstable-step` implements `std::iter::Step` for `Zemen`, so date ranges
+//!   like `start..end` can be iterated over. It needs a nightly compiler,
+//!   [`Zemen::until`](crate::Zemen::until) is the stable equivalent.
+//! - `unstable-holidays` adds the `holiday` module, with the public holidays
+//!   of Ethiopia and providers listing their dates, and the `greetings`
+//!   module, with the greetings sent out on them.
+//!
+//! Once a subsystem is stable its feature becomes a no-op alias, and is
+//! removed in the next breaking release.
+//!
+//! Use [`FEATURES`] or [`is_enabled`] to check, at runtime, which unstable
+//! features a binary was built with, e.g. to log them at start up.
+
+/// Names of the unstable features the crate was built with.
+pub const FEATURES: &[&str] = &[
+    #[cfg(feature = "unstable")]
+    "unstable",
+    #[cfg(feature = "unstable-step")]
+    "unstable-step",
+    #[cfg(feature = "unstable-holidays")]
+    "unstable-holidays",
+];
+
+/// Returns `true` if the crate was built with the unstable feature `name`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(zemen::unstable::is_enabled("unstable"), cfg!(feature = "unstable"));
+/// assert!(!zemen::unstable::is_enabled("time"));
+/// ```
+pub fn is_enabled(name: &str) -> bool {
+    FEATURES.contains(&name)
+}
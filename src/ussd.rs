@@ -0,0 +1,76 @@
+//! A terse profile for USSD menus and SMS, e.g. `21 Tir 2016`.
+//!
+//! USSD menus can't render Ethiopic script and have tight length budgets, so
+//! the profile only uses ASCII letters, digits, and spaces, all of which are
+//! in the GSM-7 alphabet. The month is written as the first three letters of
+//! its latin name, which are unique, so the day and month can never be
+//! mistaken for each other.
+
+use crate::{error, Werh, Zemen};
+
+pub(crate) fn format(qen: &Zemen) -> String {
+    format!(
+        "{:02} {} {:04}",
+        qen.day(),
+        &qen.month().latin_name()[..3],
+        qen.year()
+    )
+}
+
+pub(crate) fn parse(s: &str) -> Result<Zemen, error::Error> {
+    let invalid = || error::Error::InvalidVariant("USSD date", s.to_string());
+
+    let mut parts = s.split_whitespace();
+    let (Some(day), Some(month), Some(year), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let day: u8 = day.parse().map_err(|_| invalid())?;
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let month = Werh::iter()
+        .find(|werh| {
+            let name = werh.latin_name();
+            month.eq_ignore_ascii_case(&name[..3]) || month.eq_ignore_ascii_case(name)
+        })
+        .ok_or_else(invalid)?;
+
+    Zemen::from_eth_cal(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ussd_round_trip() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Meskerem, 1)?;
+
+        for days in 0..400 {
+            let qen = start + days;
+            let formatted = format(&qen);
+
+            assert_eq!(formatted.len(), Zemen::USSD_MAX_LEN);
+            assert!(formatted
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == ' '));
+            assert_eq!(parse(&formatted)?, qen);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ussd_is_lenient_about_case_and_spacing() -> Result<(), error::Error> {
+        let qen = Zemen::from_eth_cal(2016, Werh::Puagme, 3)?;
+
+        assert_eq!(parse("3 pua 2016")?, qen);
+        assert_eq!(parse(" 03  PUAGME 2016 ")?, qen);
+        assert!(parse("03 Pu 2016").is_err());
+        assert!(parse("2016 Pua 03").is_err());
+        assert!(parse("07 Pua 2016").is_err());
+
+        Ok(())
+    }
+}
@@ -1,50 +1,41 @@
 use crate::error::Error;
 
-pub fn is_leap_year(year: i32) -> bool {
-    year % 4 == 3
+pub const fn is_leap_year(year: i32) -> bool {
+    crate::leap::leap_rule().applies_to(year)
 }
 
 pub fn is_valid_date(year: i32, month: u8, day: u8) -> Result<(), Error> {
-    if is_leap_year(year) {
-        if month == 13 && day > 6 {
-            return Err(Error::InvalidRange {
-                name: "day",
-                given: day as i32,
-                min: 1,
-                max: 6,
-            });
-        }
-    } else if month == 13 && day > 5 {
-        return Err(Error::InvalidRange {
-            name: "day",
-            given: day as i32,
-            min: 1,
-            max: 5,
-        });
-    }
-
-    if month > 13 {
-        return Err(Error::InvalidRange {
-            name: "month",
-            given: month as i32,
-            min: 1,
-            max: 30,
-        });
-    }
-
-    if day > 30 {
-        return Err(Error::InvalidRange {
-            name: "day",
-            given: day as i32,
-            min: 1,
-            max: 30,
-        });
-    }
-
-    Ok(())
+    crate::error::is_in_range(month as i32, 1, 13, "month")?;
+    crate::error::is_in_range(
+        day as i32,
+        1,
+        days_in_month(year, month) as i32,
+        day_of(month),
+    )
+}
+
+/// The name of the day in errors, saying which month bounds it, e.g. `day of
+/// Puagme`.
+pub const fn day_of(month: u8) -> &'static str {
+    match month {
+        1 => "day of Meskerem",
+        2 => "day of Tikimit",
+        3 => "day of Hedar",
+        4 => "day of Tahasass",
+        5 => "day of Tir",
+        6 => "day of Yekatit",
+        7 => "day of Megabit",
+        8 => "day of Miyazia",
+        9 => "day of Ginbot",
+        10 => "day of Sene",
+        11 => "day of Hamle",
+        12 => "day of Nehase",
+        13 => "day of Puagme",
+        _ => "day",
+    }
 }
 
-pub fn days_in_year(year: i32) -> u16 {
+pub const fn days_in_year(year: i32) -> u16 {
     if is_leap_year(year) {
         366
     } else {
@@ -52,25 +43,99 @@ pub fn days_in_year(year: i32) -> u16 {
     }
 }
 
-#[cfg(not(feature = "time"))]
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        13 if is_leap_year(year) => 6,
+        13 => 5,
+        _ => 30,
+    }
+}
+
 pub mod gre {
+    use crate::error::Error;
 
-    pub fn is_leap_year(year: u64) -> bool {
+    pub fn is_leap_year(year: i32) -> bool {
         (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
     }
 
-    pub fn days_in_year(year: u64) -> u16 {
+    #[cfg(not(feature = "time"))]
+    pub fn days_in_year(year: i32) -> u16 {
         if is_leap_year(year) {
             366
         } else {
             365
         }
     }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    pub fn is_valid_date(year: i32, month: u8, day: u8) -> Result<(), Error> {
+        crate::error::is_in_range(month as i32, 1, 12, "month")?;
+        crate::error::is_in_range(
+            day as i32,
+            1,
+            days_in_month(year, month) as i32,
+            day_of(month),
+        )
+    }
+
+    /// The name of the day in errors, like [`super::day_of`] for the
+    /// Gregorian and Julian months, e.g. `day of February`.
+    pub const fn day_of(month: u8) -> &'static str {
+        match month {
+            1 => "day of January",
+            2 => "day of February",
+            3 => "day of March",
+            4 => "day of April",
+            5 => "day of May",
+            6 => "day of June",
+            7 => "day of July",
+            8 => "day of August",
+            9 => "day of September",
+            10 => "day of October",
+            11 => "day of November",
+            12 => "day of December",
+            _ => "day",
+        }
+    }
+}
+
+pub mod julian {
+    use crate::error::Error;
+
+    pub fn is_leap_year(year: i32) -> bool {
+        year.rem_euclid(4) == 0
+    }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            _ => super::gre::days_in_month(1, month),
+        }
+    }
+
+    pub fn is_valid_date(year: i32, month: u8, day: u8) -> Result<(), Error> {
+        crate::error::is_in_range(month as i32, 1, 12, "month")?;
+        crate::error::is_in_range(
+            day as i32,
+            1,
+            days_in_month(year, month) as i32,
+            super::gre::day_of(month),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{days_in_year, is_leap_year, is_valid_date};
+    use super::{days_in_month, days_in_year, is_leap_year, is_valid_date};
+    use crate::error::Error;
 
     #[test]
     fn validator_leap_year() {
@@ -88,6 +153,21 @@ mod tests {
         assert_ne!(days_in_year(2001), 366);
     }
 
+    #[test]
+    fn validator_days_in_month() {
+        assert_eq!(days_in_month(2001, 1), 30);
+        assert_eq!(days_in_month(2001, 13), 5);
+        assert_eq!(days_in_month(2003, 13), 6);
+    }
+
+    #[test]
+    fn validator_gre_valid_date() {
+        super::gre::is_valid_date(2000, 2, 29).unwrap();
+        super::gre::is_valid_date(1900, 2, 29).unwrap_err();
+        super::gre::is_valid_date(2023, 4, 31).unwrap_err();
+        super::gre::is_valid_date(2023, 13, 1).unwrap_err();
+    }
+
     #[test]
     fn validator_valid_date() {
         let (year, month, day) = (2000, 13, 5);
@@ -105,4 +185,29 @@ mod tests {
         let (year, month, day) = (2001, 13, 6);
         is_valid_date(year, month, day).unwrap_err();
     }
+
+    #[test]
+    fn validator_error_bounds() {
+        let bounds = |year, month, day| match is_valid_date(year, month, day) {
+            Err(Error::InvalidRange {
+                name,
+                given,
+                min,
+                max,
+            }) => (name, given, min, max),
+            other => panic!("unexpected {other:?}"),
+        };
+
+        assert_eq!(bounds(2001, 14, 1), ("month", 14, 1, 13));
+        assert_eq!(bounds(2001, 0, 1), ("month", 0, 1, 13));
+        assert_eq!(bounds(2001, 5, 31), ("day of Tir", 31, 1, 30));
+        assert_eq!(bounds(2001, 1, 0), ("day of Meskerem", 0, 1, 30));
+        assert_eq!(bounds(2001, 13, 6), ("day of Puagme", 6, 1, 5));
+        assert_eq!(bounds(2003, 13, 7), ("day of Puagme", 7, 1, 6));
+
+        assert_eq!(
+            is_valid_date(2001, 13, 6).unwrap_err().to_string(),
+            "With the provided date: day of Puagme must be in the range 1..=5, but given 6."
+        );
+    }
 }
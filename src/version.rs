@@ -0,0 +1,126 @@
+//! What the crate is and how it converts dates, for logging.
+
+use std::fmt;
+
+use crate::{conversion, zemen::UNIX_EPOCH_JDN, Zemen};
+
+/// The version of the crate, the algorithm it converts dates with, and the
+/// constants and ranges that go into it, see [`VERSION_INFO`].
+///
+/// Services can log it at startup, so conversions that disagree across
+/// deployments can be traced to the build that made them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{Zemen, VERSION_INFO};
+/// assert_eq!(VERSION_INFO.algorithm(), "Beyene-Kudlek");
+/// assert_eq!(VERSION_INFO.ethiopian_epoch_jdn(), 1_724_221);
+/// assert_eq!(VERSION_INFO.range(), (Zemen::MIN, Zemen::MAX));
+///
+/// println!("{VERSION_INFO}");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VersionInfo {
+    version: &'static str,
+    algorithm: &'static str,
+    ethiopian_epoch_jdn: i32,
+    unix_epoch_jdn: i32,
+    range: (Zemen, Zemen),
+    gregorian_interop: (Zemen, Zemen),
+}
+
+/// The [`VersionInfo`] of this build of the crate.
+pub const VERSION_INFO: VersionInfo = VersionInfo {
+    version: env!("CARGO_PKG_VERSION"),
+    algorithm: "Beyene-Kudlek",
+    ethiopian_epoch_jdn: conversion::eth_to_jdn(1, 1, 1),
+    unix_epoch_jdn: UNIX_EPOCH_JDN,
+    range: (Zemen::MIN, Zemen::MAX),
+    gregorian_interop: (Zemen::MIN_GREGORIAN_INTEROP, Zemen::MAX_GREGORIAN_INTEROP),
+};
+
+impl VersionInfo {
+    /// Get the version of the crate, e.g. `0.1.7`.
+    pub const fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Get the name of the algorithm converting between Julian day numbers
+    /// and Ethiopian dates.
+    pub const fn algorithm(&self) -> &'static str {
+        self.algorithm
+    }
+
+    /// Get the Julian day number of መስከረም 1 of the year 1, the Ethiopian
+    /// epoch.
+    pub const fn ethiopian_epoch_jdn(&self) -> i32 {
+        self.ethiopian_epoch_jdn
+    }
+
+    /// Get the Julian day number of the Unix epoch, 1970-01-01, which
+    /// [`Zemen::to_epoch_day`] counts from.
+    pub const fn unix_epoch_jdn(&self) -> i32 {
+        self.unix_epoch_jdn
+    }
+
+    /// Get the earliest and latest dates a `Zemen` can hold,
+    /// [`Zemen::MIN`] and [`Zemen::MAX`].
+    pub const fn range(&self) -> (Zemen, Zemen) {
+        self.range
+    }
+
+    /// Get the earliest and latest dates that convert to and from
+    /// `time::Date`, [`Zemen::MIN_GREGORIAN_INTEROP`] and
+    /// [`Zemen::MAX_GREGORIAN_INTEROP`].
+    pub const fn gregorian_interop(&self) -> (Zemen, Zemen) {
+        self.gregorian_interop
+    }
+}
+
+impl fmt::Display for VersionInfo {
+    /// Writes everything on one line, e.g. for a log.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "zemen {} ({}, epoch jdn {}, unix epoch jdn {}, range {}..={}, gregorian interop {}..={})",
+            self.version,
+            self.algorithm,
+            self.ethiopian_epoch_jdn,
+            self.unix_epoch_jdn,
+            self.range.0,
+            self.range.1,
+            self.gregorian_interop.0,
+            self.gregorian_interop.1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, Werh};
+
+    #[test]
+    fn test_constants_match_the_conversions() -> Result<(), error::Error> {
+        let epoch = Zemen::from_jdn(VERSION_INFO.ethiopian_epoch_jdn())?;
+        assert_eq!(epoch, Zemen::from_eth_cal(1, Werh::Meskerem, 1)?);
+        assert_eq!(
+            Zemen::from_jdn(VERSION_INFO.unix_epoch_jdn())?.to_epoch_day(),
+            0
+        );
+
+        assert_eq!(VERSION_INFO.version(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            VERSION_INFO.to_string(),
+            format!(
+                "zemen {} (Beyene-Kudlek, epoch jdn 1724221, unix epoch jdn 2440588, \
+                 range -4194304-01-01..=4194303-13-06, \
+                 gregorian interop -10007-07-23..=9992-02-21)",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -2,13 +2,13 @@
 use std::fmt;
 use std::str::FromStr;
 
-use crate::error;
+use crate::{error, validator, Days, Zemen};
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
 /// Months of the Ethiopian year. `Werh` means month in Ge'ez.
 #[repr(u8)]
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
 pub enum Werh {
     Meskerem = 1,
     Tikimit = 2,
@@ -26,6 +26,60 @@ pub enum Werh {
 }
 
 impl Werh {
+    /// Every month, from Meskerem to Puagme.
+    pub const ALL: [Werh; 13] = [
+        Self::Meskerem,
+        Self::Tikimit,
+        Self::Hedar,
+        Self::Tahasass,
+        Self::Tir,
+        Self::Yekatit,
+        Self::Megabit,
+        Self::Miyazia,
+        Self::Ginbot,
+        Self::Sene,
+        Self::Hamle,
+        Self::Nehase,
+        Self::Puagme,
+    ];
+
+    /// The number of months, 13.
+    pub const COUNT: usize = Self::ALL.len();
+
+    /// The latin names of the months, see [`Werh::latin_name`], in the order
+    /// of [`Werh::ALL`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Werh;
+    /// assert_eq!(Werh::VARIANTS.len(), Werh::COUNT);
+    /// assert_eq!(Werh::VARIANTS[4], Werh::Tir.latin_name());
+    /// ```
+    pub const VARIANTS: &'static [&'static str] = &[
+        "Meskerem", "Tikimit", "Hedar", "Tahasass", "Tir", "Yekatit", "Megabit", "Miyazia",
+        "Ginbot", "Sene", "Hamle", "Nehase", "Puagme",
+    ];
+
+    /// Iterate over every month, from Meskerem to Puagme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Werh;
+    /// let months: Vec<_> = Werh::iter().map(|werh| werh as u8).collect();
+    ///
+    /// assert_eq!(months, (1..=13).collect::<Vec<_>>());
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Werh> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Get the month numbered `month`, which is already known to be 1 to 13.
+    pub(crate) fn of_number(month: i32) -> Self {
+        Self::ALL[(month - 1).rem_euclid(13) as usize]
+    }
+
     /// Get the next `Werh`
     ///
     /// # Examples
@@ -91,6 +145,101 @@ impl Werh {
     pub fn short_name(&self) -> String {
         self.to_string().chars().take(3).collect()
     }
+
+    /// Get the number of days the month has in `year`: 30, or 5 or 6 for
+    /// Puagme.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use zemen::Werh;
+    /// assert_eq!(Werh::Tir.num_days(2015), 30);
+    /// assert_eq!(Werh::Puagme.num_days(2015), 6);
+    /// assert_eq!(Werh::Puagme.num_days(2016), 5);
+    /// ```
+    pub fn num_days(self, year: i32) -> u8 {
+        validator::days_in_month(year, self as u8)
+    }
+
+    /// Iterate over every day of the month in `year`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use zemen::Werh;
+    /// let days: Vec<_> = Werh::Puagme.days_iter(2016).map(|qen| qen.day()).collect();
+    /// assert_eq!(days, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year` is beyond the years of [`Zemen::MIN`] and
+    /// [`Zemen::MAX`].
+    #[allow(clippy::expect_used)]
+    pub fn days_iter(self, year: i32) -> Days {
+        let first =
+            Zemen::from_eth_cal(year, self, 1).expect("`year` is beyond the years of `Zemen`");
+        Days::new(first, self.num_days(year) as usize)
+    }
+
+    /// Get the name of the month written in latin script.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use zemen::Werh;
+    /// assert_eq!(Werh::Meskerem.latin_name(), "Meskerem");
+    /// assert_eq!(Werh::Puagme.latin_name(), "Puagme");
+    /// ```
+    pub fn latin_name(&self) -> &'static str {
+        match self {
+            Self::Meskerem => "Meskerem",
+            Self::Tikimit => "Tikimit",
+            Self::Hedar => "Hedar",
+            Self::Tahasass => "Tahasass",
+            Self::Tir => "Tir",
+            Self::Yekatit => "Yekatit",
+            Self::Megabit => "Megabit",
+            Self::Miyazia => "Miyazia",
+            Self::Ginbot => "Ginbot",
+            Self::Sene => "Sene",
+            Self::Hamle => "Hamle",
+            Self::Nehase => "Nehase",
+            Self::Puagme => "Puagme",
+        }
+    }
+}
+
+/// Iterate over the 13 months of `year` as `(month, first day, last day)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, Werh, Zemen};
+/// let months: Vec<_> = zemen::months_of(2015).collect();
+///
+/// assert_eq!(months.len(), 13);
+/// assert_eq!(
+///     months[12],
+///     (
+///         Werh::Puagme,
+///         Zemen::from_eth_cal(2015, Werh::Puagme, 1)?,
+///         Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+///     )
+/// );
+/// # Ok::<(), error::Error>(())
+/// ```
+///
+/// # Panics
+///
+/// Panics if `year` is beyond the years of [`Zemen::MIN`] and [`Zemen::MAX`].
+#[allow(clippy::expect_used)]
+pub fn months_of(year: i32) -> impl Iterator<Item = (Werh, Zemen, Zemen)> {
+    Werh::iter().map(move |month| {
+        let first =
+            Zemen::from_eth_cal(year, month, 1).expect("`year` is beyond the years of `Zemen`");
+        let last = Zemen::from_eth_cal(year, month, month.num_days(year))
+            .expect("`year` is beyond the years of `Zemen`");
+
+        (month, first, last)
+    })
 }
 
 impl TryFrom<u8> for Werh {
@@ -132,10 +281,45 @@ impl TryFrom<u8> for Werh {
     }
 }
 
+impl TryFrom<&str> for Werh {
+    type Error = error::Error;
+
+    /// Parses a month name like [`FromStr`], or its number, e.g. `05` or
+    /// `13`, for semi-structured input that has either.
+    ///
+    /// `FromStr` only takes names, so numbers are only accepted where this
+    /// is asked for explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Werh};
+    /// assert_eq!(Werh::try_from("05")?, Werh::Tir);
+    /// assert_eq!(Werh::try_from(" 13 ")?, Werh::Puagme);
+    /// assert_eq!(Werh::try_from("tir")?, Werh::Tir);
+    ///
+    /// assert!(Werh::try_from("14").is_err());
+    /// assert!("05".parse::<Werh>().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn try_from(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() || !trimmed.bytes().all(|byte| byte.is_ascii_digit()) {
+            return s.parse();
+        }
+
+        let num = trimmed
+            .parse::<u8>()
+            .map_err(|_| error::Error::InvalidVariant("Werh", s.to_string()))?;
+        Werh::try_from(num)
+    }
+}
+
 impl FromStr for Werh {
     type Err = error::Error;
 
-    /// Parses the given string to `Werh`. It's case insensitive.
+    /// Parses the given string to `Werh`. It's case insensitive, and ignores
+    /// surrounding whitespace. The error has `month_name` as it was given.
     ///
     /// # Examples
     ///
@@ -146,10 +330,13 @@ impl FromStr for Werh {
     ///
     /// assert_eq!(Werh::Meskerem, mesk);
     /// assert_eq!(Werh::Tikimit, tikm);
+    ///
+    /// let err = "Meskerm".parse::<Werh>().unwrap_err();
+    /// assert_eq!(err.to_string(), "can not parse Werh, invalid token `Meskerm`");
     /// # Ok::<(), error::Error>(())
     /// ```
     fn from_str(month_name: &str) -> Result<Self> {
-        let lower = month_name.to_lowercase();
+        let lower = month_name.trim().to_lowercase();
         match lower.as_str() {
             "meskerem" | "መስከረም" => Ok(Werh::Meskerem),
             "tikimit" | "ጥቅምት" => Ok(Werh::Tikimit),
@@ -164,7 +351,7 @@ impl FromStr for Werh {
             "hamle" | "ሐምሌ" => Ok(Werh::Hamle),
             "nehase" | "ነሐሴ" => Ok(Werh::Nehase),
             "puagme" | "ጳጉሜ" => Ok(Werh::Puagme),
-            _ => Err(error::Error::InvalidVariant("Werh", lower)),
+            _ => Err(error::Error::InvalidVariant("Werh", month_name.to_string())),
         }
     }
 }
@@ -181,7 +368,14 @@ impl fmt::Display for Werh {
     /// assert_eq!("መስከረም", wer.to_string())
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+        f.write_str(self.amharic_name())
+    }
+}
+
+impl Werh {
+    /// Get the Amharic name of the month, as written by `Display`.
+    pub(crate) fn amharic_name(&self) -> &'static str {
+        match self {
             Self::Meskerem => "መስከረም",
             Self::Tikimit => "ጥቅምት",
             Self::Hedar => "ኅዳር",
@@ -195,7 +389,7 @@ impl fmt::Display for Werh {
             Self::Hamle => "ሐምሌ",
             Self::Nehase => "ነሐሴ",
             Self::Puagme => "ጳጉሜ",
-        })
+        }
     }
 }
 
@@ -203,6 +397,86 @@ impl fmt::Display for Werh {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_variants_are_the_latin_names() {
+        assert_eq!(Werh::COUNT, Werh::iter().len());
+        for (werh, name) in Werh::iter().zip(Werh::VARIANTS) {
+            assert_eq!(werh.latin_name(), *name);
+            assert_eq!(name.parse::<Werh>().unwrap(), werh);
+        }
+    }
+
+    #[test]
+    fn test_months_of_are_consecutive() {
+        let mut expected_first = Zemen::from_eth_cal(2016, Werh::Meskerem, 1).unwrap();
+
+        for (month, first, last) in months_of(2016) {
+            assert_eq!(first, expected_first);
+            assert_eq!(first.month(), month);
+            assert_eq!(last.month(), month);
+            expected_first = last.next();
+        }
+
+        assert_eq!(
+            expected_first,
+            Zemen::from_eth_cal(2017, Werh::Meskerem, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_keep_the_token() {
+        for token in ["TIRR", " ጥርር", "13", ""] {
+            match token.parse::<Werh>() {
+                Err(error::Error::InvalidVariant("Werh", given)) => assert_eq!(given, token),
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+
+        assert_eq!(" Tir\n".parse::<Werh>().unwrap(), Werh::Tir);
+    }
+
+    #[test]
+    fn test_numeric_fallback() -> Result<()> {
+        for werh in Werh::iter() {
+            let num = werh as u8;
+            assert_eq!(Werh::try_from(num.to_string().as_str())?, werh);
+            assert_eq!(Werh::try_from(format!("{num:02}").as_str())?, werh);
+            assert_eq!(Werh::try_from(werh.latin_name())?, werh);
+        }
+
+        for token in ["0", "14", "+5", "-1", "5.0", "99999"] {
+            assert!(Werh::try_from(token).is_err(), "{token}");
+        }
+        match Werh::try_from("TIRR") {
+            Err(error::Error::InvalidVariant("Werh", given)) => assert_eq!(given, "TIRR"),
+            other => panic!("unexpected {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_is_in_order() {
+        for (i, werh) in Werh::iter().enumerate() {
+            assert_eq!(Werh::try_from(i as u8 + 1).unwrap(), werh);
+        }
+        assert_eq!(Werh::iter().next_back(), Some(Werh::Puagme));
+    }
+
+    #[test]
+    fn test_days_iter_covers_the_year() {
+        for year in [2015, 2016] {
+            let days: Vec<_> = Werh::iter()
+                .flat_map(|month| month.days_iter(year))
+                .collect();
+
+            assert_eq!(days.len(), validator::days_in_year(year) as usize);
+            for (i, qen) in days.iter().enumerate() {
+                assert_eq!(qen.ordinal_date(), (year, i as u16 + 1));
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_werh_from_number() {
@@ -210,6 +484,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::unused_enumerate_index)]
     fn test_month_from_english_text() -> Result<()> {
         let amh_month_name = [
             "መስከረም",
@@ -0,0 +1,259 @@
+//! Ethiopian dates beyond the range of [`Zemen`], e.g. for archival and
+//! astronomical records.
+//!
+//! `Zemen` packs a date into an `i32` to stay small. `WideZemen` keeps the year
+//! in an `i64` and does its Julian day number math in `i128` instead, and
+//! converts to and from `Zemen` whenever the date fits.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::zemen::{MAX_YEAR, MIN_YEAR};
+use crate::{error, validator, Samint, Werh, Zemen};
+
+const JDN_EPOCH_OFFSET_ETH: i128 = 1_723_856;
+
+/// An Ethiopian date with an `i64` year.
+///
+/// Unlike [`Zemen`], the Julian day number math is done with floored
+/// division, so dates before the Ethiopian epoch, i.e. year 0 and below, are
+/// handled as well.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, WideZemen, Werh, Zemen};
+/// let qen = WideZemen::new(-1_000_000_000, Werh::Meskerem, 1)?;
+/// assert_eq!(WideZemen::from_jdn(qen.to_jdn())?, qen);
+/// assert!(Zemen::try_from(qen).is_err());
+///
+/// let qen = WideZemen::new(2015, Werh::Tir, 10)?;
+/// assert_eq!(Zemen::try_from(qen)?, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct WideZemen {
+    year: i64,
+    month: Werh,
+    day: u8,
+}
+
+impl WideZemen {
+    /// Attempt to create a `WideZemen` from the year, month, and day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, WideZemen, Werh};
+    /// assert!(WideZemen::new(10_000_003, Werh::Puagme, 6).is_ok());
+    /// assert!(WideZemen::new(10_000_000, Werh::Puagme, 6).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn new(year: i64, month: Werh, day: u8) -> Result<Self, error::Error> {
+        let name = validator::day_of(month as u8);
+        error::is_in_range(day as _, 1, days_in_month(year, month) as _, name)?;
+
+        Ok(WideZemen { year, month, day })
+    }
+
+    /// Create a `WideZemen` from a Julian day number.
+    ///
+    /// Fails if the year doesn't fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, WideZemen, Werh};
+    /// let qen = WideZemen::from_jdn(2_451_545)?;
+    ///
+    /// assert_eq!(qen, WideZemen::new(1992, Werh::Tahasass, 22)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn from_jdn(jdn: i128) -> Result<Self, error::Error> {
+        let days = jdn - JDN_EPOCH_OFFSET_ETH;
+        let r = days.rem_euclid(1461);
+        let n = r % 365 + 365 * (r / 1460);
+
+        let year = 4 * days.div_euclid(1461) + r / 365 - r / 1460;
+        let year = i64::try_from(year)
+            .map_err(|_| error::Error::InvalidVariant("year", year.to_string()))?;
+        let month = Werh::try_from((n / 30 + 1) as u8)?;
+
+        Self::new(year, month, (n % 30 + 1) as u8)
+    }
+
+    /// Get the Julian day number of the date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, WideZemen, Werh};
+    /// let qen = WideZemen::new(1992, Werh::Tahasass, 22)?;
+    ///
+    /// assert_eq!(qen.to_jdn(), 2_451_545);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn to_jdn(&self) -> i128 {
+        let year = self.year as i128;
+
+        JDN_EPOCH_OFFSET_ETH
+            + 365 * year
+            + year.div_euclid(4)
+            + 30 * (self.month as i128 - 1)
+            + self.day as i128
+            - 1
+    }
+
+    /// Get the year.
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Get the month.
+    pub fn month(&self) -> Werh {
+        self.month
+    }
+
+    /// Get the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Get the day of the week.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, WideZemen, Werh};
+    /// let qen = WideZemen::new(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.weekday(), Samint::Irob);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn weekday(&self) -> Samint {
+        Samint::of_jdn(self.to_jdn())
+    }
+
+    /// Check whether the year of the date is a leap year.
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 3
+}
+
+fn days_in_month(year: i64, month: Werh) -> u8 {
+    match month {
+        Werh::Puagme if is_leap_year(year) => 6,
+        Werh::Puagme => 5,
+        _ => 30,
+    }
+}
+
+impl PartialOrd for WideZemen {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WideZemen {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month as u8, self.day).cmp(&(other.year, other.month as u8, other.day))
+    }
+}
+
+impl fmt::Display for WideZemen {
+    /// Formats the date into `YYYY-MM-DD`, like [`Zemen`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year, self.month as u8, self.day
+        )
+    }
+}
+
+impl From<Zemen> for WideZemen {
+    fn from(qen: Zemen) -> Self {
+        WideZemen {
+            year: qen.year() as i64,
+            month: qen.month(),
+            day: qen.day(),
+        }
+    }
+}
+
+impl TryFrom<WideZemen> for Zemen {
+    type Error = error::Error;
+
+    /// Convert a `WideZemen` to a `Zemen`, failing if it's outside of
+    /// [`Zemen::MIN`] to [`Zemen::MAX`].
+    fn try_from(qen: WideZemen) -> Result<Self, Self::Error> {
+        if !(MIN_YEAR as i64..=MAX_YEAR as i64).contains(&qen.year) {
+            return Err(error::Error::InvalidRange {
+                name: "year",
+                given: qen.year.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+                min: MIN_YEAR,
+                max: MAX_YEAR,
+            });
+        }
+
+        Zemen::from_eth_cal(qen.year as i32, qen.month, qen.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_zemen_jdn() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(-1000, Werh::Meskerem, 1)?;
+
+        for days in (0..1_000_000).step_by(97) {
+            let qen = start + days;
+            let wide = WideZemen::from(qen);
+
+            assert_eq!(wide.to_jdn(), qen.to_jdn() as i128);
+            assert_eq!(WideZemen::from_jdn(qen.to_jdn() as i128)?, wide);
+            assert_eq!(wide.weekday(), qen.weekday());
+            assert_eq!(Zemen::try_from(wide)?, qen);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_days_are_consecutive_before_the_epoch() -> Result<(), error::Error> {
+        let mut qen = WideZemen::new(-5, Werh::Meskerem, 1)?;
+        assert_eq!(qen.to_jdn(), JDN_EPOCH_OFFSET_ETH - 5 * 365 - 2);
+
+        for jdn in qen.to_jdn()..JDN_EPOCH_OFFSET_ETH + 2000 {
+            let next = WideZemen::from_jdn(jdn + 1)?;
+            assert_eq!(WideZemen::from_jdn(jdn)?, qen);
+            assert!(next > qen);
+            qen = next;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extreme_years() -> Result<(), error::Error> {
+        for year in [i64::MIN, i64::MIN + 1, -1, 0, i64::MAX - 1, i64::MAX] {
+            let qen = WideZemen::new(year, Werh::Puagme, 5)?;
+            assert_eq!(WideZemen::from_jdn(qen.to_jdn())?, qen);
+        }
+
+        let past_max = WideZemen::new(i64::MAX, Werh::Puagme, 6)?.to_jdn() + 1;
+        assert!(WideZemen::from_jdn(past_max).is_err());
+        assert_eq!(Zemen::try_from(WideZemen::from(Zemen::MIN))?, Zemen::MIN);
+        assert_eq!(Zemen::try_from(WideZemen::from(Zemen::MAX))?, Zemen::MAX);
+        assert!(Zemen::try_from(WideZemen::from_jdn(Zemen::MIN.to_jdn() as i128 - 1)?).is_err());
+        assert!(Zemen::try_from(WideZemen::from_jdn(Zemen::MAX.to_jdn() as i128 + 1)?).is_err());
+
+        Ok(())
+    }
+}
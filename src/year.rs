@@ -0,0 +1,207 @@
+//! Derived data about whole years, and caches of it.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::{conversion, leap::Evangelist, validator, Samint};
+#[cfg(feature = "unstable-holidays")]
+use crate::{
+    holiday::{EthiopianHolidays, Holiday, HolidayProvider},
+    Zemen,
+};
+
+thread_local! {
+    static CACHE: RefCell<YearCache> = RefCell::new(YearCache::new());
+}
+
+/// Facts about an Ethiopian year that code working over a whole year keeps
+/// needing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{Samint, YearInfo};
+/// let info = YearInfo::of(2015);
+///
+/// assert!(info.is_leap());
+/// assert_eq!(info.days(), 366);
+/// assert_eq!(info.start_jdn(), 2_459_834);
+/// assert_eq!(info.first_weekday(), Samint::Ihud);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct YearInfo {
+    year: i32,
+    is_leap: bool,
+    start_jdn: i32,
+}
+
+impl YearInfo {
+    /// Work out the data of `year`.
+    pub fn of(year: i32) -> Self {
+        YearInfo {
+            year,
+            is_leap: validator::is_leap_year(year),
+            start_jdn: conversion::eth_to_jdn(year, 1, 1),
+        }
+    }
+
+    /// Get the data of `year` from a cache kept per thread, working it out
+    /// on first use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::YearInfo;
+    /// assert_eq!(YearInfo::cached(2016), YearInfo::of(2016));
+    /// ```
+    pub fn cached(year: i32) -> Self {
+        CACHE.with(|cache| cache.borrow_mut().get(year))
+    }
+
+    /// Get the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns `true` if Puagme has 6 days this year.
+    pub fn is_leap(&self) -> bool {
+        self.is_leap
+    }
+
+    /// Get the Evangelist the year is named after, see [`crate::leap`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{leap::Evangelist, YearInfo};
+    /// assert_eq!(YearInfo::of(2015).evangelist(), Evangelist::Luqas);
+    /// ```
+    pub fn evangelist(&self) -> Evangelist {
+        Evangelist::of(self.year)
+    }
+
+    /// Get the number of days in the year.
+    pub fn days(&self) -> u16 {
+        if self.is_leap {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Get the Julian day number of Meskerem 1.
+    pub fn start_jdn(&self) -> i32 {
+        self.start_jdn
+    }
+
+    /// Get the weekday of Meskerem 1.
+    pub fn first_weekday(&self) -> Samint {
+        Samint::of_jdn(self.start_jdn.into())
+    }
+
+    /// Get the holidays of the year from [`EthiopianHolidays`], listed once
+    /// per thread and reused after that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{holiday::{EthiopianHolidays, HolidayProvider}, YearInfo};
+    /// assert_eq!(YearInfo::of(2016).holidays(), EthiopianHolidays.holidays(2016));
+    /// ```
+    #[cfg(feature = "unstable-holidays")]
+    pub fn holidays(&self) -> Vec<(Zemen, Holiday)> {
+        CACHE.with(|cache| cache.borrow_mut().holidays(self.year).to_vec())
+    }
+}
+
+/// A cache of [`YearInfo`]s, for reusing across a batch of work explicitly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::YearCache;
+/// let mut cache = YearCache::new();
+///
+/// let leap_years = (1900..2100).filter(|year| cache.get(*year).is_leap()).count();
+/// assert_eq!(leap_years, 50);
+/// assert_eq!(cache.len(), 200);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct YearCache {
+    years: HashMap<i32, YearInfo>,
+    #[cfg(feature = "unstable-holidays")]
+    holidays: HashMap<i32, Vec<(Zemen, Holiday)>>,
+}
+
+impl YearCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the data of `year`, working it out if it isn't cached yet.
+    pub fn get(&mut self, year: i32) -> YearInfo {
+        *self.years.entry(year).or_insert_with(|| YearInfo::of(year))
+    }
+
+    /// Get the holidays of `year` from [`EthiopianHolidays`], listing them if
+    /// they aren't cached yet.
+    #[cfg(feature = "unstable-holidays")]
+    pub fn holidays(&mut self, year: i32) -> &[(Zemen, Holiday)] {
+        self.holidays
+            .entry(year)
+            .or_insert_with(|| EthiopianHolidays.holidays(year))
+    }
+
+    /// Get the number of cached years.
+    pub fn len(&self) -> usize {
+        self.years.len()
+    }
+
+    /// Returns `true` if no year is cached.
+    pub fn is_empty(&self) -> bool {
+        self.years.is_empty()
+    }
+
+    /// Remove every cached year.
+    pub fn clear(&mut self) {
+        self.years.clear();
+        #[cfg(feature = "unstable-holidays")]
+        self.holidays.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Werh, Zemen};
+
+    #[test]
+    fn test_year_info_matches_zemen() {
+        let mut cache = YearCache::new();
+
+        for year in 1990..2030 {
+            let meskerem_1 = Zemen::from_eth_cal(year, Werh::Meskerem, 1).unwrap();
+            let info = cache.get(year);
+
+            assert_eq!(info.start_jdn(), meskerem_1.to_jdn());
+            assert_eq!(info.first_weekday(), meskerem_1.weekday());
+            assert_eq!(info.days(), validator::days_in_year(year));
+        }
+    }
+
+    #[cfg(feature = "unstable-holidays")]
+    #[test]
+    fn test_holidays_are_listed_once() {
+        let mut cache = YearCache::new();
+
+        for year in [2015, 2016, 2015] {
+            assert_eq!(cache.holidays(year), EthiopianHolidays.holidays(year));
+            assert_eq!(YearInfo::cached(year).holidays(), cache.holidays(year));
+        }
+        assert_eq!(cache.holidays.len(), 2);
+
+        cache.clear();
+        assert!(cache.holidays.is_empty());
+    }
+}
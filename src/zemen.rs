@@ -2,14 +2,82 @@
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
-use crate::{conversion, error, formatting, validator, Samint, Werh};
-use std::{fmt, ops::Add};
+use crate::{
+    base32,
+    calendar::{self, WeekNumbering, WerhWeek},
+    conversion, error,
+    format::{self, FormatDescription},
+    formatting, iso8601, locale, partition, ussd, validator, Days, DigitStyle, Locale,
+    PartitionStyle, Period, Samint, Weighting, Werh, ZemenDuration,
+};
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
 
 #[cfg(not(feature = "time"))]
 use std::time::SystemTime;
 
+/// What to do when month arithmetic lands on a day the target month doesn't
+/// have, e.g. adding a month to Nehase 30 lands on Puagme 30.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MonthOverflow {
+    /// Use the last day of the target month instead.
+    Clamp,
+    /// Return an error.
+    Reject,
+    /// Carry the extra days over into the following month.
+    Rollover,
+}
+
+/// A calendar period length used to bucket dates.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Granularity {
+    Day,
+    /// Seven day weeks counted from Meskerem 1, the last one of the year
+    /// being cut short by Puagme.
+    Week,
+    Month,
+    Year,
+}
+
 /// An Ethiopian Date.
-#[derive(PartialEq, Clone)]
+///
+/// A `Zemen` is a single `i32` and is `Copy`, so dates can be passed around
+/// by value freely.
+///
+/// Dates are ordered from the earliest to the latest, so they can be sorted
+/// and used as keys of a `BTreeMap`.
+///
+/// The year is kept in the upper 23 bits, so a `Zemen` can hold any date from
+/// [`Zemen::MIN`], the first day of the year -4,194,304, to [`Zemen::MAX`],
+/// the last day of the year 4,194,303. Constructors reject years outside of
+/// that range, and arithmetic going past it panics rather than wrapping
+/// around.
+///
+/// # Examples
+///
+/// ```rust
+/// # use zemen::{error, Zemen, Werh};
+/// let mut dates = vec![
+///     Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?,
+///     Zemen::from_eth_cal(2015, Werh::Puagme, 6)?,
+///     Zemen::from_eth_cal(1992, Werh::Tir, 10)?,
+/// ];
+/// dates.sort();
+///
+/// assert_eq!(dates[0].year(), 1992);
+/// assert!(dates[1] < dates[2]);
+/// # Ok::<(), error::Error>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Date))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
 pub struct Zemen {
     // the first 9 bits will store the ordinal day
     // the rest is for the year.
@@ -31,25 +99,64 @@ impl fmt::Display for Zemen {
     /// # Ok::<(), error::Error>(())
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:04}-{:02}-{:02}",
-            self.year(),
-            self.month() as u8,
-            self.day()
-        )
+        let (year, month, day) = self.to_calendar_date();
+        write!(f, "{:04}-{:02}-{:02}", year, month as u8, day)
     }
 }
 
 impl fmt::Debug for Zemen {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:04}-{:02}-{:02}",
-            self.year(),
-            self.month() as u8,
-            self.day()
-        )
+        let (year, month, day) = self.to_calendar_date();
+        write!(f, "{:04}-{:02}-{:02}", year, month as u8, day)
+    }
+}
+
+impl FromStr for Zemen {
+    type Err = error::Error;
+
+    /// Parses a date in the `YYYY-MM-DD` form produced by `Display`: an
+    /// optional `-`, the digits of the year, and two digits each for the
+    /// month and the day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Zemen, Werh, error};
+    /// let qen: Zemen = "2015-05-10".parse()?;
+    ///
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    /// assert!("2015-13-07".parse::<Zemen>().is_err());
+    /// assert!("2015-5-10".parse::<Zemen>().is_err());
+    /// assert!("+2015-05-10".parse::<Zemen>().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || error::Error::InvalidVariant("Zemen", s.to_string());
+        let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+        let (sign, rest) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, s),
+        };
+
+        let mut parts = rest.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if !digits(year)
+            || [month, day]
+                .iter()
+                .any(|part| part.len() != 2 || !digits(part))
+        {
+            return Err(invalid());
+        }
+
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let month: u8 = month.parse().map_err(|_| invalid())?;
+        let day: u8 = day.parse().map_err(|_| invalid())?;
+
+        Self::new(sign * year, month, day)
     }
 }
 
@@ -74,10 +181,13 @@ impl From<&Zemen> for time::Date {
     /// assert_eq!(1, day.day());
     /// # Ok::<(), error::Error>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics like [`Zemen::to_gre`] for dates beyond
+    /// [`Zemen::MIN_GREGORIAN_INTEROP`] and [`Zemen::MAX_GREGORIAN_INTEROP`].
     fn from(value: &Zemen) -> Self {
-        // Zemen::from_eth_date(value)
-        conversion::eth_to_gre(value.year(), value.month() as u8, value.day())
-            .expect("From already a validated date")
+        value.to_gre()
     }
 }
 
@@ -108,6 +218,74 @@ impl From<&time::Date> for Zemen {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<&chrono::NaiveDate> for Zemen {
+    /// Converts a `chrono::NaiveDate`, which is in Gregorian format, to its
+    /// Ethiopian format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let date = chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    ///
+    /// assert_eq!(Zemen::from(&date), Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[allow(clippy::expect_used)]
+    fn from(value: &chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+
+        // every `NaiveDate`, about 262,000 years either way, is within `Zemen`
+        Zemen::from_jdn(value.num_days_from_ce() + CE_JDN)
+            .expect("since `value` is a valid date the returned date will also be valid")
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<&jiff::civil::Date> for Zemen {
+    /// Converts a `jiff::civil::Date`, which is in Gregorian format, to its
+    /// Ethiopian format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let date = jiff::civil::date(2000, 1, 1);
+    ///
+    /// assert_eq!(Zemen::from(&date), Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[allow(clippy::expect_used)]
+    fn from(value: &jiff::civil::Date) -> Self {
+        // every `civil::Date`, from -9999 to 9999, is within `Zemen`
+        Zemen::checked_from_gre_cal(value.year() as i32, value.month() as u8, value.day() as u8)
+            .expect("since `value` is a valid date the returned date will also be valid")
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<&jiff::Zoned> for Zemen {
+    /// Converts the date of a `jiff::Zoned` in its own timezone, e.g. an
+    /// event at 23:30 UTC is already on the next day in Addis Ababa.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jiff::{civil, tz::{Offset, TimeZone}};
+    /// # use zemen::{error, Zemen, Werh};
+    /// let utc = civil::date(2023, 1, 18).at(23, 30, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+    /// let addis = utc.with_time_zone(TimeZone::fixed(Offset::constant(3)));
+    ///
+    /// assert_eq!(Zemen::from(&utc), Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    /// assert_eq!(Zemen::from(&addis), Zemen::from_eth_cal(2015, Werh::Tir, 11)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn from(value: &jiff::Zoned) -> Self {
+        Self::from(&value.date())
+    }
+}
+
 impl Add<i32> for Zemen {
     type Output = Zemen;
 
@@ -125,12 +303,304 @@ impl Add<i32> for Zemen {
     /// assert_eq!(qen, Zemen::from_eth_cal(2004, Werh::Meskerem, 1)?);
     /// # Ok::<(), error::Error>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is beyond [`Zemen::MIN`] and [`Zemen::MAX`], see
+    /// [`Zemen::checked_add_days`].
+    #[allow(clippy::expect_used)]
     fn add(self, days: i32) -> Self::Output {
-        Zemen::from_jdn(self.to_jdn() + days).expect("`to_jdn` gives us a valid jdn date")
+        self.checked_add_days(days)
+            .expect("date is beyond `Zemen::MIN` and `Zemen::MAX`")
+    }
+}
+
+impl Sub<i32> for Zemen {
+    type Output = Zemen;
+
+    /// Subtracting a number from a `Zemen` instance will go back by the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Zemen;
+    /// # use zemen::error;
+    /// # use zemen::Werh;
+    /// let qen = Zemen::from_eth_cal(2004, Werh::Meskerem, 1)?;
+    /// let qen = qen - 6;
+    ///
+    /// assert_eq!(qen, Zemen::from_eth_cal(2003, Werh::Puagme, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is beyond [`Zemen::MIN`] and [`Zemen::MAX`], see
+    /// [`Zemen::checked_sub_days`].
+    #[allow(clippy::expect_used)]
+    fn sub(self, days: i32) -> Self::Output {
+        self.checked_sub_days(days)
+            .expect("date is beyond `Zemen::MIN` and `Zemen::MAX`")
+    }
+}
+
+impl Add<ZemenDuration> for Zemen {
+    type Output = Zemen;
+
+    /// Adding a `ZemenDuration` advances the years, then the months, then the
+    /// days, clamping to the end of Puagme when needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, ZemenDuration, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+    ///
+    /// assert_eq!(qen + ZemenDuration::years(1), Zemen::from_eth_cal(2016, Werh::Puagme, 5)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is beyond [`Zemen::MIN`] and [`Zemen::MAX`], see
+    /// [`Zemen::add_years`] and [`Zemen::add_months`].
+    #[allow(clippy::expect_used)]
+    fn add(self, duration: ZemenDuration) -> Self::Output {
+        self.add_years(duration.years, MonthOverflow::Clamp)
+            .and_then(|qen| qen.add_months(duration.months, MonthOverflow::Clamp))
+            .expect("date is beyond `Zemen::MIN` and `Zemen::MAX`")
+            + duration.days
+    }
+}
+
+impl Sub<ZemenDuration> for Zemen {
+    type Output = Zemen;
+
+    /// Subtracting a `ZemenDuration` is the same as adding its negation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, ZemenDuration, Werh};
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Meskerem, 30)?;
+    ///
+    /// assert_eq!(qen - ZemenDuration::months(1), Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn sub(self, duration: ZemenDuration) -> Self::Output {
+        self + -duration
+    }
+}
+
+impl AddAssign<i32> for Zemen {
+    /// Advances the date in place by a number of days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let mut qen = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// qen += 7;
+    ///
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 8)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn add_assign(&mut self, days: i32) {
+        *self = *self + days;
+    }
+}
+
+impl SubAssign<i32> for Zemen {
+    /// Moves the date back in place by a number of days.
+    fn sub_assign(&mut self, days: i32) {
+        *self = *self - days;
+    }
+}
+
+impl AddAssign<ZemenDuration> for Zemen {
+    /// Advances the date in place by a `ZemenDuration`.
+    fn add_assign(&mut self, duration: ZemenDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl SubAssign<ZemenDuration> for Zemen {
+    /// Moves the date back in place by a `ZemenDuration`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, ZemenDuration, Werh};
+    /// let mut qen = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// qen -= ZemenDuration::months(1);
+    ///
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tahasass, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    fn sub_assign(&mut self, duration: ZemenDuration) {
+        *self = *self - duration;
+    }
+}
+
+#[cfg(feature = "time")]
+impl Add<time::Duration> for Zemen {
+    type Output = Zemen;
+
+    /// Advances the date by the whole days in `duration`, any remaining
+    /// hours, minutes, etc. are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    ///
+    /// assert_eq!(qen + time::Duration::days(3), Zemen::from_eth_cal(2015, Werh::Tir, 4)?);
+    /// assert_eq!(qen + time::Duration::hours(47), Zemen::from_eth_cal(2015, Werh::Tir, 2)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is beyond [`Zemen::MIN`] and [`Zemen::MAX`].
+    #[allow(clippy::expect_used)]
+    fn add(self, duration: time::Duration) -> Self::Output {
+        let days = i32::try_from(duration.whole_days())
+            .expect("date is beyond `Zemen::MIN` and `Zemen::MAX`");
+        self + days
+    }
+}
+
+#[cfg(feature = "time")]
+impl Sub<time::Duration> for Zemen {
+    type Output = Zemen;
+
+    /// Goes back by the whole days in `duration`, any remaining hours,
+    /// minutes, etc. are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    ///
+    /// assert_eq!(qen - time::Duration::hours(25), Zemen::from_eth_cal(2015, Werh::Tahasass, 30)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is beyond [`Zemen::MIN`] and [`Zemen::MAX`].
+    #[allow(clippy::expect_used)]
+    fn sub(self, duration: time::Duration) -> Self::Output {
+        let duration = duration
+            .checked_neg()
+            .expect("date is beyond `Zemen::MIN` and `Zemen::MAX`");
+        Add::add(self, duration)
+    }
+}
+
+#[cfg(feature = "time")]
+impl AddAssign<time::Duration> for Zemen {
+    /// Advances the date in place by the whole days in `duration`.
+    fn add_assign(&mut self, duration: time::Duration) {
+        *self = *self + duration;
     }
 }
 
+#[cfg(feature = "time")]
+impl SubAssign<time::Duration> for Zemen {
+    /// Moves the date back in place by the whole days in `duration`.
+    fn sub_assign(&mut self, duration: time::Duration) {
+        *self = *self - duration;
+    }
+}
+
+/// The earliest year a `Zemen` can hold.
+pub(crate) const MIN_YEAR: i32 = -(1 << 22);
+/// The latest year a `Zemen` can hold.
+pub(crate) const MAX_YEAR: i32 = (1 << 22) - 1;
+/// The Julian day number of the Unix epoch, 1970-01-01.
+pub(crate) const UNIX_EPOCH_JDN: i32 = 2_440_588;
+/// The Julian day number of the day before the Gregorian 0001-01-01, which
+/// chrono counts days from.
+#[cfg(feature = "chrono")]
+const CE_JDN: i32 = 1_721_425;
+
 impl Zemen {
+    /// The earliest date a `Zemen` can hold, መስከረም 1 of the year -4,194,304.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::MIN, Zemen::from_eth_cal(-4_194_304, Werh::Meskerem, 1)?);
+    /// assert!(Zemen::from_jdn(Zemen::MIN.to_jdn() - 1).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const MIN: Zemen = Zemen {
+        ordinal_date: (MIN_YEAR << 9) | 1,
+    };
+
+    /// The latest date a `Zemen` can hold, ጳጉሜ 6 of the year 4,194,303.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::MAX, Zemen::from_eth_cal(4_194_303, Werh::Puagme, 6)?);
+    /// assert!(Zemen::from_jdn(Zemen::MAX.to_jdn() + 1).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const MAX: Zemen = Zemen {
+        ordinal_date: (MAX_YEAR << 9) | 366,
+    };
+
+    /// The earliest date that converts to and from `time::Date`, the
+    /// Gregorian -9999-01-01.
+    ///
+    /// `time::Date` only holds the Gregorian years -9999 to 9999, unless its
+    /// `large-dates` feature is enabled, so conversions with the `time`
+    /// feature fail with [`error::Error::InvalidRange`] of the Gregorian year
+    /// for dates before this one, whichever features it's built with. Use
+    /// [`Zemen::is_gregorian_interop`] to check dates when taking them in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(
+    ///     Zemen::MIN_GREGORIAN_INTEROP,
+    ///     Zemen::from_eth_cal(-10_007, Werh::Megabit, 23)?
+    /// );
+    /// assert_eq!(Zemen::MIN_GREGORIAN_INTEROP, Zemen::checked_from_gre_cal(-9999, 1, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const MIN_GREGORIAN_INTEROP: Zemen = Zemen {
+        ordinal_date: (-10_007 << 9) | 203,
+    };
+
+    /// The latest date that converts to and from `time::Date`, the Gregorian
+    /// 9999-12-31, see [`Zemen::MIN_GREGORIAN_INTEROP`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(
+    ///     Zemen::MAX_GREGORIAN_INTEROP,
+    ///     Zemen::from_eth_cal(9992, Werh::Tikimit, 21)?
+    /// );
+    /// assert_eq!(Zemen::MAX_GREGORIAN_INTEROP, Zemen::checked_from_gre_cal(9999, 12, 31)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const MAX_GREGORIAN_INTEROP: Zemen = Zemen {
+        ordinal_date: (9992 << 9) | 51,
+    };
+
+    /// The length of [`Zemen::format_ussd`] for the years 0 to 9999.
+    pub const USSD_MAX_LEN: usize = 11;
+
     pub(crate) fn new(year: i32, month: u8, day: u8) -> Result<Self> {
         validator::is_valid_date(year, month, day)?;
         Self::from_ordinal_date(year, conversion::to_ordinal(month as i32, day as i32) as _)
@@ -148,19 +618,84 @@ impl Zemen {
     /// assert_eq!(qen, Zemen::from_eth_cal(2000, Werh::Hedar, 2)?);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn from_ordinal_date(year: i32, ordinal: u16) -> Result<Self> {
-        error::is_in_range(
-            ordinal as _,
-            1,
-            validator::days_in_year(year) as _,
-            "ordinal",
-        )?;
+    pub const fn from_ordinal_date(year: i32, ordinal: u16) -> Result<Self> {
+        match Self::checked_from_ordinal_date(year, ordinal) {
+            Some(qen) => Ok(qen),
+            None if year < MIN_YEAR || year > MAX_YEAR => Err(error::Error::InvalidRange {
+                name: "year",
+                given: year,
+                min: MIN_YEAR,
+                max: MAX_YEAR,
+            }),
+            None => Err(error::Error::InvalidRange {
+                name: "ordinal",
+                given: ordinal as i32,
+                min: 1,
+                max: validator::days_in_year(year) as i32,
+            }),
+        }
+    }
+
+    /// Create a `Zemen` from the year and day number, returning `None` if
+    /// the date doesn't exist.
+    ///
+    /// Unlike [`Zemen::from_ordinal_date`], this can be unwrapped in `const`
+    /// contexts, as the compiler can't drop an [`error::Error`] there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// const ENKUTATASH: Zemen = match Zemen::checked_from_ordinal_date(2016, 1) {
+    ///     Some(qen) => qen,
+    ///     None => panic!("Meskerem 1 always exists"),
+    /// };
+    ///
+    /// assert_eq!(ENKUTATASH, Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?);
+    /// assert_eq!(Zemen::checked_from_ordinal_date(2016, 366), None);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const fn checked_from_ordinal_date(year: i32, ordinal: u16) -> Option<Self> {
+        if year < MIN_YEAR || year > MAX_YEAR {
+            return None;
+        }
+        if ordinal < 1 || ordinal > validator::days_in_year(year) {
+            return None;
+        }
 
-        Ok(Zemen {
+        Some(Zemen {
             ordinal_date: (year << 9) | ordinal as i32,
         })
     }
 
+    /// Create a `Zemen` from the Ethiopian year, month, and day, returning
+    /// `None` if the date doesn't exist.
+    ///
+    /// This is the `const` counterpart of [`Zemen::from_eth_cal`], e.g. to
+    /// keep holiday tables and fixed epochs as constants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Zemen, Werh};
+    /// const TIMKET: Zemen = match Zemen::checked_from_eth_cal(2015, Werh::Tir, 11) {
+    ///     Some(qen) => qen,
+    ///     None => panic!("Tir 11 always exists"),
+    /// };
+    /// const TIMKET_JDN: i32 = TIMKET.to_jdn();
+    ///
+    /// assert_eq!(Zemen::from_jdn(TIMKET_JDN).ok(), Some(TIMKET));
+    /// assert_eq!(Zemen::checked_from_eth_cal(2015, Werh::Tir, 31), None);
+    /// assert_eq!(Zemen::checked_from_eth_cal(2016, Werh::Puagme, 6), None);
+    /// ```
+    pub const fn checked_from_eth_cal(year: i32, month: Werh, day: u8) -> Option<Self> {
+        if day < 1 || day > validator::days_in_month(year, month as u8) {
+            return None;
+        }
+
+        Self::checked_from_ordinal_date(year, (month as u16 - 1) * 30 + day as u16)
+    }
+
     /// Get the year.
     ///
     /// # Examples
@@ -173,7 +708,7 @@ impl Zemen {
     /// assert_eq!(qen.year(), 2000);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn year(&self) -> i32 {
+    pub const fn year(&self) -> i32 {
         self.ordinal_date >> 9
     }
 
@@ -190,7 +725,7 @@ impl Zemen {
     /// ```
     pub fn month(&self) -> Werh {
         let (month, _) = conversion::from_ordinal(self.ordinal() as _);
-        Werh::try_from(month as u8).expect("validated by new")
+        Werh::of_number(month)
     }
 
     /// Get the day of the month.
@@ -211,6 +746,26 @@ impl Zemen {
         day as u8
     }
 
+    /// Get the year, month, and day at once, splitting the day of the year
+    /// up only once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    /// let (year, month, day) = qen.to_calendar_date();
+    ///
+    /// assert_eq!((year, month, day), (2015, Werh::Tir, 10));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn to_calendar_date(&self) -> (i32, Werh, u8) {
+        let (month, day) = conversion::from_ordinal(self.ordinal() as _);
+        let month = Werh::of_number(month);
+
+        (self.year(), month, day as u8)
+    }
+
     /// returns the current date in Ethiopian date.
     ///
     /// # Examples
@@ -235,6 +790,12 @@ impl Zemen {
     /// println!("zare: {}", zare);
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Without the `time` feature, panics if the system clock is set before
+    /// 1970.
+    #[cfg_attr(not(feature = "time"), allow(clippy::panic, clippy::expect_used))]
     pub fn today() -> Self {
         #[cfg(feature = "time")]
         {
@@ -276,8 +837,10 @@ impl Zemen {
     /// # Ok::<(), error::Error>(())
     /// ```
     #[cfg(feature = "time")]
+    #[allow(clippy::expect_used, clippy::missing_panics_doc)]
     pub fn from_date(gc_date: &time::Date) -> Self {
-        conversion::gre_to_eth(gc_date.year(), gc_date.month() as u8, gc_date.day())
+        // even with `large-dates`, every `time::Date` is within `Zemen`
+        Zemen::from_jdn(gc_date.to_julian_day())
             .expect("since `gc_date` is a valid date the returned date will also be valid")
     }
 
@@ -299,15 +862,105 @@ impl Zemen {
         Self::new(year, month as u8, day)
     }
 
-    /// Create an Ethiopian date from Gregorian date
+    /// Create an Ethiopian date from components that may have overflowed,
+    /// e.g. the result of adding offsets to each of them separately.
+    ///
+    /// The components are normalized in two steps:
+    ///
+    /// 1. `month` is brought into 1 to 13 by moving whole years, so month 14
+    ///    is Meskerem of the next year and month 0 is Puagme of the year
+    ///    before.
+    /// 2. `day` is counted from the first of that month, so days beyond the
+    ///    month's length roll into the months after it, Puagme's 5 or 6 days
+    ///    included, and day 0 is the last day of the month before.
+    ///
+    /// Only fails if the date is beyond [`Zemen::MIN`] and [`Zemen::MAX`],
+    /// with an [`error::Error::InvalidRange`] of the normalized year or of
+    /// `day`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use zemen::{Zemen, error, Werh};
-    ///
-    /// let qen: Zemen = Zemen::from_gre_cal(2000, 1, 1)?;
-    /// assert_eq!(qen.year(), 1992);
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::from_parts_lenient(2015, 14, 1)?, Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?);
+    /// assert_eq!(Zemen::from_parts_lenient(2015, 5, 31)?, Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?);
+    /// assert_eq!(Zemen::from_parts_lenient(2016, 13, 7)?, Zemen::from_eth_cal(2017, Werh::Meskerem, 2)?);
+    /// assert_eq!(Zemen::from_parts_lenient(2016, 1, 0)?, Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+    /// assert_eq!(Zemen::from_parts_lenient(2016, 0, 1)?, Zemen::from_eth_cal(2015, Werh::Puagme, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn from_parts_lenient(year: i32, month: i32, day: i32) -> Result<Self> {
+        // the components of dates far beyond `Zemen` overflow an `i32`, so
+        // they're worked out in i64 and reported saturated
+        let saturate = |value: i64| value.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+
+        let months = i64::from(month) - 1;
+        let year = saturate(i64::from(year) + months.div_euclid(13));
+        error::is_in_range(year, MIN_YEAR, MAX_YEAR, "year")?;
+
+        let first = conversion::eth_to_jdn(year, months.rem_euclid(13) as i32 + 1, 1);
+        let min = saturate(i64::from(Self::MIN.to_jdn()) - i64::from(first) + 1);
+        let max = saturate(i64::from(Self::MAX.to_jdn()) - i64::from(first) + 1);
+        error::is_in_range(day, min, max, "day")?;
+
+        Self::from_jdn((i64::from(first) + i64::from(day) - 1) as i32)
+    }
+
+    /// Get the `nth` `weekday` of `month` in `year`, e.g. the 3rd Senyo of
+    /// Hedar. A negative `nth` counts from the end, so `-1` is the last one.
+    ///
+    /// Fails if the month doesn't have an `nth` `weekday`, e.g. a 5th one,
+    /// or if `nth` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let third = Zemen::nth_weekday_of_month(2016, Werh::Hedar, Samint::Senyo, 3)?;
+    /// assert_eq!(third, Zemen::from_eth_cal(2016, Werh::Hedar, 17)?);
+    ///
+    /// let last = Zemen::nth_weekday_of_month(2016, Werh::Hedar, Samint::Senyo, -1)?;
+    /// assert_eq!(last, Zemen::from_eth_cal(2016, Werh::Hedar, 24)?);
+    ///
+    /// assert!(Zemen::nth_weekday_of_month(2016, Werh::Hedar, Samint::Senyo, 5).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn nth_weekday_of_month(year: i32, month: Werh, weekday: Samint, nth: i8) -> Result<Self> {
+        let first = Self::from_eth_cal(year, month, 1)?;
+        let offset = (weekday as i32 - first.weekday() as i32).rem_euclid(7);
+        let days = month.num_days(year) as i32;
+        let count = if offset < days {
+            (days - 1 - offset) / 7 + 1
+        } else {
+            0
+        };
+
+        let index = if nth > 0 {
+            nth as i32 - 1
+        } else {
+            count + nth as i32
+        };
+        if nth == 0 || !(0..count).contains(&index) {
+            return Err(error::Error::InvalidRange {
+                name: "nth",
+                given: nth as i32,
+                min: -count,
+                max: count,
+            });
+        }
+
+        Ok(first + (offset + 7 * index))
+    }
+
+    /// Create an Ethiopian date from Gregorian date
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Zemen, error, Werh};
+    ///
+    /// let qen: Zemen = Zemen::from_gre_cal(2000, 1, 1)?;
+    /// assert_eq!(qen.year(), 1992);
     /// assert_eq!(qen.month(), Werh::Tahasass);
     /// assert_eq!(qen.day(), 22);
     /// # Ok::<(), error::Error>(())
@@ -317,6 +970,66 @@ impl Zemen {
         conversion::gre_to_eth(year, month, day)
     }
 
+    /// Create a `Zemen` from a proleptic Gregorian date, checking every
+    /// component itself so the error names the invalid one and its allowed
+    /// range, e.g. for user input. Unlike [`Zemen::from_gre_cal`], it doesn't
+    /// need the `time` feature.
+    ///
+    /// The year must be one of the Gregorian years `Zemen` reaches. In the
+    /// first and last of them, days beyond [`Zemen::MIN`] and [`Zemen::MAX`]
+    /// fail with the range of the Ethiopian year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::checked_from_gre_cal(2000, 1, 1)?;
+    /// assert_eq!(qen, Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?);
+    ///
+    /// let err = Zemen::checked_from_gre_cal(2023, 2, 29).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "With the provided date: day of February must be in the range 1..=28, but given 29."
+    /// );
+    /// assert!(Zemen::checked_from_gre_cal(2023, 13, 1).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn checked_from_gre_cal(year: i32, month: u8, day: u8) -> Result<Self> {
+        let (min, _, _) = conversion::jdn_to_gre(Self::MIN.to_jdn());
+        let (max, _, _) = conversion::jdn_to_gre(Self::MAX.to_jdn());
+
+        error::is_in_range(year, min, max, "Gregorian year")?;
+        validator::gre::is_valid_date(year, month, day)?;
+
+        Self::from_jdn(conversion::gre_to_jdn(year, month, day))
+    }
+
+    /// Get the proleptic Gregorian year, month (1..12), and day of the date,
+    /// the inverse of [`Zemen::checked_from_gre_cal`], without the `time`
+    /// feature.
+    ///
+    /// Unlike [`Zemen::try_to_gre`], it works for every date `Zemen` holds.
+    /// The parts are checked to be a valid Gregorian date before they are
+    /// returned, so FFI and serialization layers can pass them on as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+    /// assert_eq!(qen.try_into_gregorian_parts()?, (2000, 1, 1));
+    ///
+    /// let (year, month, day) = Zemen::MAX.try_into_gregorian_parts()?;
+    /// assert_eq!(Zemen::checked_from_gre_cal(year, month, day)?, Zemen::MAX);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn try_into_gregorian_parts(&self) -> Result<(i32, u8, u8)> {
+        let (year, month, day) = conversion::jdn_to_gre(self.to_jdn());
+        validator::gre::is_valid_date(year, month, day)?;
+
+        Ok((year, month, day))
+    }
+
     /// Convertes the current Ethiopian date in `time::Date`.
     ///
     /// # Examples
@@ -331,13 +1044,126 @@ impl Zemen {
     /// assert_eq!(date, qen.to_gre());
     /// # Ok::<(), error::Error>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is beyond [`Zemen::MIN_GREGORIAN_INTEROP`] and
+    /// [`Zemen::MAX_GREGORIAN_INTEROP`], see [`Zemen::try_to_gre`].
     #[cfg(feature = "time")]
+    #[allow(clippy::expect_used)]
     pub fn to_gre(&self) -> time::Date {
-        conversion::eth_to_gre(
-            self.year(),
-            self.month() as u8,
-            self.day()
-        ).expect("Since we are able to create an instance of `Zemen` in the beginning. we dont need to return errors")
+        self.try_to_gre()
+            .expect("date is beyond the Gregorian years of `time::Date`")
+    }
+
+    /// Convert the date to `time::Date`, failing with the range of the
+    /// Gregorian year if it's beyond [`Zemen::MIN_GREGORIAN_INTEROP`] and
+    /// [`Zemen::MAX_GREGORIAN_INTEROP`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+    /// assert_eq!(qen.try_to_gre()?.year(), 2000);
+    ///
+    /// let far = Zemen::MAX_GREGORIAN_INTEROP + 1;
+    /// assert_eq!(
+    ///     far.try_to_gre().unwrap_err().to_string(),
+    ///     "With the provided date: Gregorian year must be in the range -9999..=9999, but given 10000."
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn try_to_gre(&self) -> Result<time::Date> {
+        conversion::eth_to_gre(self.year(), self.month() as u8, self.day())
+    }
+
+    /// Get the date of `datetime` in its own timezone, e.g. an event at
+    /// 23:30 UTC is already on the next day in Addis Ababa.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, TimeZone, Utc};
+    /// # use zemen::{error, Zemen, Werh};
+    /// let utc = Utc.with_ymd_and_hms(2023, 1, 18, 23, 30, 0).unwrap();
+    /// let addis = utc.with_timezone(&FixedOffset::east_opt(3 * 3600).unwrap());
+    ///
+    /// assert_eq!(Zemen::from_datetime(&utc), Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    /// assert_eq!(Zemen::from_datetime(&addis), Zemen::from_eth_cal(2015, Werh::Tir, 11)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime<Tz: chrono::TimeZone>(datetime: &chrono::DateTime<Tz>) -> Self {
+        Self::from(&datetime.date_naive())
+    }
+
+    /// Convert the date to `chrono::NaiveDate`, failing with the range of the
+    /// Gregorian year if it's beyond `NaiveDate::MIN` and `NaiveDate::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+    /// assert_eq!(qen.to_naive_date()?, chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+    ///
+    /// assert!(Zemen::MAX.to_naive_date().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Result<chrono::NaiveDate> {
+        use chrono::{Datelike, NaiveDate};
+
+        let (year, _, _) = conversion::jdn_to_gre(self.to_jdn());
+        error::is_in_range(
+            year,
+            NaiveDate::MIN.year(),
+            NaiveDate::MAX.year(),
+            "Gregorian year",
+        )?;
+
+        NaiveDate::from_num_days_from_ce_opt(self.to_jdn() - CE_JDN)
+            .ok_or_else(|| error::Error::InvalidVariant("NaiveDate", self.to_string()))
+    }
+
+    /// Convert the date to `jiff::civil::Date`, failing with the range of
+    /// the Gregorian year if it's beyond the years -9999 to 9999, the same
+    /// as [`Zemen::try_to_gre`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+    /// assert_eq!(qen.to_jiff_date()?, jiff::civil::date(2000, 1, 1));
+    ///
+    /// assert!((Zemen::MAX_GREGORIAN_INTEROP + 1).to_jiff_date().is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[cfg(feature = "jiff")]
+    pub fn to_jiff_date(&self) -> Result<jiff::civil::Date> {
+        let (year, month, day) = self.try_into_gregorian_parts()?;
+        error::is_in_range(year, -9999, 9999, "Gregorian year")?;
+
+        jiff::civil::Date::new(year as i16, month as i8, day as i8)
+            .map_err(|err| error::Error::InvalidVariant("jiff::civil::Date", err.to_string()))
+    }
+
+    /// Returns `true` if the date is within [`Zemen::MIN_GREGORIAN_INTEROP`]
+    /// and [`Zemen::MAX_GREGORIAN_INTEROP`], so it converts to `time::Date`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Zemen;
+    /// assert!(Zemen::MAX_GREGORIAN_INTEROP.is_gregorian_interop());
+    /// assert!(!Zemen::MAX.is_gregorian_interop());
+    /// ```
+    pub const fn is_gregorian_interop(&self) -> bool {
+        Self::MIN_GREGORIAN_INTEROP.to_bits() <= self.to_bits()
+            && self.to_bits() <= Self::MAX_GREGORIAN_INTEROP.to_bits()
     }
 
     /// Create an Ethiopian date from Julian day number.
@@ -375,8 +1201,47 @@ impl Zemen {
     /// assert_eq!(Zemen::from_eth_cal(2012, Werh::Tahasass, 21)?.to_jdn(), 2_458_849);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn to_jdn(&self) -> i32 {
-        conversion::eth_to_jdn(self.year(), self.month() as i32, self.day() as i32)
+    pub const fn to_jdn(&self) -> i32 {
+        // the ordinal is the day of Meskerem, counting on into the other months
+        conversion::eth_to_jdn(self.year(), 1, self.ordinal() as i32)
+    }
+
+    /// Create an Ethiopian date from the days since the Unix epoch,
+    /// 1970-01-01, the way most databases and columnar formats store dates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::from_epoch_day(0)?, Zemen::from_eth_cal(1962, Werh::Tahasass, 23)?);
+    /// assert_eq!(Zemen::from_epoch_day(19_375)?, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    /// assert!(Zemen::from_epoch_day(i32::MAX).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn from_epoch_day(days: i32) -> Result<Self> {
+        error::is_in_range(
+            days,
+            Self::MIN.to_epoch_day(),
+            Self::MAX.to_epoch_day(),
+            "epoch day",
+        )?;
+
+        Self::from_jdn(days + UNIX_EPOCH_JDN)
+    }
+
+    /// Get the days since the Unix epoch, 1970-01-01, negative for the dates
+    /// before it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Tir, 10)?.to_epoch_day(), 19_375);
+    /// assert_eq!(Zemen::from_eth_cal(1962, Werh::Tahasass, 22)?.to_epoch_day(), -1);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const fn to_epoch_day(&self) -> i32 {
+        self.to_jdn() - UNIX_EPOCH_JDN
     }
 
     /// Get the weekday.
@@ -391,9 +1256,7 @@ impl Zemen {
     /// # Ok::<(), error::Error>(())
     /// ```
     pub fn weekday(&self) -> Samint {
-        let weekday = (self.to_jdn() + 1) % 7;
-        Samint::try_from(weekday as u8)
-            .expect("the modulo operation will guarantee this won't go past 6")
+        Samint::of_jdn(self.to_jdn().into())
     }
 
     /// Get the next date.
@@ -406,8 +1269,12 @@ impl Zemen {
     /// assert_eq!(qen, Zemen::from_eth_cal(1992, Werh::Tir, 16)?);
     /// # Ok::<(), error::Error>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics on [`Zemen::MAX`], which has no next date.
     pub fn next(self) -> Self {
-        Self::from_jdn(self.to_jdn() + 1).expect("incrementing by one won't panic")
+        self + 1
     }
 
     /// Get the previous date.
@@ -420,128 +1287,1370 @@ impl Zemen {
     /// assert_eq!(qen, Zemen::from_eth_cal(1992, Werh::Tir, 14)?);
     /// # Ok::<(), error::Error>(())
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics on [`Zemen::MIN`], which has no previous date.
     pub fn previous(self) -> Self {
-        Self::from_jdn(self.to_jdn() - 1).expect("decrementing by one won't panic")
+        self - 1
     }
 
-    /// Get the day of the year.
+    /// Get the date `days` after this one, or `None` if it's beyond
+    /// [`Zemen::MIN`] and [`Zemen::MAX`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use zemen::{error, Zemen, Werh};
-    /// let qen = Zemen::from_eth_cal(1992, Werh::Meskerem, 15)?;
-    /// assert_eq!(qen.ordinal(), 15);
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Puagme, 5)?;
+    ///
+    /// assert_eq!(qen.checked_add_days(2), Some(Zemen::from_eth_cal(2017, Werh::Meskerem, 2)?));
+    /// assert_eq!(Zemen::MAX.checked_add_days(1), None);
+    /// assert_eq!(qen.checked_add_days(i32::MAX), None);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn ordinal(&self) -> u16 {
-        (self.ordinal_date & 0x1ff) as _
+    pub fn checked_add_days(&self, days: i32) -> Option<Self> {
+        Self::from_jdn(self.to_jdn().checked_add(days)?).ok()
     }
 
-    /// Get the year, and day of the year.
+    /// Get the date `days` before this one, or `None` if it's beyond
+    /// [`Zemen::MIN`] and [`Zemen::MAX`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use zemen::{error, Zemen, Werh};
-    /// let qen = Zemen::from_eth_cal(1992, Werh::Meskerem, 15)?;
-    /// assert_eq!(qen.ordinal_date(), (1992, 15));
+    /// let qen = Zemen::from_eth_cal(2017, Werh::Meskerem, 2)?;
+    ///
+    /// assert_eq!(qen.checked_sub_days(2), Some(Zemen::from_eth_cal(2016, Werh::Puagme, 5)?));
+    /// assert_eq!(Zemen::MIN.checked_sub_days(1), None);
+    /// assert_eq!(qen.checked_sub_days(i32::MIN), None);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn ordinal_date(&self) -> (i32, u16) {
-        (self.year(), self.ordinal())
+    pub fn checked_sub_days(&self, days: i32) -> Option<Self> {
+        Self::from_jdn(self.to_jdn().checked_sub(days)?).ok()
     }
 
-    /// Formats the current date given a format specifires.
+    /// Get the first date after this one falling on `weekday`.
     ///
-    /// currently the supported format specifires are:
-    /// ```txt
+    /// # Examples
     ///
-    /// YY       The last two digits of year (00..99)
-    /// YYYY     Full Year
-    /// M        Month (01..12)
-    /// MM       Abbreviated month name (e.g., መስከ)
-    /// MMM      Full Month Name (e.g., መስከረም)
-    /// D        Day of Month (1..31)
-    /// DD       Day of Week Abbreviated (e.g., ማክሰ)
-    /// DDD      Day of Week (e.g., ማክሰ)
-    /// JJ       Day of Year (001..366)
-    /// QQ       Quarter of Year (1..4)
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.next_weekday(Samint::Arb), Zemen::from_eth_cal(2015, Werh::Tir, 12)?);
+    /// assert_eq!(qen.next_weekday(Samint::Irob), Zemen::from_eth_cal(2015, Werh::Tir, 17)?);
+    /// # Ok::<(), error::Error>(())
     /// ```
     ///
+    /// # Panics
+    ///
+    /// Panics if the date is after the last `weekday` before [`Zemen::MAX`],
+    /// see [`Zemen::checked_next_weekday`].
+    pub fn next_weekday(&self, weekday: Samint) -> Self {
+        self.next().next_weekday_or_same(weekday)
+    }
+
+    /// Get the first date after this one falling on `weekday`, or `None` if
+    /// it's after [`Zemen::MAX`].
+    ///
     /// # Examples
     ///
     /// ```rust
-    /// # use zemen::*;
+    /// # use zemen::{error, Samint, Zemen, Werh};
     /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
-    /// assert_eq!(&qen.format("ዛሬ ቀን DD, MM D-YYYY ነው")[..], "ዛሬ ቀን ረቡዕ, ጥር 10-2015 ነው");
+    ///
+    /// assert_eq!(qen.checked_next_weekday(Samint::Arb), Some(qen + 2));
+    /// assert_eq!(Zemen::MAX.checked_next_weekday(Samint::Arb), None);
     /// # Ok::<(), error::Error>(())
     /// ```
-    pub fn format(&self, pattern: &str) -> String {
-        formatting::format(self, pattern)
+    pub fn checked_next_weekday(&self, weekday: Samint) -> Option<Self> {
+        self.checked_add_days(1)?
+            .checked_next_weekday_or_same(weekday)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::error;
-    use crate::error::Error;
-    use crate::Werh;
-    use crate::Zemen;
-
-    #[test]
-    #[cfg(feature = "time")]
-    fn test_get_current_date_and_convert_to_gre() {
-        let zare = Zemen::today();
-        println!("Zare (initiated): {}", zare);
-
-        let converted_date = zare.to_gre();
-        println!("Today (converted): {}", converted_date);
 
-        let converted_zare = Zemen::from_date(&converted_date);
-        println!("Zare (converted): {}", converted_zare);
+    /// Get the first date from this one on falling on `weekday`, i.e. this
+    /// date if it already falls on `weekday`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.next_weekday_or_same(Samint::Irob), qen);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is after the last `weekday` up to [`Zemen::MAX`],
+    /// see [`Zemen::checked_next_weekday_or_same`].
+    pub fn next_weekday_or_same(&self, weekday: Samint) -> Self {
+        *self + (weekday as i32 - self.weekday() as i32).rem_euclid(7)
     }
 
-    #[test]
-    fn test_month_creating_and_basic_parsing() -> Result<(), error::Error> {
-        let wer_num = Werh::try_from(13)?;
-        let wer_enum_pag = Werh::Puagme;
-
-        assert_eq!(wer_enum_pag, wer_num);
-
-        let wer_enum_mesk = Werh::Meskerem;
-        let wer_str: Werh = "Meskerem".parse()?;
-
-        assert_eq!(wer_enum_mesk, wer_str);
-
-        Ok(())
+    /// Get the first date from this one on falling on `weekday`, or `None`
+    /// if it's after [`Zemen::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.checked_next_weekday_or_same(Samint::Irob), Some(qen));
+    /// assert_eq!(Zemen::MAX.checked_next_weekday_or_same(Zemen::MAX.weekday()), Some(Zemen::MAX));
+    /// assert_eq!(Zemen::MAX.checked_next_weekday_or_same(Zemen::MIN.weekday()), None);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn checked_next_weekday_or_same(&self, weekday: Samint) -> Option<Self> {
+        self.checked_add_days((weekday as i32 - self.weekday() as i32).rem_euclid(7))
     }
 
-    #[test]
-    fn test_zemen_date_range_error() {
-        let err = error::Error::InvalidRange {
-            max: 30,
-            min: 1,
-            given: 31,
-            name: "month",
-        };
-
-        println!("{}", err)
+    /// Get the last date before this one falling on `weekday`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.prev_weekday(Samint::Ihud), Zemen::from_eth_cal(2015, Werh::Tir, 7)?);
+    /// assert_eq!(qen.prev_weekday(Samint::Irob), Zemen::from_eth_cal(2015, Werh::Tir, 3)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is before the first `weekday` after [`Zemen::MIN`],
+    /// see [`Zemen::checked_prev_weekday`].
+    pub fn prev_weekday(&self, weekday: Samint) -> Self {
+        self.previous().prev_weekday_or_same(weekday)
     }
 
-    #[test]
-    #[cfg(feature = "time")]
-    fn test_trait_conversion() -> Result<(), error::Error> {
-        use time::Date;
-
-        let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
-        let day: time::Date = Date::from(&qen);
+    /// Get the last date before this one falling on `weekday`, or `None` if
+    /// it's before [`Zemen::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.checked_prev_weekday(Samint::Ihud), Some(qen - 3));
+    /// assert_eq!(Zemen::MIN.checked_prev_weekday(Samint::Ihud), None);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn checked_prev_weekday(&self, weekday: Samint) -> Option<Self> {
+        self.checked_sub_days(1)?
+            .checked_prev_weekday_or_same(weekday)
+    }
+
+    /// Get the last date up to this one falling on `weekday`, i.e. this date
+    /// if it already falls on `weekday`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.prev_weekday_or_same(Samint::Irob), qen);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is before the first `weekday` from [`Zemen::MIN`]
+    /// on, see [`Zemen::checked_prev_weekday_or_same`].
+    pub fn prev_weekday_or_same(&self, weekday: Samint) -> Self {
+        *self - (self.weekday() as i32 - weekday as i32).rem_euclid(7)
+    }
+
+    /// Get the last date up to this one falling on `weekday`, or `None` if
+    /// it's before [`Zemen::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.checked_prev_weekday_or_same(Samint::Irob), Some(qen));
+    /// assert_eq!(Zemen::MIN.checked_prev_weekday_or_same(Zemen::MIN.weekday()), Some(Zemen::MIN));
+    /// assert_eq!(Zemen::MIN.checked_prev_weekday_or_same(Zemen::MAX.weekday()), None);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn checked_prev_weekday_or_same(&self, weekday: Samint) -> Option<Self> {
+        self.checked_sub_days((self.weekday() as i32 - weekday as i32).rem_euclid(7))
+    }
+
+    /// Get the age, on `as_of`, of someone born on this date as a `Period`.
+    /// Use [`Period::breakdown`] to get the years, months, and days.
+    ///
+    /// The age is worked out on the Ethiopian calendar, so a birthday is
+    /// always on the same month and day. Someone born on Puagme 6 has their
+    /// birthday on Puagme 5 in years that don't have a Puagme 6.
+    ///
+    /// If `as_of` is before the date of birth the period is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let born = Zemen::from_eth_cal(1987, Werh::Puagme, 6)?;
+    ///
+    /// let age = born.age_on(&Zemen::from_eth_cal(2016, Werh::Puagme, 4)?);
+    /// assert_eq!(age.breakdown().years, 28);
+    ///
+    /// let age = born.age_on(&Zemen::from_eth_cal(2016, Werh::Puagme, 5)?);
+    /// assert_eq!(age.breakdown().years, 29);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn age_on(&self, as_of: &Zemen) -> Period {
+        if as_of.to_jdn() < self.to_jdn() {
+            Period::new(*self, *self)
+        } else {
+            Period::between(self, as_of)
+        }
+    }
+
+    /// Get the age, today, of someone born on this date. See
+    /// [`Zemen::age_on`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::Zemen;
+    /// let zare = Zemen::today();
+    ///
+    /// assert!(zare.age().is_empty());
+    /// ```
+    pub fn age(&self) -> Period {
+        self.age_on(&Zemen::today())
+    }
+
+    /// Get the ten day decade (አሥርት) of the month the date is in: 1 for days
+    /// 1 to 10, 2 for days 11 to 20, and 3 for days 21 to 30.
+    ///
+    /// Puagme only has a single dekad, see [`Period::dekad`](crate::Period::dekad).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Tir, 10)?.dekad(), 1);
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Tir, 11)?.dekad(), 2);
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Tir, 30)?.dekad(), 3);
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Puagme, 6)?.dekad(), 1);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn dekad(&self) -> u8 {
+        match self.month() {
+            Werh::Puagme => 1,
+            _ => (self.day() - 1) / 10 + 1,
+        }
+    }
+
+    /// Distribute `value` across the months of `period`, the first and last
+    /// being cut to the days in the period, weighed by `weighting`.
+    ///
+    /// Shares are rounded by the largest remainder, so they always sum to
+    /// exactly `value`; units left over from rounding go to the months with
+    /// the largest remainders, the earlier month first. A negative `value`
+    /// is distributed as its absolute value, and every share negated. An
+    /// empty period gets nothing, so the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Period, Weighting, Zemen, Werh};
+    /// // the last 14 days of Nehase, Puagme, and 10 days of Meskerem
+    /// let period = Period::new(
+    ///     Zemen::from_eth_cal(2015, Werh::Nehase, 17)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Meskerem, 11)?,
+    /// );
+    ///
+    /// let shares = Zemen::distribute(1000, &period, Weighting::ByDayCount);
+    /// assert_eq!(shares.len(), 3);
+    /// assert_eq!(shares[1].0.len_days(), 6);
+    /// assert_eq!(
+    ///     shares.iter().map(|(_, share)| *share).collect::<Vec<_>>(),
+    ///     [467, 200, 333]
+    /// );
+    ///
+    /// let shares = Zemen::distribute(1000, &period, Weighting::Thirty360);
+    /// assert_eq!(
+    ///     shares.iter().map(|(_, share)| *share).collect::<Vec<_>>(),
+    ///     [583, 0, 417]
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn distribute(value: i64, period: &Period, weighting: Weighting) -> Vec<(Period, i64)> {
+        crate::period::distribute(value, period, weighting)
+    }
+
+    /// Iterate over every day of `month` in `year`, see [`Werh::days_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{Zemen, Werh};
+    /// assert_eq!(Zemen::month_days(2015, Werh::Tir).count(), 30);
+    /// assert_eq!(Zemen::month_days(2015, Werh::Puagme).count(), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year` is beyond the years of [`Zemen::MIN`] and
+    /// [`Zemen::MAX`].
+    pub fn month_days(year: i32, month: Werh) -> Days {
+        month.days_iter(year)
+    }
+
+    /// Iterate over the dates from this date up to, but not including, `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let start = Zemen::from_eth_cal(2015, Werh::Puagme, 5)?;
+    /// let end = Zemen::from_eth_cal(2016, Werh::Meskerem, 2)?;
+    ///
+    /// let days: Vec<_> = start.iter_days_until(&end).map(|qen| qen.to_string()).collect();
+    /// assert_eq!(days, ["2015-13-05", "2015-13-06", "2016-01-01"]);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn iter_days_until(&self, end: &Zemen) -> Days {
+        let len = (i64::from(end.to_jdn()) - i64::from(self.to_jdn())).max(0);
+        Days::new(*self, len as usize)
+    }
+
+    /// Get the week of its month the date falls in, cut off at the month's
+    /// first and last day, see [`calendar::werh_weeks`], failing like it in
+    /// the Puagme of [`Zemen::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 3)?;
+    /// let week = qen.week_of_werh_boundaries(Samint::Ihud)?;
+    ///
+    /// assert_eq!(week.number(), 1);
+    /// assert_eq!(week.len_days(), 6);
+    /// assert!(week.is_partial());
+    ///
+    /// assert!(Zemen::MAX.week_of_werh_boundaries(Samint::Ihud).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    #[allow(clippy::expect_used, clippy::missing_panics_doc)]
+    pub fn week_of_werh_boundaries(&self, week_start: Samint) -> Result<WerhWeek> {
+        // the weeks of a month cover every day of it
+        let week = calendar::werh_weeks(self.year(), self.month(), week_start)?
+            .into_iter()
+            .find(|week| week.period().contains(self))
+            .expect("the date is in a week of its month");
+
+        Ok(week)
+    }
+
+    /// Iterate over the dates from this date up to, but not including, `end`.
+    ///
+    /// These are the dates `start..end` yields when the `unstable-step`
+    /// feature, which needs a nightly compiler, is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+    /// let end = Zemen::from_eth_cal(2015, Werh::Yekatit, 1)?;
+    ///
+    /// assert_eq!(start.until(&end).count(), 30);
+    /// assert_eq!(end.until(&start).count(), 0);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn until(&self, end: &Zemen) -> Days {
+        self.iter_days_until(end)
+    }
+
+    /// Get the day of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Meskerem, 15)?;
+    /// assert_eq!(qen.ordinal(), 15);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const fn ordinal(&self) -> u16 {
+        (self.ordinal_date & 0x1ff) as _
+    }
+
+    /// Get the year, and day of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(1992, Werh::Meskerem, 15)?;
+    /// assert_eq!(qen.ordinal_date(), (1992, 15));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn ordinal_date(&self) -> (i32, u16) {
+        (self.year(), self.ordinal())
+    }
+
+    /// Get the date packed into 4 bytes, e.g. to store it in a binary
+    /// protocol. [`Zemen::from_bits`] reads it back.
+    ///
+    /// The low 9 bits are the day of the year, 1 to 366, and the rest is the
+    /// year as a signed number, i.e. `(year << 9) | ordinal`. This layout is
+    /// stable: it won't change without a major version bump. The bits of
+    /// dates compare in the same order as the dates themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.to_bits(), (2015 << 9) | 130);
+    /// assert_eq!(Zemen::from_bits(qen.to_bits())?, qen);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const fn to_bits(&self) -> i32 {
+        self.ordinal_date
+    }
+
+    /// Read a date written by [`Zemen::to_bits`], failing if the day of the
+    /// year isn't in the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_bits((2015 << 9) | 366)?;
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+    ///
+    /// // 2016 isn't a leap year
+    /// assert!(Zemen::from_bits((2016 << 9) | 366).is_err());
+    /// assert!(Zemen::from_bits(2016 << 9).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub const fn from_bits(bits: i32) -> Result<Self> {
+        Self::from_ordinal_date(bits >> 9, (bits & 0x1ff) as u16)
+    }
+
+    /// Advance (or with a negative `months`, go back) by a number of months,
+    /// keeping the day of the month.
+    ///
+    /// Every month has 30 days except Puagme, so `overflow` decides what
+    /// happens when the day doesn't exist in the target month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, MonthOverflow, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Meskerem, 30)?;
+    /// assert_eq!(
+    ///     qen.add_months(1, MonthOverflow::Reject)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Tikimit, 30)?
+    /// );
+    ///
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Nehase, 30)?;
+    /// assert_eq!(
+    ///     qen.add_months(1, MonthOverflow::Clamp)?,
+    ///     Zemen::from_eth_cal(2015, Werh::Puagme, 6)?
+    /// );
+    /// assert_eq!(
+    ///     qen.add_months(1, MonthOverflow::Rollover)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Meskerem, 24)?
+    /// );
+    /// assert!(qen.add_months(1, MonthOverflow::Reject).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn add_months(&self, months: i32, overflow: MonthOverflow) -> Result<Self> {
+        self.shift_months(months as i64, overflow)
+    }
+
+    /// Advance (or with a negative `years`, go back) by a number of years,
+    /// keeping the month and the day.
+    ///
+    /// Only Puagme 6 can be missing from the target year, `overflow` decides
+    /// whether it becomes Puagme 5 (`Clamp`), Meskerem 1 of the following
+    /// year (`Rollover`), or an error (`Reject`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, MonthOverflow, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+    ///
+    /// assert_eq!(
+    ///     qen.add_years(1, MonthOverflow::Clamp)?,
+    ///     Zemen::from_eth_cal(2016, Werh::Puagme, 5)?
+    /// );
+    /// assert_eq!(
+    ///     qen.add_years(1, MonthOverflow::Rollover)?,
+    ///     Zemen::from_eth_cal(2017, Werh::Meskerem, 1)?
+    /// );
+    /// assert_eq!(
+    ///     qen.add_years(4, MonthOverflow::Reject)?,
+    ///     Zemen::from_eth_cal(2019, Werh::Puagme, 6)?
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn add_years(&self, years: i32, overflow: MonthOverflow) -> Result<Self> {
+        self.shift_months(years as i64 * 13, overflow)
+    }
+
+    /// Advance by a number of years, returning `None` if the same month and
+    /// day don't exist in the target year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+    ///
+    /// assert_eq!(qen.checked_add_years(1), None);
+    /// assert_eq!(qen.checked_add_years(-4), Some(Zemen::from_eth_cal(2011, Werh::Puagme, 6)?));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn checked_add_years(&self, years: i32) -> Option<Self> {
+        self.add_years(years, MonthOverflow::Reject).ok()
+    }
+
+    /// Get the same month and day in `year`, failing if the date doesn't
+    /// exist, i.e. Puagme 6 outside of leap years.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    /// assert_eq!(qen.replace_year(2016)?, Zemen::from_eth_cal(2016, Werh::Tir, 10)?);
+    ///
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 6)?;
+    /// assert!(qen.replace_year(2016).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn replace_year(&self, year: i32) -> Result<Self> {
+        let (_, month, day) = self.to_calendar_date();
+        Self::from_eth_cal(year, month, day)
+    }
+
+    /// Get the same day of `month` in the same year, failing if the date
+    /// doesn't exist, e.g. Puagme 20.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 20)?;
+    ///
+    /// assert_eq!(qen.replace_month(Werh::Yekatit)?, Zemen::from_eth_cal(2015, Werh::Yekatit, 20)?);
+    /// assert!(qen.replace_month(Werh::Puagme).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn replace_month(&self, month: Werh) -> Result<Self> {
+        let (year, _, day) = self.to_calendar_date();
+        Self::from_eth_cal(year, month, day)
+    }
+
+    /// Get `day` of the same month and year, failing if the date doesn't
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 20)?;
+    ///
+    /// assert_eq!(qen.replace_day(1)?, Zemen::from_eth_cal(2015, Werh::Tir, 1)?);
+    /// assert!(qen.replace_day(31).is_err());
+    /// assert!(qen.replace_day(0).is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn replace_day(&self, day: u8) -> Result<Self> {
+        let (year, month, _) = self.to_calendar_date();
+        Self::from_eth_cal(year, month, day)
+    }
+
+    /// Get the first day of the month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 20)?;
+    ///
+    /// assert_eq!(qen.start_of_month(), Zemen::from_eth_cal(2015, Werh::Tir, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn start_of_month(&self) -> Self {
+        self.with_ordinal(self.ordinal() - (self.day() as u16 - 1))
+    }
+
+    /// Get the last day of the month, i.e. the 30th, or Puagme 5 or 6.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Puagme, 2)?;
+    /// assert_eq!(qen.end_of_month(), Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+    ///
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Puagme, 2)?;
+    /// assert_eq!(qen.end_of_month(), Zemen::from_eth_cal(2016, Werh::Puagme, 5)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn end_of_month(&self) -> Self {
+        let (year, month, day) = self.to_calendar_date();
+        let last = validator::days_in_month(year, month as u8);
+
+        self.with_ordinal(self.ordinal() + (last - day) as u16)
+    }
+
+    /// Get Meskerem 1 of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 20)?;
+    ///
+    /// assert_eq!(qen.start_of_year(), Zemen::from_eth_cal(2015, Werh::Meskerem, 1)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn start_of_year(&self) -> Self {
+        self.with_ordinal(1)
+    }
+
+    /// Get the last day of the year, i.e. Puagme 5 or 6.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 20)?;
+    ///
+    /// assert_eq!(qen.end_of_year(), Zemen::from_eth_cal(2015, Werh::Puagme, 6)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn end_of_year(&self) -> Self {
+        self.with_ordinal(validator::days_in_year(self.year()))
+    }
+
+    /// Get the week of the year the date is in, as the year the week belongs
+    /// to and its number, starting at 1.
+    ///
+    /// The year is the Ethiopian year for [`WeekNumbering::Ethiopian`], and
+    /// the Gregorian week-numbering year for [`WeekNumbering::Iso`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{calendar::WeekNumbering, error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.week_of_year(WeekNumbering::Ethiopian(Samint::Ihud)), (2015, 19));
+    /// assert_eq!(qen.week_of_year(WeekNumbering::Ethiopian(Samint::Senyo)), (2015, 20));
+    /// assert_eq!(qen.week_of_year(WeekNumbering::Iso), (2023, 3));
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn week_of_year(&self, numbering: WeekNumbering) -> (i32, u8) {
+        calendar::week_of_year(self, numbering)
+    }
+
+    /// Get the day `ordinal` of the same year.
+    #[allow(clippy::expect_used)]
+    fn with_ordinal(&self, ordinal: u16) -> Self {
+        Self::checked_from_ordinal_date(self.year(), ordinal).expect("the day is in the same year")
+    }
+
+    fn shift_months(&self, months: i64, overflow: MonthOverflow) -> Result<Self> {
+        let index = self.year() as i64 * 13 + (self.month() as i64 - 1) + months;
+        let year = index.div_euclid(13) as i32;
+        let month = (index.rem_euclid(13) + 1) as u8;
+
+        let day = self.day();
+        let last = validator::days_in_month(year, month);
+
+        if day <= last {
+            return Self::new(year, month, day);
+        }
+
+        match overflow {
+            MonthOverflow::Clamp => Self::new(year, month, last),
+            MonthOverflow::Reject => Err(error::Error::InvalidRange {
+                name: validator::day_of(month),
+                given: day as i32,
+                min: 1,
+                max: last as i32,
+            }),
+            MonthOverflow::Rollover => {
+                Self::from_jdn(conversion::eth_to_jdn(year, month as i32, 1) + day as i32 - 1)
+            }
+        }
+    }
+
+    /// Get a short key naming the `granularity` sized period this date falls
+    /// in, suitable for cache keys and storage prefixes.
+    ///
+    /// The keys look like:
+    /// ```txt
+    ///
+    /// Day      2016-05-10
+    /// Week     2016-W14
+    /// Month    2016-Tir
+    /// Year     2016
+    /// ```
+    ///
+    /// Keys are ASCII only, and the key given to a date for a granularity is
+    /// part of the stable API: it won't change in future releases.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Granularity, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.cache_key(Granularity::Day), "2016-05-10");
+    /// assert_eq!(qen.cache_key(Granularity::Week), "2016-W19");
+    /// assert_eq!(qen.cache_key(Granularity::Month), "2016-Tir");
+    /// assert_eq!(qen.cache_key(Granularity::Year), "2016");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn cache_key(&self, granularity: Granularity) -> String {
+        match granularity {
+            Granularity::Day => self.to_string(),
+            Granularity::Week => {
+                format!("{:04}-W{:02}", self.year(), (self.ordinal() - 1) / 7 + 1)
+            }
+            Granularity::Month => format!("{:04}-{}", self.year(), self.month().latin_name()),
+            Granularity::Year => format!("{:04}", self.year()),
+        }
+    }
+
+    /// Get the Hive style partition path of the date, e.g.
+    /// `year=2016/month=05/day=10`, with either Ethiopian or Gregorian
+    /// components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, PartitionStyle, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 11)?;
+    ///
+    /// assert_eq!(qen.partition_path(PartitionStyle::Ethiopian), "year=2015/month=05/day=11");
+    /// assert_eq!(qen.partition_path(PartitionStyle::Gregorian), "year=2023/month=01/day=19");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn partition_path(&self, style: PartitionStyle) -> String {
+        partition::partition_path(self, style)
+    }
+
+    /// Parse a date from the `year=`, `month=`, and `day=` segments of a
+    /// partition path. Any other segment, like a bucket prefix or a file
+    /// name, is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, PartitionStyle, Zemen, Werh};
+    /// let qen = Zemen::from_partition_path(
+    ///     "visits/year=2023/month=01/day=19/part-0.parquet",
+    ///     PartitionStyle::Gregorian,
+    /// )?;
+    ///
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 11)?);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn from_partition_path(path: &str, style: PartitionStyle) -> Result<Self> {
+        partition::parse(path, style)
+    }
+
+    /// Encode the date as a six character code, e.g. `2B2NMM`, short enough to
+    /// print on a ticket or send in an SMS.
+    ///
+    /// The code is five base32 digits and a check digit, using the Crockford
+    /// alphabet which has no `I`, `L`, `O`, or `U`. Only dates whose Julian
+    /// day number is between 0 and 2^25 - 1 have a code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Tir, 21)?;
+    /// let code = qen.to_base32_code()?;
+    ///
+    /// assert_eq!(code, "2B2NMM");
+    /// assert_eq!(Zemen::from_base32_code(&code)?, qen);
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn to_base32_code(&self) -> Result<String> {
+        base32::encode(self)
+    }
+
+    /// Parse a code made by [`Zemen::to_base32_code`].
+    ///
+    /// Case, dashes, and spaces are ignored, `O` is read as `0`, and `I` or
+    /// `L` as `1`. Codes with a wrong check digit, which catches any single
+    /// mistyped character, are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let code = Zemen::from_eth_cal(2016, Werh::Tir, 21)?.to_base32_code()?;
+    ///
+    /// let (head, tail) = code.split_at(3);
+    /// let retyped = format!("{}-{}", head, tail).to_lowercase();
+    /// assert_eq!(Zemen::from_base32_code(&retyped)?.to_string(), "2016-05-21");
+    ///
+    /// assert!(Zemen::from_base32_code("2B2NM").is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn from_base32_code(code: &str) -> Result<Self> {
+        base32::decode(code)
+    }
+
+    /// Writes the date out in full for screen readers and text to speech,
+    /// without abbreviations and with as little punctuation as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Locale, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 13)?;
+    ///
+    /// assert_eq!(qen.accessible_label(Locale::Amharic), "ቅዳሜ ጥር 13 ቀን 2015 ዓመተ ምሕረት");
+    /// assert_eq!(qen.accessible_label(Locale::English), "Saturday 13 Tir 2015");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn accessible_label(&self, locale: Locale) -> String {
+        locale::accessible_label(self, locale)
+    }
+
+    /// Formats the date for USSD menus and SMS, e.g. `21 Tir 2016`.
+    ///
+    /// The output only has ASCII letters, digits, and spaces, which are all
+    /// GSM-7 characters, and for the years 0 to 9999 it is always
+    /// [`Zemen::USSD_MAX_LEN`] characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2016, Werh::Tir, 1)?;
+    ///
+    /// assert_eq!(qen.format_ussd(), "01 Tir 2016");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn format_ussd(&self) -> String {
+        ussd::format(self)
+    }
+
+    /// Parse a date written by [`Zemen::format_ussd`].
+    ///
+    /// The month may be in any case, and either abbreviated or in full, and
+    /// the day doesn't need a leading zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::parse_ussd("1 tir 2016")?, Zemen::from_eth_cal(2016, Werh::Tir, 1)?);
+    /// assert!(Zemen::parse_ussd("2016-05-01").is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn parse_ussd(s: &str) -> Result<Self> {
+        ussd::parse(s)
+    }
+
+    /// Formats the date as an ISO 8601 calendar date, `YYYY-MM-DD`, with the
+    /// Ethiopian year, month, and day.
+    ///
+    /// Years outside of 0 to 9999 are written expanded, with a sign and at
+    /// least 5 digits. Unlike `Display`, this is always valid ISO 8601.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// assert_eq!(Zemen::from_eth_cal(2015, Werh::Puagme, 5)?.format_iso8601(), "2015-13-05");
+    /// assert_eq!(Zemen::from_eth_cal(12_000, Werh::Tir, 10)?.format_iso8601(), "+12000-05-10");
+    /// assert_eq!(Zemen::from_eth_cal(-5, Werh::Tir, 10)?.format_iso8601(), "-00005-05-10");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn format_iso8601(&self) -> String {
+        iso8601::format(self)
+    }
+
+    /// Formats the date as an ISO 8601 ordinal date, `YYYY-DDD`, with the
+    /// Ethiopian year and day of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.format_iso8601_ordinal(), "2015-130");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn format_iso8601_ordinal(&self) -> String {
+        iso8601::format_ordinal(self)
+    }
+
+    /// Parse an ISO 8601 calendar or ordinal date, in the basic or extended
+    /// format, with the Ethiopian year, month, and day.
+    ///
+    /// Expanded years, with a sign, have to be in the extended format, i.e.
+    /// separated with `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(Zemen::parse_iso8601("2015-05-10")?, qen);
+    /// assert_eq!(Zemen::parse_iso8601("20150510")?, qen);
+    /// assert_eq!(Zemen::parse_iso8601("2015-130")?, qen);
+    /// assert_eq!(Zemen::parse_iso8601("+02015-05-10")?, qen);
+    ///
+    /// assert!(Zemen::parse_iso8601("2015-5-10").is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<Self> {
+        iso8601::parse(s)
+    }
+
+    /// Formats the date with every [`DisplayPreset`](crate::DisplayPreset),
+    /// paired with the name of the preset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(
+    ///     qen.to_tuple_string_styles(),
+    ///     [
+    ///         ("GOV_LETTER", "ጥር 10 ቀን 2015 ዓ.ም".to_string()),
+    ///         ("BANK_STATEMENT", "10-ጥር-2015".to_string()),
+    ///         ("SHORT_SLASH", "10/05/15".to_string()),
+    ///         ("ISO", "2015-05-10".to_string()),
+    ///     ]
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn to_tuple_string_styles(&self) -> Vec<(&'static str, String)> {
+        crate::DisplayPreset::ALL
+            .iter()
+            .map(|preset| (preset.name(), preset.format(self)))
+            .collect()
+    }
+
+    /// Formats the current date given a format specifires.
+    ///
+    /// currently the supported format specifires are:
+    /// ```txt
+    ///
+    /// YY       The last two digits of year (00..99)
+    /// YYYY     Full Year
+    /// M        Month (01..12)
+    /// MM       Abbreviated month name (e.g., መስከ)
+    /// MMM      Full Month Name (e.g., መስከረም)
+    /// D        Day of Month (1..31)
+    /// DD       Day of Week Abbreviated (e.g., ማክሰ)
+    /// DDD      Day of Week (e.g., ማክሰ)
+    /// JJ       Day of Year (001..366)
+    /// QQ       Quarter of Year (1..4)
+    /// ```
+    ///
+    /// See [`format::specifiers`](crate::format::specifiers) for the same list
+    /// in code. The longest specifier wins, e.g. `MMMM` is `MMM` then `M`.
+    ///
+    /// Text between `[` and `]` is copied as it is, so words with specifier
+    /// letters in them can be written out, and `[[]` writes a `[`.
+    ///
+    /// Formatting is lenient: anything that isn't a specifier, including
+    /// stray letters like the `X` in `MX`, is copied to the output as it is.
+    /// Use [`Zemen::try_format`] to reject those instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::*;
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    /// assert_eq!(&qen.format("ዛሬ ቀን DD, MM D-YYYY ነው")[..], "ዛሬ ቀን ረቡዕ, ጥር 10-2015 ነው");
+    /// assert_eq!(qen.format("[Monthly] MM [report] D"), "Monthly ጥር report 10");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        formatting::format(self, pattern)
+    }
+
+    /// Formats the date like [`Zemen::format`], with the numbers written
+    /// in `digits`. Text in the pattern is copied as it is, digits included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, DigitStyle, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(
+    ///     qen.format_digits("MMM D ቀን YYYY ዓ.ም", DigitStyle::Ethiopic),
+    ///     "ጥር ፲ ቀን ፳፻፲፭ ዓ.ም"
+    /// );
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn format_digits(&self, pattern: &str, digits: DigitStyle) -> String {
+        formatting::format_digits(self, pattern, digits)
+    }
+
+    /// Formats the date like `Display`, `YYYY-MM-DD`, with the numbers
+    /// written in `digits`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, DigitStyle, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.render_digits(DigitStyle::Ethiopic), "፳፻፲፭-፭-፲");
+    /// assert_eq!(qen.render_digits(DigitStyle::ArabicIndic), "٢٠١٥-٠٥-١٠");
+    /// assert_eq!(qen.render_digits(DigitStyle::Ascii), qen.to_string());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn render_digits(&self, digits: DigitStyle) -> String {
+        digits.apply(&self.to_string())
+    }
+
+    /// Formats the date like [`Zemen::format`], but fails on letters that
+    /// aren't a specifier instead of copying them, so typos in patterns are
+    /// caught.
+    ///
+    /// The error names the unknown letters and their position in the
+    /// pattern, counting characters. Letters meant as text go between `[`
+    /// and `]`, e.g. `[Day] D`, and an unclosed `[` is reported like an
+    /// unknown specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// assert_eq!(qen.try_format("D-M-YYYY")?, "10-05-2015");
+    ///
+    /// let err = qen.try_format("D-M-YYYYY").unwrap_err();
+    /// assert_eq!(err.to_string(), "unknown format specifier `Y` at position 8");
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn try_format(&self, pattern: &str) -> Result<String> {
+        formatting::try_format(self, pattern)
+    }
+
+    /// Formats the date like [`Zemen::try_format`], also writing the week of
+    /// the year, numbered by `numbering`, for `WW` (01..53).
+    ///
+    /// `WW` is only understood here, so the output of [`Zemen::format`] for
+    /// patterns with `W`s in them doesn't change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{calendar::WeekNumbering, error, Samint, Zemen, Werh};
+    /// let qen = Zemen::from_eth_cal(2015, Werh::Tir, 10)?;
+    ///
+    /// let ihud = WeekNumbering::Ethiopian(Samint::Ihud);
+    /// assert_eq!(qen.try_format_weeks("[Week] WW, YYYY", ihud)?, "Week 19, 2015");
+    /// assert_eq!(qen.try_format_weeks("WW", WeekNumbering::Ethiopian(Samint::Senyo))?, "20");
+    /// assert_eq!(qen.try_format_weeks("WW", WeekNumbering::Iso)?, "03");
+    ///
+    /// assert_eq!(qen.format("WW"), "WW");
+    /// assert!(qen.try_format("WW").is_err());
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn try_format_weeks(&self, pattern: &str, numbering: WeekNumbering) -> Result<String> {
+        formatting::try_format_weeks(self, pattern, numbering)
+    }
+
+    /// Parse a date written with a [`FormatDescription`], e.g. from a log
+    /// line.
+    ///
+    /// The input is read as bytes, so it doesn't have to be valid UTF-8
+    /// outside of the date, and nothing is allocated, not even when parsing
+    /// fails: a mismatch is reported as [`Error::InvalidBytes`], with the
+    /// position of the byte it was found at.
+    ///
+    /// [`Error::InvalidBytes`]: crate::error::Error::InvalidBytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zemen::{error, format::FormatDescription, Zemen, Werh};
+    /// let description = FormatDescription::parse("YYYY-M-D")?;
+    ///
+    /// let qen = Zemen::parse_bytes(b"2015-05-10", &description)?;
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    ///
+    /// // the month has one digit
+    /// assert!(matches!(
+    ///     Zemen::parse_bytes(b"2015-5-10", &description),
+    ///     Err(error::Error::InvalidBytes { position: 5, len: 9 })
+    /// ));
+    ///
+    /// let description = FormatDescription::parse("MMM D ቀን YYYY")?;
+    /// let qen = Zemen::parse_bytes("ጥር 10 ቀን 2015".as_bytes(), &description)?;
+    /// assert_eq!(qen, Zemen::from_eth_cal(2015, Werh::Tir, 10)?);
+    ///
+    /// # Ok::<(), error::Error>(())
+    /// ```
+    pub fn parse_bytes(bytes: &[u8], description: &FormatDescription) -> Result<Self> {
+        format::parse_bytes(bytes, description)
+    }
+}
+
+/// Steps a day at a time, so `for qen in start..end` works on nightly.
+#[cfg(feature = "unstable-step")]
+impl std::iter::Step for Zemen {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        // the days between far apart dates overflow an `i32`
+        match usize::try_from(i64::from(end.to_jdn()) - i64::from(start.to_jdn())) {
+            Ok(steps) => (steps, Some(steps)),
+            Err(_) => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        let jdn = i64::from(start.to_jdn()).checked_add(i64::try_from(count).ok()?)?;
+        Zemen::from_jdn(i32::try_from(jdn).ok()?).ok()
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let jdn = i64::from(start.to_jdn()).checked_sub(i64::try_from(count).ok()?)?;
+        Zemen::from_jdn(i32::try_from(jdn).ok()?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_YEAR, MIN_YEAR};
+    use crate::error;
+    use crate::error::Error;
+    use crate::Werh;
+    use crate::Zemen;
+    use crate::{calendar, Period, Samint};
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_get_current_date_and_convert_to_gre() {
+        let zare = Zemen::today();
+        println!("Zare (initiated): {}", zare);
+
+        let converted_date = zare.to_gre();
+        println!("Today (converted): {}", converted_date);
+
+        let converted_zare = Zemen::from_date(&converted_date);
+        println!("Zare (converted): {}", converted_zare);
+    }
+
+    #[test]
+    fn test_month_creating_and_basic_parsing() -> Result<(), error::Error> {
+        let wer_num = Werh::try_from(13)?;
+        let wer_enum_pag = Werh::Puagme;
+
+        assert_eq!(wer_enum_pag, wer_num);
+
+        let wer_enum_mesk = Werh::Meskerem;
+        let wer_str: Werh = "Meskerem".parse()?;
+
+        assert_eq!(wer_enum_mesk, wer_str);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_zero_is_rejected() {
+        // day 0 used to be accepted, and ended up in the previous month
+        for month in Werh::iter() {
+            assert!(matches!(
+                Zemen::from_eth_cal(2015, month, 0),
+                Err(Error::InvalidRange {
+                    given: 0,
+                    min: 1,
+                    ..
+                })
+            ));
+            assert_eq!(Zemen::checked_from_eth_cal(2015, month, 0), None);
+        }
+
+        assert!("2015-05-00".parse::<Zemen>().is_err());
+    }
+
+    #[test]
+    fn test_zemen_date_range_error() {
+        let err = error::Error::InvalidRange {
+            max: 13,
+            min: 1,
+            given: 14,
+            name: "month",
+        };
+
+        println!("{}", err)
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_trait_conversion() -> Result<(), error::Error> {
+        use time::Date;
+
+        let qen = Zemen::from_eth_cal(1992, Werh::Tahasass, 22)?;
+        let day: time::Date = Date::from(&qen);
 
         println!("qen: {}", qen);
         println!("day: {}", day);
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_naive_date_conversion() -> Result<(), error::Error> {
+        use chrono::NaiveDate;
+
+        let start = Zemen::from_eth_cal(2010, Werh::Meskerem, 1)?;
+        for qen in start.iter_days_until(&(start + 1500)) {
+            let date = qen.to_naive_date()?;
+            let (year, month, day) = crate::conversion::jdn_to_gre(qen.to_jdn());
+
+            assert_eq!(
+                date,
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+            );
+            assert_eq!(Zemen::from(&date), qen);
+        }
+
+        for date in [NaiveDate::MIN, NaiveDate::MAX] {
+            assert_eq!(Zemen::from(&date).to_naive_date()?, date);
+        }
+        assert!((Zemen::from(&NaiveDate::MAX) + 1).to_naive_date().is_err());
+        assert!((Zemen::from(&NaiveDate::MIN) - 1).to_naive_date().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_datetime_uses_its_own_timezone() -> Result<(), error::Error> {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        let qen = Zemen::from_eth_cal(2016, Werh::Meskerem, 1)?;
+        let midnight = Utc.from_utc_datetime(&qen.to_naive_date()?.and_hms_opt(0, 0, 0).unwrap());
+
+        for (hours, expected) in [(-12, qen - 1), (-1, qen - 1), (0, qen), (3, qen), (14, qen)] {
+            let offset = FixedOffset::east_opt(hours * 3600).unwrap();
+            assert_eq!(
+                Zemen::from_datetime(&midnight.with_timezone(&offset)),
+                expected
+            );
+        }
+        assert_eq!(
+            Zemen::from_datetime(&chrono::DateTime::<Utc>::MAX_UTC),
+            Zemen::from(&chrono::NaiveDate::MAX)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lenient_parts_match_day_arithmetic() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(2014, Werh::Meskerem, 1)?;
+
+        for offset in -400..400 {
+            assert_eq!(
+                Zemen::from_parts_lenient(2014, 1, 1 + offset)?,
+                start + offset
+            );
+        }
+        for month in -30..30 {
+            let qen = Zemen::from_parts_lenient(2015, month, 1)?;
+            assert_eq!(qen.day(), 1);
+            assert_eq!(qen.month() as i32, (month - 1).rem_euclid(13) + 1);
+            assert_eq!(qen.year(), 2015 + (month - 1).div_euclid(13));
+        }
+
+        assert_eq!(Zemen::from_parts_lenient(MAX_YEAR, 13, 6)?, Zemen::MAX);
+        assert!(Zemen::from_parts_lenient(MAX_YEAR, 13, 7).is_err());
+        assert!(Zemen::from_parts_lenient(MAX_YEAR, 14, 1).is_err());
+        assert!(Zemen::from_parts_lenient(MIN_YEAR, 1, 0).is_err());
+        assert!(Zemen::from_parts_lenient(i32::MAX, i32::MAX, i32::MAX).is_err());
+        assert!(Zemen::from_parts_lenient(i32::MIN, i32::MIN, i32::MIN).is_err());
+        assert!(Zemen::from_parts_lenient(0, 1, i32::MAX).is_err());
+        assert!(matches!(
+            Zemen::from_parts_lenient(i32::MAX, i32::MAX, 1),
+            Err(Error::InvalidRange {
+                name: "year",
+                given: i32::MAX,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Zemen::from_parts_lenient(0, 1, i32::MAX),
+            Err(Error::InvalidRange {
+                name: "day",
+                given: i32::MAX,
+                ..
+            })
+        ));
+        assert!(Zemen::from_parts_lenient(MAX_YEAR, 13, i32::MIN).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_epoch_days_round_trip() -> Result<(), error::Error> {
+        for qen in [Zemen::MIN, Zemen::MAX, Zemen::today()] {
+            assert_eq!(Zemen::from_epoch_day(qen.to_epoch_day())?, qen);
+        }
+        assert!(Zemen::from_epoch_day(Zemen::MIN.to_epoch_day() - 1).is_err());
+        assert!(Zemen::from_epoch_day(Zemen::MAX.to_epoch_day() + 1).is_err());
+        assert!(Zemen::from_epoch_day(i32::MIN).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bits_round_trip() -> Result<(), error::Error> {
+        let start = Zemen::from_eth_cal(-3, Werh::Meskerem, 1)?;
+        let mut previous = start.to_bits() - 1;
+
+        for qen in start.iter_days_until(&(start + 3000)) {
+            assert_eq!(Zemen::from_bits(qen.to_bits())?, qen);
+            assert!(qen.to_bits() > previous);
+            previous = qen.to_bits();
+        }
+
+        for qen in [Zemen::MIN, Zemen::MAX] {
+            assert_eq!(Zemen::from_bits(qen.to_bits())?, qen);
+        }
+        assert!(Zemen::from_bits(i32::MIN).is_err());
+        assert!(Zemen::from_bits(i32::MAX).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_ordinal_date_creation() {
         let qen = Zemen::from_ordinal_date(2001, 366);
@@ -557,6 +2666,154 @@ mod tests {
         assert!(qen.is_ok());
     }
 
+    #[test]
+    fn test_parse_from_display() -> Result<(), Error> {
+        let qen = Zemen::from_eth_cal(2003, Werh::Puagme, 6)?;
+        assert_eq!(qen, qen.to_string().parse()?);
+
+        assert!("2003".parse::<Zemen>().is_err());
+        assert!("2003-01".parse::<Zemen>().is_err());
+        assert!("2003-xx-01".parse::<Zemen>().is_err());
+        assert!("2001-13-06".parse::<Zemen>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_adding_months() -> Result<(), Error> {
+        use crate::MonthOverflow;
+
+        let qen = Zemen::from_eth_cal(2000, Werh::Puagme, 3)?;
+        assert_eq!(
+            qen.add_months(1, MonthOverflow::Reject)?,
+            Zemen::from_eth_cal(2001, Werh::Meskerem, 3)?
+        );
+        assert_eq!(
+            qen.add_months(-13, MonthOverflow::Reject)?,
+            Zemen::from_eth_cal(1999, Werh::Puagme, 3)?
+        );
+
+        let qen = Zemen::from_eth_cal(2000, Werh::Tir, 30)?;
+        assert_eq!(
+            qen.add_months(8, MonthOverflow::Clamp)?,
+            Zemen::from_eth_cal(2000, Werh::Puagme, 5)?
+        );
+        assert_eq!(
+            qen.add_months(8, MonthOverflow::Rollover)?,
+            Zemen::from_eth_cal(2001, Werh::Meskerem, 25)?
+        );
+        assert!(qen.add_months(8, MonthOverflow::Reject).is_err());
+
+        let qen = Zemen::from_eth_cal(2000, Werh::Meskerem, 1)?;
+        assert_eq!(
+            qen.add_months(-1, MonthOverflow::Reject)?,
+            Zemen::from_eth_cal(1999, Werh::Puagme, 1)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_adding_years() -> Result<(), Error> {
+        use crate::MonthOverflow;
+
+        let qen = Zemen::from_eth_cal(2000, Werh::Tir, 30)?;
+        assert_eq!(
+            qen.add_years(-2000, MonthOverflow::Reject)?,
+            Zemen::from_eth_cal(0, Werh::Tir, 30)?
+        );
+
+        let qen = Zemen::from_eth_cal(2003, Werh::Puagme, 6)?;
+        assert_eq!(
+            qen.add_years(-1, MonthOverflow::Clamp)?,
+            Zemen::from_eth_cal(2002, Werh::Puagme, 5)?
+        );
+        assert_eq!(
+            qen.add_years(-1, MonthOverflow::Rollover)?,
+            Zemen::from_eth_cal(2003, Werh::Meskerem, 1)?
+        );
+        assert!(qen.add_years(2, MonthOverflow::Reject).is_err());
+        assert!(qen.checked_add_years(2).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_age_on() -> Result<(), Error> {
+        let born = Zemen::from_eth_cal(2000, Werh::Puagme, 5)?;
+
+        let age = born.age_on(&Zemen::from_eth_cal(2003, Werh::Puagme, 6)?);
+        assert_eq!(age.breakdown().years, 3);
+        assert_eq!(age.breakdown().days, 1);
+
+        let age = born.age_on(&Zemen::from_eth_cal(2004, Werh::Puagme, 4)?);
+        assert_eq!(age.breakdown().years, 3);
+        assert_eq!(age.breakdown().months, 12);
+
+        let age = born.age_on(&Zemen::from_eth_cal(1999, Werh::Tir, 1)?);
+        assert!(age.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_keys_are_stable() -> Result<(), Error> {
+        use crate::Granularity;
+
+        let qen = Zemen::from_eth_cal(2003, Werh::Puagme, 6)?;
+        assert_eq!(qen.cache_key(Granularity::Day), "2003-13-06");
+        assert_eq!(qen.cache_key(Granularity::Week), "2003-W53");
+        assert_eq!(qen.cache_key(Granularity::Month), "2003-Puagme");
+        assert_eq!(qen.cache_key(Granularity::Year), "2003");
+
+        let qen = Zemen::from_eth_cal(2004, Werh::Meskerem, 7)?;
+        assert_eq!(qen.cache_key(Granularity::Week), "2004-W01");
+        assert_eq!((qen + 1).cache_key(Granularity::Week), "2004-W02");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assign_operators() -> Result<(), Error> {
+        use crate::ZemenDuration;
+
+        let mut qen = Zemen::from_eth_cal(2003, Werh::Puagme, 1)?;
+        qen += 6;
+        assert_eq!(qen, Zemen::from_eth_cal(2004, Werh::Meskerem, 1)?);
+
+        qen -= 1;
+        assert_eq!(qen, Zemen::from_eth_cal(2003, Werh::Puagme, 6)?);
+
+        qen += ZemenDuration {
+            years: 1,
+            months: 1,
+            days: 2,
+        };
+        assert_eq!(qen, Zemen::from_eth_cal(2005, Werh::Meskerem, 7)?);
+
+        qen -= ZemenDuration::weeks(1);
+        assert_eq!(qen, Zemen::from_eth_cal(2004, Werh::Puagme, 5)?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_time_duration_truncates_to_days() -> Result<(), Error> {
+        let mut qen = Zemen::from_eth_cal(2003, Werh::Puagme, 6)?;
+
+        qen += time::Duration::hours(23);
+        assert_eq!(qen, Zemen::from_eth_cal(2003, Werh::Puagme, 6)?);
+
+        qen += time::Duration::hours(24);
+        assert_eq!(qen, Zemen::from_eth_cal(2004, Werh::Meskerem, 1)?);
+
+        qen -= time::Duration::weeks(1);
+        assert_eq!(qen, Zemen::from_eth_cal(2003, Werh::Nehase, 30)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_adding_days_to_zemen() -> Result<(), Error> {
         let qen = Zemen::from_eth_cal(2000, Werh::Meskerem, 1)?;
@@ -576,4 +2833,492 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_order_matches_jdn() -> Result<(), Error> {
+        let start = Zemen::from_eth_cal(2002, Werh::Nehase, 20)?;
+        let dates: Vec<_> = (0..900).step_by(11).map(|days| start + days).collect();
+
+        for pair in dates.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert_eq!(
+                pair[0].cmp(&pair[1]),
+                pair[0].to_jdn().cmp(&pair[1].to_jdn())
+            );
+        }
+
+        let keyed: std::collections::BTreeMap<_, _> =
+            dates.iter().rev().cloned().zip(0..).collect();
+        assert!(keyed.keys().eq(dates.iter()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_constructors_agree() {
+        for year in [MIN_YEAR, 2015, 2016, MAX_YEAR, MAX_YEAR + 1] {
+            for month in Werh::iter() {
+                for day in 0..=31 {
+                    let qen = Zemen::from_eth_cal(year, month, day).ok();
+                    assert_eq!(Zemen::checked_from_eth_cal(year, month, day), qen);
+
+                    if let Some(qen) = qen {
+                        let ordinal = Zemen::checked_from_ordinal_date(year, qen.ordinal());
+                        assert_eq!(ordinal, Some(qen));
+                        assert_eq!((qen.month(), qen.day()), (month, day));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_month_and_year_boundaries() -> Result<(), Error> {
+        let start = Zemen::from_eth_cal(2014, Werh::Meskerem, 1)?;
+
+        for qen in start.iter_days_until(&(start + 3 * 366)) {
+            let (year, month, _) = qen.to_calendar_date();
+
+            assert_eq!(qen.start_of_month(), Zemen::from_eth_cal(year, month, 1)?);
+            assert_eq!(qen.end_of_month().next().day(), 1);
+            assert_eq!(qen.end_of_month().month(), month);
+            assert_eq!(qen.start_of_year().previous().year(), year - 1);
+            assert_eq!(
+                qen.end_of_year().next(),
+                Zemen::from_eth_cal(year + 1, Werh::Meskerem, 1)?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() -> Result<(), Error> {
+        for year in [2015, 2016] {
+            for month in Werh::iter() {
+                for weekday in crate::Samint::iter() {
+                    let all: Vec<_> = month
+                        .days_iter(year)
+                        .filter(|qen| qen.weekday() == weekday)
+                        .collect();
+
+                    for (i, qen) in all.iter().enumerate() {
+                        let nth = i as i8 + 1;
+                        let from_end = i as i8 - all.len() as i8;
+                        assert_eq!(
+                            Zemen::nth_weekday_of_month(year, month, weekday, nth)?,
+                            *qen
+                        );
+                        assert_eq!(
+                            Zemen::nth_weekday_of_month(year, month, weekday, from_end)?,
+                            *qen
+                        );
+                    }
+
+                    let past = all.len() as i8 + 1;
+                    assert!(Zemen::nth_weekday_of_month(year, month, weekday, past).is_err());
+                    assert!(Zemen::nth_weekday_of_month(year, month, weekday, -past).is_err());
+                    assert!(Zemen::nth_weekday_of_month(year, month, weekday, 0).is_err());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weekday_occurrences() -> Result<(), Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Puagme, 1)?;
+
+        for qen in start.iter_days_until(&(start + 14)) {
+            for weekday in crate::Samint::iter() {
+                let next = qen.next_weekday(weekday);
+                let prev = qen.prev_weekday(weekday);
+
+                assert_eq!(next.weekday(), weekday);
+                assert_eq!(prev.weekday(), weekday);
+                assert!((1..=7).contains(&(next.to_jdn() - qen.to_jdn())));
+                assert!((1..=7).contains(&(qen.to_jdn() - prev.to_jdn())));
+
+                let same = qen.weekday() == weekday;
+                assert_eq!(qen.next_weekday_or_same(weekday) == qen, same);
+                assert_eq!(qen.prev_weekday_or_same(weekday) == qen, same);
+                if !same {
+                    assert_eq!(qen.next_weekday_or_same(weekday), next);
+                    assert_eq!(qen.prev_weekday_or_same(weekday), prev);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_and_max() -> Result<(), Error> {
+        assert!(Zemen::from_eth_cal(MIN_YEAR - 1, Werh::Puagme, 5).is_err());
+        assert!(Zemen::from_eth_cal(MAX_YEAR + 1, Werh::Meskerem, 1).is_err());
+        assert!(Zemen::from_ordinal_date(MAX_YEAR + 1, 1).is_err());
+
+        for (qen, days) in [(Zemen::MIN, 0..1500), (Zemen::MAX - 1500, 0..1500)] {
+            for days in days {
+                let next = qen + days;
+                assert_eq!(Zemen::from_jdn(next.to_jdn())?, next);
+                assert_eq!(next.next(), next + 1);
+                assert!(next < next + 1);
+            }
+        }
+
+        assert_eq!(Zemen::MIN.to_string(), "-4194304-01-01");
+        assert_eq!(Zemen::MAX.to_string(), "4194303-13-06");
+        assert!(std::panic::catch_unwind(|| Zemen::MAX + 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_from_gre_cal_bounds() -> Result<(), Error> {
+        let bounds = |year, month, day| match Zemen::checked_from_gre_cal(year, month, day) {
+            Err(Error::InvalidRange { name, min, max, .. }) => (name, min, max),
+            other => panic!("unexpected {other:?}"),
+        };
+
+        assert_eq!(bounds(2023, 0, 1), ("month", 1, 12));
+        assert_eq!(bounds(2024, 2, 30), ("day of February", 1, 29));
+        assert_eq!(bounds(1900, 2, 29), ("day of February", 1, 28));
+        assert_eq!(bounds(2023, 4, 31), ("day of April", 1, 30));
+        assert_eq!(bounds(2023, 12, 0), ("day of December", 1, 31));
+        assert_eq!(bounds(i32::MAX, 1, 1).0, "Gregorian year");
+        assert_eq!(bounds(i32::MIN, 1, 1).0, "Gregorian year");
+
+        let first = crate::conversion::jdn_to_gre(Zemen::MIN.to_jdn());
+        assert_eq!(
+            Zemen::checked_from_gre_cal(first.0, first.1, first.2)?,
+            Zemen::MIN
+        );
+        let last = crate::conversion::jdn_to_gre(Zemen::MAX.to_jdn());
+        assert_eq!(
+            Zemen::checked_from_gre_cal(last.0, last.1, last.2)?,
+            Zemen::MAX
+        );
+        assert!(Zemen::checked_from_gre_cal(last.0, 12, 31).is_err());
+
+        #[cfg(feature = "time")]
+        for (year, month, day) in [(2000, 1, 1), (2024, 2, 29), (1582, 10, 4), (-44, 3, 15)] {
+            assert_eq!(
+                Zemen::checked_from_gre_cal(year, month, day)?,
+                Zemen::from_gre_cal(year, month, day)?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gregorian_parts_round_trip() -> Result<(), Error> {
+        let start = Zemen::from_eth_cal(1990, Werh::Meskerem, 1)?;
+
+        for qen in start
+            .iter_days_until(&(start + 3000))
+            .chain([Zemen::MIN, Zemen::MAX])
+        {
+            let (year, month, day) = qen.try_into_gregorian_parts()?;
+            assert_eq!(Zemen::checked_from_gre_cal(year, month, day)?, qen);
+
+            #[cfg(feature = "time")]
+            if qen.is_gregorian_interop() {
+                let date = qen.to_gre();
+                assert_eq!(
+                    (year, month, day),
+                    (date.year(), date.month() as u8, date.day())
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "jiff")]
+    fn test_jiff_conversion() -> Result<(), Error> {
+        use jiff::civil::Date;
+
+        let start = Zemen::from_eth_cal(2010, Werh::Meskerem, 1)?;
+        for qen in start.iter_days_until(&(start + 1500)) {
+            let date = qen.to_jiff_date()?;
+            let (year, month, day) = qen.try_into_gregorian_parts()?;
+
+            assert_eq!(date, jiff::civil::date(year as i16, month as i8, day as i8));
+            assert_eq!(Zemen::from(&date), qen);
+        }
+
+        assert_eq!(Zemen::from(&Date::MIN), Zemen::MIN_GREGORIAN_INTEROP);
+        assert_eq!(Zemen::from(&Date::MAX), Zemen::MAX_GREGORIAN_INTEROP);
+        assert_eq!(Zemen::MIN_GREGORIAN_INTEROP.to_jiff_date()?, Date::MIN);
+        assert!((Zemen::MIN_GREGORIAN_INTEROP - 1).to_jiff_date().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dates_as_hash_keys() -> Result<(), Error> {
+        use std::collections::{HashMap, HashSet};
+
+        let start = Zemen::from_eth_cal(2015, Werh::Tir, 1)?;
+        let mut by_day = HashMap::new();
+        let mut by_weekday: HashMap<_, u32> = HashMap::new();
+
+        for days in 0..60 {
+            let qen = start + days % 30;
+            *by_day.entry(qen).or_insert(0) += 1;
+            *by_weekday.entry(qen.weekday()).or_default() += 1;
+        }
+
+        assert_eq!(by_day.len(), 30);
+        assert!(by_day.values().all(|count| *count == 2));
+        assert_eq!(by_weekday.len(), 7);
+
+        let months: HashSet<_> = (0..60).map(|days| (start + days).month()).collect();
+        assert_eq!(months, HashSet::from([Werh::Tir, Werh::Yekatit]));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    #[should_panic(expected = "date is beyond `Zemen::MIN` and `Zemen::MAX`")]
+    fn test_sub_duration_min_panics_with_the_range() {
+        let _ = Zemen::MAX - time::Duration::MIN;
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    #[should_panic(expected = "date is beyond `Zemen::MIN` and `Zemen::MAX`")]
+    fn test_sub_duration_beyond_min_panics() {
+        let _ = Zemen::MIN - time::Duration::days(1);
+    }
+
+    #[test]
+    fn test_from_str_takes_only_the_display_form() -> Result<(), error::Error> {
+        for qen in [
+            Zemen::from_eth_cal(2015, Werh::Tir, 10)?,
+            Zemen::from_eth_cal(-5, Werh::Hedar, 3)?,
+            Zemen::from_eth_cal(12, Werh::Puagme, 1)?,
+            Zemen::MIN,
+            Zemen::MAX,
+        ] {
+            assert_eq!(qen.to_string().parse::<Zemen>()?, qen);
+        }
+
+        for input in [
+            "+2015-05-10",
+            "2015-5-10",
+            "2015-05-1",
+            "2015-005-10",
+            "2015-05-010",
+            "2015-05-10-",
+            "2015-05",
+            "--2015-05-10",
+            " 2015-05-10",
+            "2015-+5-10",
+            "-2015-05",
+            "",
+        ] {
+            assert!(input.parse::<Zemen>().is_err(), "{input}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_days_until_across_all_of_zemen() {
+        let mut days = Zemen::MIN.iter_days_until(&Zemen::MAX);
+        let len = i64::from(Zemen::MAX.to_jdn()) - i64::from(Zemen::MIN.to_jdn());
+
+        assert_eq!(days.len() as i64, len);
+        assert_eq!(days.next(), Some(Zemen::MIN));
+        assert_eq!(days.next_back(), Some(Zemen::MAX.previous()));
+        assert_eq!(Zemen::MAX.iter_days_until(&Zemen::MIN).len(), 0);
+    }
+
+    #[test]
+    fn test_hot_paths_never_panic() {
+        let extremes = [i32::MIN, -1, 0, 1, 13, 31, 366, i32::MAX];
+        let jdns = i64::from(Zemen::MIN.to_jdn())..=i64::from(Zemen::MAX.to_jdn());
+        let in_zemen = |jdn: i64| jdns.contains(&jdn);
+        let years = MIN_YEAR..=MAX_YEAR;
+
+        for n in extremes {
+            assert_eq!(Zemen::from_jdn(n).is_ok(), in_zemen(n.into()));
+            let _ = Zemen::from_epoch_day(n);
+            let _ = Zemen::from_parts_lenient(n, n, n);
+            #[cfg(feature = "time")]
+            let _ = Zemen::from_gre_cal(n, n as u8, n as u8);
+            let _ = Zemen::checked_from_gre_cal(n, n as u8, n as u8);
+            let _ = Zemen::from_eth_cal(n, Werh::Puagme, n as u8);
+
+            for qen in [Zemen::MIN, Zemen::MAX] {
+                let jdn = i64::from(qen.to_jdn());
+                assert_eq!(
+                    qen.checked_add_days(n).is_some(),
+                    in_zemen(jdn + i64::from(n))
+                );
+                assert_eq!(
+                    qen.checked_sub_days(n).is_some(),
+                    in_zemen(jdn - i64::from(n))
+                );
+                let _ = qen.add_months(n, crate::MonthOverflow::Clamp);
+                let _ = qen.add_years(n, crate::MonthOverflow::Clamp);
+            }
+        }
+
+        for year in [MIN_YEAR - 1, MIN_YEAR, MAX_YEAR, MAX_YEAR + 1] {
+            for werh in Werh::iter() {
+                // only the Puagme of the last year ends beyond `Zemen`
+                let holds = years.contains(&year) && (year, werh) != (MAX_YEAR, Werh::Puagme);
+
+                assert_eq!(
+                    calendar::werh_weeks(year, werh, Samint::Ihud).is_ok(),
+                    holds
+                );
+                for dekad in 1..=3 {
+                    // Puagme has one short dekad
+                    let exists = werh != Werh::Puagme || dekad == 1;
+                    assert_eq!(Period::dekad(year, werh, dekad).is_ok(), holds && exists);
+                }
+                for scheme in [
+                    crate::codes::CodeScheme::Calendar,
+                    crate::codes::CodeScheme::Fiscal,
+                ] {
+                    for quarter in 1..=4 {
+                        let code = format!("{year}/Q{quarter}/{werh:?}");
+                        let _ = crate::codes::parse_period_code(&code, scheme);
+                    }
+                }
+            }
+            for season in [
+                crate::Season::Bega,
+                crate::Season::Belg,
+                crate::Season::Kiremt,
+            ] {
+                let period = season.period(year);
+                assert!(period.is_err() || years.contains(&year));
+            }
+        }
+
+        for range in [
+            crate::ZemenRange::new(Zemen::MAX + -10, Zemen::MAX),
+            crate::ZemenRange::exclusive(Zemen::MIN, Zemen::MIN + 10),
+        ] {
+            for days in [1, 3, 11, 12, u32::MAX] {
+                assert!(range.windows(days).count() as i64 <= range.len_days());
+                assert!(range.chunks(days).count() as i64 <= range.len_days());
+            }
+        }
+
+        // the days between the ends of `Zemen` overflow an `i32`
+        let days = i64::from(Zemen::MAX.to_jdn()) - i64::from(Zemen::MIN.to_jdn());
+        assert_eq!(Period::new(Zemen::MIN, Zemen::MAX).len_days(), days);
+        assert_eq!(
+            crate::ZemenRange::new(Zemen::MIN, Zemen::MAX).len_days(),
+            days + 1
+        );
+        assert_eq!(Zemen::MIN.iter_days_until(&Zemen::MAX).len() as i64, days);
+        assert_eq!(Zemen::MAX.iter_days_until(&Zemen::MIN).len(), 0);
+        for (qen, now) in [(Zemen::MIN, Zemen::MAX), (Zemen::MAX, Zemen::MIN)] {
+            let options = crate::humanize::HumanizeOptions::default();
+            assert!(crate::humanize::relative_with(&qen, &now, &options).contains("ዓመት"));
+        }
+        #[cfg(feature = "unstable-step")]
+        assert_eq!(
+            (Zemen::MIN..Zemen::MAX).size_hint(),
+            (days as usize, Some(days as usize))
+        );
+
+        for weekday in Samint::iter() {
+            let (min, max) = (Zemen::MIN, Zemen::MAX);
+
+            assert_eq!(min.checked_prev_weekday(weekday), None);
+            assert_eq!(max.checked_next_weekday(weekday), None);
+            assert_eq!(
+                min.checked_prev_weekday_or_same(weekday).is_some(),
+                min.weekday() == weekday
+            );
+            assert_eq!(
+                max.checked_next_weekday_or_same(weekday).is_some(),
+                max.weekday() == weekday
+            );
+            assert_eq!(
+                min.checked_next_weekday(weekday),
+                Some(min.next_weekday(weekday))
+            );
+            assert_eq!(
+                max.checked_prev_weekday(weekday),
+                Some(max.prev_weekday(weekday))
+            );
+        }
+
+        assert!(Zemen::MIN.week_of_werh_boundaries(Samint::Ihud).is_ok());
+        assert!(Zemen::MAX.week_of_werh_boundaries(Samint::Ihud).is_err());
+        for qen in [Zemen::MIN, Zemen::MAX] {
+            let _ = (qen.weekday(), qen.to_calendar_date(), qen.to_epoch_day());
+            for text in [qen.to_string(), qen.format_iso8601(), qen.format_ussd()] {
+                assert!(!text.is_empty());
+            }
+            assert!(!qen.format("YY YYYY M MM MMM D DD DDD JJ QQ").is_empty());
+            assert!(qen
+                .try_format_weeks("WW", calendar::WeekNumbering::Iso)
+                .is_ok());
+            #[cfg(feature = "time")]
+            assert!(qen.try_to_gre().is_err());
+        }
+
+        for input in [
+            "",
+            "-",
+            "--",
+            "2015",
+            "2015-05",
+            "-2147483648-01-01",
+            "99999999999-01-01",
+            "2015-255-255",
+            "2015-05-10\0",
+            "ጥር",
+            "+",
+            "2015-13-07",
+        ] {
+            assert!(input.parse::<Zemen>().is_err(), "{input}");
+            assert!(Zemen::parse_iso8601(input).is_err(), "{input}");
+            assert!(Zemen::parse_ussd(input).is_err(), "{input}");
+            let _ = Zemen::from_base32_code(input);
+            for style in [
+                crate::PartitionStyle::Ethiopian,
+                crate::PartitionStyle::Gregorian,
+            ] {
+                assert!(Zemen::from_partition_path(input, style).is_err(), "{input}");
+            }
+        }
+    }
+
+    #[cfg(feature = "unstable-step")]
+    #[test]
+    fn test_ranges_step_like_until() -> Result<(), Error> {
+        let start = Zemen::from_eth_cal(2015, Werh::Nehase, 28)?;
+        let end = Zemen::from_eth_cal(2016, Werh::Meskerem, 3)?;
+
+        let stepped: Vec<_> = (start..end).collect();
+        assert_eq!(stepped, start.until(&end).collect::<Vec<_>>());
+        assert_eq!((start..=end).next_back(), Some(end));
+
+        let days = i64::from(Zemen::MAX.to_jdn()) - i64::from(Zemen::MIN.to_jdn());
+        let steps = days as usize;
+        assert_eq!((Zemen::MIN..Zemen::MAX).size_hint(), (steps, Some(steps)));
+        assert_eq!((Zemen::MIN..=Zemen::MAX).next_back(), Some(Zemen::MAX));
+        assert_eq!(
+            (Zemen::MIN..Zemen::MAX).nth(steps - 1),
+            Some(Zemen::MAX.previous())
+        );
+        assert_eq!((Zemen::MAX..Zemen::MIN).next(), None);
+
+        Ok(())
+    }
 }